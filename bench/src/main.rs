@@ -0,0 +1,148 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use chess_engine_movegen::{
+    eval::{Evaluator, Material},
+    perft::perft,
+    Board, BoardBuilder,
+};
+use chess_engine_uci::BUILD_INFO;
+use clap::Parser;
+use serde::Serialize;
+
+/// Times move generation and the other hot paths of the engine, and prints
+/// a JSON report.
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// Times each benchmark runs over every position
+    #[arg(long, default_value_t = 1000)]
+    iterations: u64,
+}
+
+/// The positions of the usual perft suite: the starting position,
+/// Kiwipete, and positions rich in en passant, promotions and checks.
+const POSITIONS: [&str; 6] = [
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+    "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+    "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+    "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+];
+
+/// The depth of the perft benchmark, shallow enough to run every iteration.
+const PERFT_DEPTH: u8 = 2;
+
+/// The report printed at the end of the run.
+#[derive(Serialize)]
+struct Report {
+    build: String,
+    version: &'static str,
+    git_hash: Option<&'static str>,
+    features: &'static [&'static str],
+    positions: usize,
+    iterations: u64,
+    benchmarks: Vec<Benchmark>,
+}
+
+/// The result of one benchmark.
+#[derive(Serialize)]
+struct Benchmark {
+    name: &'static str,
+    operations: u64,
+    nanos: u128,
+    per_second: f64,
+}
+
+impl Benchmark {
+    /// Runs `operation` on every board, `iterations` times, and counts the
+    /// operations it reports.
+    fn run(
+        name: &'static str,
+        boards: &[Board],
+        iterations: u64,
+        mut operation: impl FnMut(&Board) -> u64,
+    ) -> Self {
+        let start = Instant::now();
+        let mut operations = 0;
+
+        for _ in 0..iterations {
+            for board in boards {
+                operations += operation(black_box(board));
+            }
+        }
+
+        Self::new(name, operations, start.elapsed())
+    }
+
+    fn new(name: &'static str, operations: u64, elapsed: Duration) -> Self {
+        Self {
+            name,
+            operations,
+            nanos: elapsed.as_nanos(),
+            per_second: operations as f64 / elapsed.as_secs_f64().max(f64::EPSILON),
+        }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let boards: Vec<Board> = POSITIONS
+        .iter()
+        .map(|fen| {
+            fen.parse::<BoardBuilder>()
+                .expect("bench positions are valid")
+                .build()
+        })
+        .collect();
+
+    let benchmarks = vec![
+        Benchmark::run("generate_moves", &boards, cli.iterations, |board| {
+            black_box(board.generate_moves()).len() as u64
+        }),
+        Benchmark::run("generate_legal_moves", &boards, cli.iterations, |board| {
+            black_box(board.generate_legal_moves()).len() as u64
+        }),
+        Benchmark::run("check_move", &boards, cli.iterations, |board| {
+            let moves = board.generate_moves();
+            for &mv in &moves {
+                let _ = black_box(board.check_move(mv));
+            }
+            moves.len() as u64
+        }),
+        Benchmark::run("perft", &boards, cli.iterations, |board| {
+            black_box(perft(&mut board.clone(), PERFT_DEPTH))
+        }),
+        Benchmark::run("hash", &boards, cli.iterations, |board| {
+            black_box(board.hash());
+            1
+        }),
+        Benchmark::run("evaluate", &boards, cli.iterations, |board| {
+            black_box(Material.evaluate(board));
+            1
+        }),
+        Benchmark::run("parse_fen", &boards, cli.iterations, |board| {
+            black_box(board.to_string().parse::<BoardBuilder>().is_ok());
+            1
+        }),
+    ];
+
+    let report = Report {
+        build: BUILD_INFO.to_string(),
+        version: BUILD_INFO.version,
+        git_hash: BUILD_INFO.git_hash,
+        features: BUILD_INFO.features,
+        positions: boards.len(),
+        iterations: cli.iterations,
+        benchmarks,
+    };
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&report).expect("the report always serializes to JSON")
+    );
+}
@@ -0,0 +1,211 @@
+use std::{fmt, time::Duration};
+
+use crate::Nag;
+
+/// The annotation of a move: its NAGs, comment, clock and evaluation.
+///
+/// Formats as the PGN text that follows the move. The clock and evaluation
+/// are embedded in the comment as `[%clk]` and `[%eval]` commands, the way
+/// Lichess exports them.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// let mut annotation = Annotation::default();
+/// annotation.push_nag(Nag::BLUNDER);
+/// annotation.push_nag(Nag::BLACK_DECISIVE_ADVANTAGE);
+/// annotation.comment = Some("Drops the queen".to_string());
+///
+/// assert_eq!(annotation.to_string(), "$4 $19 {Drops the queen}");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Annotation {
+    /// The NAGs of the move, in order.
+    pub nags: Vec<Nag>,
+    /// The comment after the move.
+    pub comment: Option<String>,
+    /// The time left on the clock of the player after the move.
+    pub clock: Option<Duration>,
+    /// The evaluation of the position after the move.
+    pub eval: Option<Evaluation>,
+}
+
+impl Annotation {
+    /// Returns `true` if the annotation has no NAG, comment, clock or
+    /// evaluation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// assert!(Annotation::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.nags.is_empty()
+            && self.comment.is_none()
+            && self.clock.is_none()
+            && self.eval.is_none()
+    }
+
+    /// Adds a NAG, replacing the previous move assessment if both judge the
+    /// move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let mut annotation = Annotation::default();
+    /// annotation.push_nag(Nag::MISTAKE);
+    /// annotation.push_nag(Nag::BLUNDER);
+    ///
+    /// assert_eq!(annotation.nags, vec![Nag::BLUNDER]);
+    /// ```
+    pub fn push_nag(&mut self, nag: Nag) {
+        if nag.is_move_assessment() {
+            self.nags.retain(|other| !other.is_move_assessment());
+        }
+
+        if !self.nags.contains(&nag) {
+            self.nags.push(nag);
+        }
+    }
+
+    /// Returns the glyphs of the NAGs, like `?? -+`, for display next to a
+    /// move.
+    ///
+    /// NAGs without a glyph are written as `$<number>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let annotation = Annotation {
+    ///     nags: vec![Nag::SPECULATIVE_MOVE, Nag(146)],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(annotation.glyphs(), "!? $146");
+    /// ```
+    pub fn glyphs(&self) -> String {
+        self.nags
+            .iter()
+            .map(|nag| nag.glyph().map_or_else(|| nag.to_string(), str::to_string))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Formats an `Annotation` as PGN, NAGs first and then the comment.
+///
+/// The clock and evaluation commands open the comment. Closing braces in the
+/// comment are removed, since PGN comments can't contain them.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// # use std::time::Duration;
+/// let annotation = Annotation {
+///     comment: Some("Only move".to_string()),
+///     clock: Some(Duration::from_secs(178)),
+///     eval: Some(Evaluation::Centipawns(-35)),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     annotation.to_string(),
+///     "{[%clk 0:02:58] [%eval -0.35] Only move}"
+/// );
+/// ```
+impl fmt::Display for Annotation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts: Vec<String> = self.nags.iter().map(Nag::to_string).collect();
+
+        let mut comment = Vec::new();
+
+        if let Some(clock) = self.clock {
+            let seconds = clock.as_secs();
+
+            comment.push(format!(
+                "[%clk {}:{:02}:{:02}]",
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60
+            ));
+        }
+
+        if let Some(eval) = self.eval {
+            comment.push(format!("[%eval {}]", eval));
+        }
+
+        if let Some(text) = &self.comment {
+            comment.push(text.replace('}', ""));
+        }
+
+        if !comment.is_empty() {
+            parts.push(format!("{{{}}}", comment.join(" ")));
+        }
+
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+/// The evaluation of a position, from White's point of view.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// assert_eq!(Evaluation::Centipawns(120).to_string(), "1.20");
+/// assert_eq!(Evaluation::Centipawns(-5).to_string(), "-0.05");
+/// assert_eq!(Evaluation::Mate(-3).to_string(), "#-3");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Evaluation {
+    /// Score in centipawns.
+    Centipawns(i32),
+    /// Mate in a number of moves, negative if Black mates.
+    Mate(i32),
+}
+
+impl Evaluation {
+    /// Returns White's winning chances, from -1 for a sure loss to 1 for a
+    /// sure win, with the logistic curve Lichess fits to its games.
+    ///
+    /// Scores beyond 10 pawns count as 10 pawns, and mates count as more
+    /// than that the closer they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// assert_eq!(Evaluation::Centipawns(0).winning_chances(), 0.0);
+    /// assert!(Evaluation::Centipawns(300).winning_chances() > 0.5);
+    /// assert!(Evaluation::Mate(-1).winning_chances() < Evaluation::Mate(-8).winning_chances());
+    /// ```
+    pub fn winning_chances(self) -> f64 {
+        let centipawns = match self {
+            Evaluation::Centipawns(centipawns) => centipawns.clamp(-1000, 1000),
+            Evaluation::Mate(moves) => (21 - moves.abs().min(10)) * 100 * moves.signum(),
+        };
+
+        2.0 / (1.0 + (-0.00368208 * centipawns as f64).exp()) - 1.0
+    }
+}
+
+/// Formats an `Evaluation` the way `[%eval]` writes it, in pawns or as
+/// `#<moves>`.
+impl fmt::Display for Evaluation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Evaluation::Centipawns(centipawns) => {
+                let sign = if *centipawns < 0 { "-" } else { "" };
+                let centipawns = centipawns.unsigned_abs();
+
+                write!(f, "{}{}.{:02}", sign, centipawns / 100, centipawns % 100)
+            }
+            Evaluation::Mate(moves) => write!(f, "#{}", moves),
+        }
+    }
+}
@@ -1,8 +1,10 @@
-use crate::{File, Rank, Square};
+use crate::{Color, File, Rank, Square};
 use std::{
     fmt::{self, Debug},
     ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+    str::FromStr,
 };
+use thiserror::Error;
 
 /// A macro for creating a bitboard.
 ///
@@ -129,6 +131,39 @@ impl BitBoard {
     /// ```
     pub const EMPTY: Self = Self(0);
 
+    /// A `BitBoard` with all the light squares.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let bitboard = BitBoard::LIGHT_SQUARES;
+    /// assert_eq!(bitboard, bitboard! {
+    ///     X . X . X . X .
+    ///     . X . X . X . X
+    ///     X . X . X . X .
+    ///     . X . X . X . X
+    ///     X . X . X . X .
+    ///     . X . X . X . X
+    ///     X . X . X . X .
+    ///     . X . X . X . X
+    /// });
+    /// assert!(bitboard.is_get_square(Square::H1));
+    /// ```
+    pub const LIGHT_SQUARES: Self = Self(0x55AA55AA55AA55AA);
+
+    /// A `BitBoard` with all the dark squares.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let bitboard = BitBoard::DARK_SQUARES;
+    /// assert_eq!(bitboard, !BitBoard::LIGHT_SQUARES);
+    /// assert!(bitboard.is_get_square(Square::A1));
+    /// ```
+    pub const DARK_SQUARES: Self = Self(0xAA55AA55AA55AA55);
+
     /// Sets a square on a `BitBoard`.
     ///
     /// # Examples
@@ -383,6 +418,107 @@ impl BitBoard {
         Self(self.0 >> 1 & NOT_FILE_H)
     }
 
+    /// Fills the bits of a `BitBoard` up to the last rank, with a
+    /// Kogge-Stone fill.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let bitboard = bitboard! {
+    ///     . . . . . . . .
+    ///     . . . . . . . .
+    ///     . . . . . . . .
+    ///     . . . . . . . .
+    ///     . . X . . . . .
+    ///     . . . . . . . .
+    ///     . . . . . . X .
+    ///     . . . . . . . .
+    /// };
+    /// assert_eq!(bitboard.north_fill(), bitboard! {
+    ///     . . X . . . X .
+    ///     . . X . . . X .
+    ///     . . X . . . X .
+    ///     . . X . . . X .
+    ///     . . X . . . X .
+    ///     . . . . . . X .
+    ///     . . . . . . X .
+    ///     . . . . . . . .
+    /// });
+    /// ```
+    pub const fn north_fill(self) -> Self {
+        let mut fill = self.0;
+
+        fill |= fill << 8;
+        fill |= fill << 16;
+        fill |= fill << 32;
+
+        Self(fill)
+    }
+
+    /// Fills the bits of a `BitBoard` down to the first rank, with a
+    /// Kogge-Stone fill.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let bitboard = Square::C4.bitboard();
+    /// assert_eq!(bitboard.south_fill(), bitboard! {
+    ///     . . . . . . . .
+    ///     . . . . . . . .
+    ///     . . . . . . . .
+    ///     . . . . . . . .
+    ///     . . X . . . . .
+    ///     . . X . . . . .
+    ///     . . X . . . . .
+    ///     . . X . . . . .
+    /// });
+    /// ```
+    pub const fn south_fill(self) -> Self {
+        let mut fill = self.0;
+
+        fill |= fill >> 8;
+        fill |= fill >> 16;
+        fill |= fill >> 32;
+
+        Self(fill)
+    }
+
+    /// Fills the files of the bits of a `BitBoard`.
+    ///
+    /// The files without a bit are the open files of a set of pawns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let pawns = Square::C4.bitboard() | Square::G2.bitboard();
+    /// assert_eq!(pawns.file_fill(), File::C.bitboard() | File::G.bitboard());
+    /// ```
+    pub const fn file_fill(self) -> Self {
+        Self(self.north_fill().0 | self.south_fill().0)
+    }
+
+    /// Fills the bits of a `BitBoard` in front of a [`Color`], towards the
+    /// rank its pawns promote on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let bitboard = Square::C4.bitboard();
+    ///
+    /// assert_eq!(bitboard.front_fill(Color::White), bitboard.north_fill());
+    /// assert_eq!(bitboard.front_fill(Color::Black), bitboard.south_fill());
+    /// ```
+    pub const fn front_fill(self, color: Color) -> Self {
+        match color {
+            Color::White => self.north_fill(),
+            Color::Black => self.south_fill(),
+        }
+    }
+
     /// Returns the least significant square of a `BitBoard`.
     ///
     /// # Examples
@@ -430,6 +566,32 @@ impl BitBoard {
     pub const fn len(self) -> usize {
         self.0.count_ones() as usize
     }
+
+    /// Returns the squares of the set bits of a `BitBoard`, from a1 to h8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let bitboard = Square::B2.bitboard() | Square::A2.bitboard();
+    /// assert_eq!(bitboard.to_square_list(), vec![Square::A2, Square::B2]);
+    /// ```
+    pub fn to_square_list(self) -> Vec<Square> {
+        self.into_iter().collect()
+    }
+
+    /// Returns `true` if no bits are set in a `BitBoard`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// assert!(BitBoard::EMPTY.is_empty());
+    /// assert!(!Square::E4.bitboard().is_empty());
+    /// ```
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
 }
 
 macro_rules! impl_ops {
@@ -635,22 +797,85 @@ impl From<Rank> for BitBoard {
 
 /// Formats a `BitBoard` as a string.
 ///
-/// The string is formatted as a 16-character hexadecimal number.
+/// The string is formatted as a 16-character hexadecimal number, or with the
+/// alternate flag (`{:#}`) as the list of its squares.
 ///
 /// # Examples
 ///
 /// ```
 /// # use chess_engine_core::*;
-/// let bitboard = BitBoard(0x000000000000FF00);
-/// println!("{}", bitboard);
-/// ```
+/// let bitboard = Square::A2.bitboard() | Square::B2.bitboard();
 ///
-/// ```textplain
-/// 000000000000FF00
+/// assert_eq!(format!("{}", bitboard), "0000000000000300");
+/// assert_eq!(format!("{:#}", bitboard), "{a2, b2}");
+/// assert_eq!(format!("{:#}", BitBoard::EMPTY), "{}");
 /// ```
 impl fmt::Display for BitBoard {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:016X}", self.0)
+        if !f.alternate() {
+            return write!(f, "{:016X}", self.0);
+        }
+
+        write!(f, "{{")?;
+
+        for (index, square) in self.into_iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", square)?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
+/// An error that can occur when parsing a [`BitBoard`].
+#[derive(Error, Debug)]
+#[error("invalid BitBoard (expected a list of squares like {{a2, b2}}, got {0})")]
+pub struct BitBoardError(pub String);
+
+/// Parses a `BitBoard` from a list of squares, as formatted by the alternate
+/// mode of [`Display`](fmt::Display).
+///
+/// # Errors
+///
+/// Returns a [`BitBoardError`] if the string is not a list of squares in
+/// braces.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// let bitboard: BitBoard = "{a2, b2}".parse().unwrap();
+/// assert_eq!(bitboard, Square::A2.bitboard() | Square::B2.bitboard());
+///
+/// assert_eq!("{}".parse::<BitBoard>().unwrap(), BitBoard::EMPTY);
+/// assert!("{a2, i9}".parse::<BitBoard>().is_err());
+/// ```
+impl FromStr for BitBoard {
+    type Err = BitBoardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || BitBoardError(s.to_string());
+
+        let squares = s
+            .trim()
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(error)?
+            .trim();
+
+        if squares.is_empty() {
+            return Ok(BitBoard::EMPTY);
+        }
+
+        squares
+            .split(',')
+            .try_fold(BitBoard::EMPTY, |bitboard, square| {
+                let square: Square = square.trim().parse().map_err(|_| error())?;
+                Ok(bitboard | square.bitboard())
+            })
     }
 }
 
@@ -698,7 +923,7 @@ impl Debug for BitBoard {
                 }
             }
 
-            s.push_str("\n");
+            s.push('\n');
         }
 
         s.push_str("\n    a b c d e f g h\n\n");
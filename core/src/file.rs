@@ -72,4 +72,37 @@ impl File {
 
         BitBoard(BITBOARD << self as usize)
     }
+
+    /// Returns the letter of the `File`, in lowercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// assert_eq!(File::C.to_char(), 'c');
+    /// ```
+    pub const fn to_char(self) -> char {
+        (b'a' + self as u8) as char
+    }
+
+    /// Parses a `File` from its letter, in lowercase or uppercase.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FileError`] if the character is not a file letter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// assert_eq!(File::from_char('c').unwrap(), File::C);
+    /// assert_eq!(File::from_char('C').unwrap(), File::C);
+    /// assert!(File::from_char('i').is_err());
+    /// ```
+    pub fn from_char(file: char) -> Result<Self, FileError> {
+        match file.to_ascii_lowercase() {
+            file @ 'a'..='h' => Ok(File::new(file as usize - 'a' as usize)),
+            _ => Err(FileError(file.to_string())),
+        }
+    }
 }
@@ -0,0 +1,22 @@
+use super::macros::{create_enum, enum_str};
+
+create_enum! {
+    /// The `GameResult` of a finished chess game.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum GameResult {
+        /// White won the game.
+        WhiteWins,
+        /// Black won the game.
+        BlackWins,
+        /// The game is a draw.
+        Draw
+    }
+}
+
+enum_str! {
+    GameResult, GameResultError {
+        WhiteWins = "1-0",
+        BlackWins = "0-1",
+        Draw = "1/2-1/2"
+    }
+}
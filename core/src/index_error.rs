@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+/// An error that can occur when creating an enum from an out of range index.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// assert_eq!(Rank::try_from(2usize), Ok(Rank::Three));
+/// assert_eq!(Square::try_from(63u8), Ok(Square::H8));
+///
+/// let error = Square::try_new(64).unwrap_err();
+/// assert_eq!(error.to_string(), "invalid Square index (expected 0-63, got 64)");
+/// ```
+#[derive(Error, Clone, Copy, Debug, PartialEq, Eq)]
+#[error("invalid {name} index (expected 0-{max}, got {index})")]
+pub struct IndexError {
+    /// Name of the enum.
+    pub name: &'static str,
+    /// Largest valid index.
+    pub max: usize,
+    /// The index that was out of range.
+    pub index: usize,
+}
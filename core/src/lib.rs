@@ -3,24 +3,35 @@
 
 //! Types for working with chessboards.
 
+mod annotation;
 mod bitboard;
 mod castle_rights_type;
 mod color;
 mod file;
+mod game_result;
+mod index_error;
 pub(crate) mod macros;
 mod r#move;
+mod move_tree;
+mod nag;
 mod piece;
 mod piece_moves;
 mod piece_type;
 mod rand;
 mod rank;
+mod review;
 mod sliding_piece;
 mod square;
 
+pub use annotation::*;
 pub use bitboard::*;
 pub use castle_rights_type::*;
 pub use color::*;
 pub use file::*;
+pub use game_result::*;
+pub use index_error::*;
+pub use move_tree::*;
+pub use nag::*;
 pub use piece::*;
 pub use piece_moves::*;
 pub use piece_type::*;
@@ -43,6 +43,64 @@ macro_rules! create_enum {
                     _ => unreachable!(),
                 }
             }
+
+            #[doc = concat!(
+                "Creates a new `", stringify!($name), "` from an index.\n",
+                "# Errors\n",
+                "Returns an [`IndexError`](crate::IndexError) if the index is out of bounds."
+            )]
+            #[inline(always)]
+            pub const fn try_new(index: usize) -> Result<Self, $crate::IndexError> {
+                if index < Self::LEN {
+                    Ok(Self::new(index))
+                } else {
+                    Err($crate::IndexError {
+                        name: stringify!($name),
+                        max: Self::LEN - 1,
+                        index,
+                    })
+                }
+            }
+        }
+
+        #[doc = concat!(
+            "Converts an index to a `", stringify!($name), "`.\n",
+            "# Errors\n",
+            "Returns an [`IndexError`](crate::IndexError) if the index is out of bounds."
+        )]
+        impl TryFrom<usize> for $name {
+            type Error = $crate::IndexError;
+
+            fn try_from(index: usize) -> Result<Self, Self::Error> {
+                Self::try_new(index)
+            }
+        }
+
+        #[doc = concat!(
+            "Converts an index to a `", stringify!($name), "`.\n",
+            "# Errors\n",
+            "Returns an [`IndexError`](crate::IndexError) if the index is out of bounds."
+        )]
+        impl TryFrom<u8> for $name {
+            type Error = $crate::IndexError;
+
+            fn try_from(index: u8) -> Result<Self, Self::Error> {
+                Self::try_new(index as usize)
+            }
+        }
+
+        #[doc = concat!("Converts a `", stringify!($name), "` to its index.")]
+        impl From<$name> for usize {
+            fn from(value: $name) -> Self {
+                value as usize
+            }
+        }
+
+        #[doc = concat!("Converts a `", stringify!($name), "` to its index.")]
+        impl From<$name> for u8 {
+            fn from(value: $name) -> Self {
+                value as u8
+            }
         }
     };
 }
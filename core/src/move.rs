@@ -1,9 +1,11 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
+
+use thiserror::Error;
 
 use crate::{PieceType, Square};
 
 /// A move in a chess game.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Move {
     /// The square to move the piece from.
     from: Square,
@@ -32,8 +34,105 @@ impl Move {
             promotion,
         }
     }
+
+    /// Returns the square to move the piece from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let mv = Move::new(Square::A2, Square::A3, None);
+    /// assert_eq!(mv.from(), Square::A2);
+    /// ```
+    pub const fn from(&self) -> Square {
+        self.from
+    }
+
+    /// Returns the square to move the piece to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let mv = Move::new(Square::A2, Square::A3, None);
+    /// assert_eq!(mv.to(), Square::A3);
+    /// ```
+    pub const fn to(&self) -> Square {
+        self.to
+    }
+
+    /// Returns the piece to promote to, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let mv = Move::new(Square::A7, Square::A8, Some(PieceType::Queen));
+    /// assert_eq!(mv.promotion(), Some(PieceType::Queen));
+    /// ```
+    pub const fn promotion(&self) -> Option<PieceType> {
+        self.promotion
+    }
 }
 
+/// An error that can occur when parsing a [`Move`].
+#[derive(Error, Debug)]
+#[error("invalid Move (expected UCI notation like e2e4 or e7e8q, got {0})")]
+pub struct MoveError(pub String);
+
+/// Parses a `Move` from UCI notation.
+///
+/// # Errors
+///
+/// Returns a [`MoveError`] if the string is not a valid UCI move.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// let mv: Move = "e7e8q".parse().unwrap();
+/// assert_eq!(mv, Move::new(Square::E7, Square::E8, Some(PieceType::Queen)));
+///
+/// assert!("e7e8x".parse::<Move>().is_err());
+///
+/// // pawns only promote to a knight, bishop, rook or queen
+/// assert!("e7e8k".parse::<Move>().is_err());
+/// assert!("e7e8p".parse::<Move>().is_err());
+/// ```
+impl FromStr for Move {
+    type Err = MoveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || MoveError(s.to_string());
+
+        if !s.is_ascii() || (s.len() != 4 && s.len() != 5) {
+            return Err(error());
+        }
+
+        let from = s[0..2].parse().map_err(|_| error())?;
+        let to = s[2..4].parse().map_err(|_| error())?;
+        let promotion = match &s[4..] {
+            "" => None,
+            promotion => Some(parse_promotion(promotion).ok_or_else(error)?),
+        };
+
+        Ok(Move::new(from, to, promotion))
+    }
+}
+
+/// Parses the piece type a pawn promotes to, a knight, bishop, rook or
+/// queen, in lowercase.
+fn parse_promotion(promotion: &str) -> Option<PieceType> {
+    match promotion {
+        "n" => Some(PieceType::Knight),
+        "b" => Some(PieceType::Bishop),
+        "r" => Some(PieceType::Rook),
+        "q" => Some(PieceType::Queen),
+        _ => None,
+    }
+}
+
+/// Formats a `Move` in UCI notation.
 impl fmt::Display for Move {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -50,11 +149,127 @@ impl fmt::Debug for Move {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let bitboard = self.from.bitboard().set_square(self.to);
 
-        write!(
-            f,
-            "{}\n\n   {}",
-            format!("{:?}", bitboard),
-            self.to_string()
-        )
+        write!(f, "{:?}\n\n   {}", bitboard, self)
+    }
+}
+
+/// JSON representation of a [`Move`], with squares and promotion as strings.
+#[cfg(feature = "json")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct MoveJson {
+    from: String,
+    to: String,
+    promotion: Option<String>,
+}
+
+/// JSON conversions for the `Move` struct.
+#[cfg(feature = "json")]
+impl Move {
+    /// Returns the move as a JSON object.
+    ///
+    /// The squares are named like `"e2"` and the promotion, if any, is a
+    /// lowercase piece type like `"q"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let mv = Move::new(Square::E7, Square::E8, Some(PieceType::Queen));
+    /// assert_eq!(mv.to_json(), r#"{"from":"e7","to":"e8","promotion":"q"}"#);
+    /// ```
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a move always serializes to JSON")
+    }
+
+    /// Parses a move from a JSON object.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`serde_json::Error`] if the JSON is invalid or a square or
+    /// promotion can not be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let mv = Move::from_json(r#"{"from":"e2","to":"e4","promotion":null}"#).unwrap();
+    /// assert_eq!(mv.to_string(), "e2e4");
+    ///
+    /// assert!(Move::from_json(r#"{"from":"e2","to":"e9","promotion":null}"#).is_err());
+    /// assert!(Move::from_json(r#"{"from":"e7","to":"e8","promotion":"k"}"#).is_err());
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Serializes a `Move` as a JSON object.
+#[cfg(feature = "json")]
+impl serde::Serialize for Move {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MoveJson {
+            from: self.from.to_string(),
+            to: self.to.to_string(),
+            promotion: self.promotion.map(|promotion| promotion.to_string()),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes a `Move` from a JSON object.
+#[cfg(feature = "json")]
+impl<'de> serde::Deserialize<'de> for Move {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let json = MoveJson::deserialize(deserializer)?;
+
+        Ok(Move::new(
+            json.from.parse().map_err(D::Error::custom)?,
+            json.to.parse().map_err(D::Error::custom)?,
+            json.promotion
+                .map(|promotion| {
+                    parse_promotion(&promotion).ok_or_else(|| {
+                        D::Error::custom(format!(
+                            "invalid promotion {promotion} (expected n, b, r or q)"
+                        ))
+                    })
+                })
+                .transpose()?,
+        ))
+    }
+}
+
+/// Generates a random `Move` for fuzzing.
+///
+/// The squares are any squares, so the move may not be possible on any
+/// board, but the promotion is always to a knight, bishop, rook or queen.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// use arbitrary::{Arbitrary, Unstructured};
+///
+/// let mut u = Unstructured::new(&[12, 28, 0]);
+/// let mv = Move::arbitrary(&mut u).unwrap();
+/// assert_eq!(mv.to_string().parse::<Move>().unwrap(), mv);
+/// ```
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Move {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        const PROMOTIONS: [Option<PieceType>; 5] = [
+            None,
+            Some(PieceType::Knight),
+            Some(PieceType::Bishop),
+            Some(PieceType::Rook),
+            Some(PieceType::Queen),
+        ];
+
+        Ok(Move::new(
+            Square::arbitrary(u)?,
+            Square::arbitrary(u)?,
+            *u.choose(&PROMOTIONS)?,
+        ))
     }
 }
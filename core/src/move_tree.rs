@@ -0,0 +1,349 @@
+use crate::{Annotation, Move};
+
+/// The identifier of a [`MoveNode`] in a [`MoveTree`].
+///
+/// Identifiers stay valid for the lifetime of the tree, since nodes are never
+/// removed.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct NodeId(usize);
+
+/// A node of a [`MoveTree`]: a move with its [`Annotation`] and the moves
+/// that can follow it.
+///
+/// The first child is the main continuation, the others are variations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveNode {
+    mv: Option<Move>,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+    /// The NAGs, comment, clock and evaluation of the move.
+    pub annotation: Annotation,
+}
+
+/// Methods for the `MoveNode` struct.
+impl MoveNode {
+    /// Returns the move that leads to the node, or `None` for the root.
+    pub fn mv(&self) -> Option<Move> {
+        self.mv
+    }
+
+    /// Returns the node before this one, or `None` for the root.
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    /// Returns the nodes after this one, the main continuation first.
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+}
+
+/// A tree of moves with variations, and a cursor pointing at the current
+/// node.
+///
+/// The root holds no move and stands for the starting position; its
+/// annotation is the comment before the first move. Moves are added at the
+/// cursor, which then follows them.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// let mv = |uci: &str| uci.parse::<Move>().unwrap();
+///
+/// let mut tree = MoveTree::new();
+/// tree.add_move(mv("e2e4"));
+/// tree.add_move(mv("e7e5"));
+///
+/// // 1. e4 c5 as a variation
+/// tree.back();
+/// let sicilian = tree.add_move(mv("c7c5"));
+///
+/// assert_eq!(tree.mainline(), vec![mv("e2e4"), mv("e7e5")]);
+/// assert!(!tree.is_mainline(sicilian));
+///
+/// tree.promote_to_mainline(sicilian);
+/// assert_eq!(tree.mainline(), vec![mv("e2e4"), mv("c7c5")]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveTree {
+    nodes: Vec<MoveNode>,
+    cursor: NodeId,
+}
+
+/// Methods for the `MoveTree` struct.
+impl MoveTree {
+    /// The identifier of the root of every `MoveTree`.
+    pub const ROOT: NodeId = NodeId(0);
+
+    /// Creates an empty `MoveTree`, with the cursor at the root.
+    pub fn new() -> Self {
+        MoveTree {
+            nodes: vec![MoveNode {
+                mv: None,
+                parent: None,
+                children: Vec::new(),
+                annotation: Annotation::default(),
+            }],
+            cursor: Self::ROOT,
+        }
+    }
+
+    /// Returns the node with the identifier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the identifier belongs to another tree.
+    pub fn node(&self, id: NodeId) -> &MoveNode {
+        &self.nodes[id.0]
+    }
+
+    /// Returns the node with the identifier, mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the identifier belongs to another tree.
+    pub fn node_mut(&mut self, id: NodeId) -> &mut MoveNode {
+        &mut self.nodes[id.0]
+    }
+
+    /// Returns the identifier of the node at the cursor.
+    pub fn cursor(&self) -> NodeId {
+        self.cursor
+    }
+
+    /// Returns the node at the cursor.
+    pub fn current(&self) -> &MoveNode {
+        self.node(self.cursor)
+    }
+
+    /// Returns the node at the cursor, mutably.
+    pub fn current_mut(&mut self) -> &mut MoveNode {
+        self.node_mut(self.cursor)
+    }
+
+    /// Plays the move at the cursor and moves the cursor to it.
+    ///
+    /// If the move already follows the cursor, the existing node is reused.
+    /// Otherwise the move is added as the main continuation if there is none,
+    /// or as the last variation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let mut tree = MoveTree::new();
+    /// let e4 = tree.add_move("e2e4".parse().unwrap());
+    ///
+    /// tree.back();
+    /// assert_eq!(tree.add_move("e2e4".parse().unwrap()), e4);
+    /// assert_eq!(tree.node(MoveTree::ROOT).children(), &[e4]);
+    /// ```
+    pub fn add_move(&mut self, mv: Move) -> NodeId {
+        let existing = self
+            .current()
+            .children
+            .iter()
+            .copied()
+            .find(|&child| self.node(child).mv == Some(mv));
+
+        let id = existing.unwrap_or_else(|| {
+            let id = NodeId(self.nodes.len());
+
+            self.nodes.push(MoveNode {
+                mv: Some(mv),
+                parent: Some(self.cursor),
+                children: Vec::new(),
+                annotation: Annotation::default(),
+            });
+            self.current_mut().children.push(id);
+
+            id
+        });
+
+        self.cursor = id;
+        id
+    }
+
+    /// Moves the cursor to the node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the identifier belongs to another tree.
+    pub fn go_to(&mut self, id: NodeId) {
+        assert!(id.0 < self.nodes.len(), "invalid NodeId");
+        self.cursor = id;
+    }
+
+    /// Moves the cursor to the main continuation, and returns its move.
+    ///
+    /// Returns `None` and leaves the cursor in place at the end of a line.
+    pub fn forward(&mut self) -> Option<Move> {
+        self.enter_variation(0)
+    }
+
+    /// Moves the cursor to the previous node, and returns the move that was
+    /// taken back.
+    ///
+    /// Returns `None` and leaves the cursor in place at the root.
+    pub fn back(&mut self) -> Option<Move> {
+        let parent = self.current().parent?;
+        let mv = self.current().mv;
+
+        self.cursor = parent;
+        mv
+    }
+
+    /// Moves the cursor to the continuation with the index, 0 being the main
+    /// one, and returns its move.
+    ///
+    /// Returns `None` and leaves the cursor in place if there is no such
+    /// continuation.
+    pub fn enter_variation(&mut self, index: usize) -> Option<Move> {
+        let child = *self.current().children.get(index)?;
+
+        self.cursor = child;
+        self.current().mv
+    }
+
+    /// Moves the cursor to the root.
+    pub fn go_to_start(&mut self) {
+        self.cursor = Self::ROOT;
+    }
+
+    /// Moves the cursor to the end of the main continuation of the current
+    /// node.
+    pub fn go_to_end(&mut self) {
+        while self.forward().is_some() {}
+    }
+
+    /// Returns the moves from the root to the node, to replay it on a board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let mut tree = MoveTree::new();
+    /// tree.add_move("d2d4".parse().unwrap());
+    /// let node = tree.add_move("g8f6".parse().unwrap());
+    ///
+    /// tree.go_to_start();
+    /// assert_eq!(
+    ///     tree.moves_to(node),
+    ///     vec!["d2d4".parse().unwrap(), "g8f6".parse().unwrap()]
+    /// );
+    /// ```
+    pub fn moves_to(&self, id: NodeId) -> Vec<Move> {
+        let mut moves = Vec::new();
+        let mut node = self.node(id);
+
+        while let (Some(mv), Some(parent)) = (node.mv, node.parent) {
+            moves.push(mv);
+            node = self.node(parent);
+        }
+
+        moves.reverse();
+        moves
+    }
+
+    /// Returns the moves of the main line, from the root.
+    pub fn mainline(&self) -> Vec<Move> {
+        let mut moves = Vec::new();
+        let mut node = self.node(Self::ROOT);
+
+        while let Some(&child) = node.children.first() {
+            node = self.node(child);
+            moves.extend(node.mv);
+        }
+
+        moves
+    }
+
+    /// Returns `true` if the node is on the main line.
+    pub fn is_mainline(&self, id: NodeId) -> bool {
+        let mut id = id;
+
+        while let Some(parent) = self.node(id).parent {
+            if self.node(parent).children.first() != Some(&id) {
+                return false;
+            }
+
+            id = parent;
+        }
+
+        true
+    }
+
+    /// Moves the variation of the node one place up among its siblings.
+    ///
+    /// Returns `false` if it is already the main continuation, or the root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let mut tree = MoveTree::new();
+    /// let e4 = tree.add_move("e2e4".parse().unwrap());
+    /// tree.back();
+    /// let d4 = tree.add_move("d2d4".parse().unwrap());
+    ///
+    /// assert!(tree.promote_variation(d4));
+    /// assert_eq!(tree.node(MoveTree::ROOT).children(), &[d4, e4]);
+    /// assert!(!tree.promote_variation(d4));
+    /// ```
+    pub fn promote_variation(&mut self, id: NodeId) -> bool {
+        match self.sibling_index(id) {
+            Some((parent, index)) if index > 0 => {
+                self.node_mut(parent).children.swap(index, index - 1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Moves the variation of the node one place down among its siblings.
+    ///
+    /// Returns `false` if it is already the last variation, or the root.
+    pub fn demote_variation(&mut self, id: NodeId) -> bool {
+        match self.sibling_index(id) {
+            Some((parent, index)) if index + 1 < self.node(parent).children.len() => {
+                self.node_mut(parent).children.swap(index, index + 1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Makes the node and all the nodes before it the main continuation, so
+    /// the node ends up on the main line.
+    ///
+    /// The former main continuations become the first variations.
+    pub fn promote_to_mainline(&mut self, id: NodeId) {
+        let mut id = id;
+
+        while let Some((parent, index)) = self.sibling_index(id) {
+            let children = &mut self.node_mut(parent).children;
+            children.remove(index);
+            children.insert(0, id);
+
+            id = parent;
+        }
+    }
+
+    fn sibling_index(&self, id: NodeId) -> Option<(NodeId, usize)> {
+        let parent = self.node(id).parent?;
+        let index = self
+            .node(parent)
+            .children
+            .iter()
+            .position(|&child| child == id)?;
+
+        Some((parent, index))
+    }
+}
+
+impl Default for MoveTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
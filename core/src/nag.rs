@@ -0,0 +1,145 @@
+use std::{fmt, str::FromStr};
+
+use thiserror::Error;
+
+/// A Numeric Annotation Glyph, as used in PGN to annotate moves and
+/// positions.
+///
+/// The common NAGs have constants and a glyph like `!` or `+=`, the others
+/// are only written as `$<number>`.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// let nag: Nag = "??".parse().unwrap();
+/// assert_eq!(nag, Nag::BLUNDER);
+/// assert_eq!(nag.to_string(), "$4");
+/// assert_eq!(nag.glyph(), Some("??"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Nag(pub u8);
+
+/// Constants and glyphs for the `Nag` struct.
+impl Nag {
+    /// A good move, `!`.
+    pub const GOOD_MOVE: Nag = Nag(1);
+    /// A mistake, `?`.
+    pub const MISTAKE: Nag = Nag(2);
+    /// A brilliant move, `!!`.
+    pub const BRILLIANT_MOVE: Nag = Nag(3);
+    /// A blunder, `??`.
+    pub const BLUNDER: Nag = Nag(4);
+    /// A speculative move, `!?`.
+    pub const SPECULATIVE_MOVE: Nag = Nag(5);
+    /// A dubious move, `?!`.
+    pub const DUBIOUS_MOVE: Nag = Nag(6);
+    /// A forced move, `□`.
+    pub const FORCED_MOVE: Nag = Nag(7);
+    /// An equal position, `=`.
+    pub const DRAWISH_POSITION: Nag = Nag(10);
+    /// An unclear position, `∞`.
+    pub const UNCLEAR_POSITION: Nag = Nag(13);
+    /// White is slightly better, `+=`.
+    pub const WHITE_SLIGHT_ADVANTAGE: Nag = Nag(14);
+    /// Black is slightly better, `=+`.
+    pub const BLACK_SLIGHT_ADVANTAGE: Nag = Nag(15);
+    /// White is better, `+/-`.
+    pub const WHITE_MODERATE_ADVANTAGE: Nag = Nag(16);
+    /// Black is better, `-/+`.
+    pub const BLACK_MODERATE_ADVANTAGE: Nag = Nag(17);
+    /// White is winning, `+-`.
+    pub const WHITE_DECISIVE_ADVANTAGE: Nag = Nag(18);
+    /// Black is winning, `-+`.
+    pub const BLACK_DECISIVE_ADVANTAGE: Nag = Nag(19);
+
+    /// The NAGs with a glyph, and their glyphs.
+    const GLYPHS: [(Nag, &'static str); 15] = [
+        (Nag::GOOD_MOVE, "!"),
+        (Nag::MISTAKE, "?"),
+        (Nag::BRILLIANT_MOVE, "!!"),
+        (Nag::BLUNDER, "??"),
+        (Nag::SPECULATIVE_MOVE, "!?"),
+        (Nag::DUBIOUS_MOVE, "?!"),
+        (Nag::FORCED_MOVE, "□"),
+        (Nag::DRAWISH_POSITION, "="),
+        (Nag::UNCLEAR_POSITION, "∞"),
+        (Nag::WHITE_SLIGHT_ADVANTAGE, "+="),
+        (Nag::BLACK_SLIGHT_ADVANTAGE, "=+"),
+        (Nag::WHITE_MODERATE_ADVANTAGE, "+/-"),
+        (Nag::BLACK_MODERATE_ADVANTAGE, "-/+"),
+        (Nag::WHITE_DECISIVE_ADVANTAGE, "+-"),
+        (Nag::BLACK_DECISIVE_ADVANTAGE, "-+"),
+    ];
+
+    /// Returns the glyph of the NAG, if it has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// assert_eq!(Nag::WHITE_SLIGHT_ADVANTAGE.glyph(), Some("+="));
+    /// assert_eq!(Nag(146).glyph(), None);
+    /// ```
+    pub fn glyph(&self) -> Option<&'static str> {
+        Nag::GLYPHS
+            .iter()
+            .find(|(nag, _)| nag == self)
+            .map(|(_, glyph)| *glyph)
+    }
+
+    /// Returns `true` if the NAG judges a move, from `!` to `?!` and `□`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// assert!(Nag::MISTAKE.is_move_assessment());
+    /// assert!(!Nag::UNCLEAR_POSITION.is_move_assessment());
+    /// ```
+    pub fn is_move_assessment(&self) -> bool {
+        (1..=7).contains(&self.0)
+    }
+}
+
+/// An error that can occur when parsing a [`Nag`].
+#[derive(Error, Debug)]
+#[error("invalid Nag (expected $<number> or a glyph, got {0})")]
+pub struct NagError(pub String);
+
+/// Parses a `Nag` from `$<number>` or a glyph.
+///
+/// # Errors
+///
+/// Returns a [`NagError`] if the string is not a valid NAG.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// assert_eq!("$14".parse::<Nag>().unwrap(), Nag::WHITE_SLIGHT_ADVANTAGE);
+/// assert_eq!("+=".parse::<Nag>().unwrap(), Nag::WHITE_SLIGHT_ADVANTAGE);
+/// assert!("$256".parse::<Nag>().is_err());
+/// ```
+impl FromStr for Nag {
+    type Err = NagError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(number) = s.strip_prefix('$') {
+            return number.parse().map(Nag).map_err(|_| NagError(s.to_string()));
+        }
+
+        Nag::GLYPHS
+            .iter()
+            .find(|(_, glyph)| *glyph == s)
+            .map(|(nag, _)| *nag)
+            .ok_or_else(|| NagError(s.to_string()))
+    }
+}
+
+/// Formats a `Nag` as `$<number>`, the way PGN writes it.
+impl fmt::Display for Nag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "${}", self.0)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{Color, PieceType, PieceTypeError};
 use std::{fmt, str::FromStr};
+use thiserror::Error;
 
 /// A `Piece` in chess.
 ///
@@ -63,7 +64,7 @@ impl Piece {
     /// assert_eq!(piece.piece_type(), PieceType::Pawn);
     /// assert_eq!(piece.color(), Color::White);
     /// ```
-    pub fn new(piece_type: PieceType, color: Color) -> Self {
+    pub const fn new(piece_type: PieceType, color: Color) -> Self {
         Piece { piece_type, color }
     }
 
@@ -76,7 +77,7 @@ impl Piece {
     /// let piece = Piece::new(PieceType::Pawn, Color::White);
     /// assert_eq!(piece.piece_type(), PieceType::Pawn);
     /// ```
-    pub fn piece_type(&self) -> PieceType {
+    pub const fn piece_type(&self) -> PieceType {
         self.piece_type
     }
 
@@ -89,7 +90,7 @@ impl Piece {
     /// let piece = Piece::new(PieceType::Pawn, Color::White);
     /// assert_eq!(piece.color(), Color::White);
     /// ```
-    pub fn color(&self) -> Color {
+    pub const fn color(&self) -> Color {
         self.color
     }
 }
@@ -143,6 +144,54 @@ impl fmt::Display for Piece {
     }
 }
 
+/// An error that can occur when converting a 4-bit code to a [`Piece`].
+#[derive(Error, Clone, Copy, Debug, PartialEq, Eq)]
+#[error("invalid Piece code (expected 0-5 or 8-13, got {0})")]
+pub struct PieceCodeError(pub u8);
+
+/// Converts a `Piece` to its 4-bit code, the [`Color`] in bit 3 and the
+/// [`PieceType`] in bits 0-2.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// assert_eq!(u8::from(Piece::new(PieceType::Knight, Color::White)), 1);
+/// assert_eq!(u8::from(Piece::new(PieceType::King, Color::Black)), 13);
+/// ```
+impl From<Piece> for u8 {
+    fn from(piece: Piece) -> Self {
+        (piece.color as u8) << 3 | piece.piece_type as u8
+    }
+}
+
+/// Converts a 4-bit code to a `Piece`, the [`Color`] in bit 3 and the
+/// [`PieceType`] in bits 0-2.
+///
+/// # Errors
+///
+/// Returns a [`PieceCodeError`] if the code is not a valid piece.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// assert_eq!(Piece::try_from(9), Ok(Piece::new(PieceType::Knight, Color::Black)));
+/// assert_eq!(Piece::try_from(6), Err(PieceCodeError(6)));
+/// ```
+impl TryFrom<u8> for Piece {
+    type Error = PieceCodeError;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match (PieceType::try_new((code & 0b111) as usize), code >> 3) {
+            (Ok(piece_type), color @ 0..=1) => {
+                Ok(Piece::new(piece_type, Color::new(color as usize)))
+            }
+            _ => Err(PieceCodeError(code)),
+        }
+    }
+}
+
 impl fmt::Debug for Piece {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut bytes: Vec<u8> = format!("{:?}", self.piece_type).bytes().collect();
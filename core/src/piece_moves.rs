@@ -38,7 +38,46 @@ impl PieceMoves {
         Self { piece, from, to }
     }
 
+    /// Returns the [`Piece`] for which moves are generated.
+    pub fn piece(&self) -> Piece {
+        self.piece
+    }
+
+    /// Returns the square to move the piece from.
+    pub fn from(&self) -> Square {
+        self.from
+    }
+
+    /// Returns the destination squares to move the piece to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let piece = Piece::new(PieceType::Knight, Color::White);
+    /// let moves = PieceMoves::new(piece, Square::B1, Square::C3.bitboard());
+    ///
+    /// assert_eq!(moves.to(), Square::C3.bitboard());
+    /// ```
+    pub fn to(&self) -> BitBoard {
+        self.to
+    }
+
     /// Returns the available moves for a piece.
+    ///
+    /// Pawns moving to the first or last rank get a move for every
+    /// promotion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let piece = Piece::new(PieceType::Pawn, Color::White);
+    /// let to = Square::A8.bitboard() | Square::B8.bitboard();
+    /// let moves = PieceMoves::new(piece, Square::B7, to);
+    ///
+    /// assert_eq!(moves.moves().len(), 8);
+    /// ```
     pub fn moves(&self) -> Vec<Move> {
         self.into_iter().collect()
     }
@@ -67,28 +106,29 @@ impl Iterator for PieceMovesIter {
 
     fn next(&mut self) -> Option<Self::Item> {
         let to: Square = self.moves.to.least_significant_square()?;
-        self.moves.to = self.moves.to.unset_square(to);
 
         let promotion = if self.moves.piece.piece_type() == PieceType::Pawn
-            && self.promotion < 3
             && (to.rank() == Rank::One || to.rank() == Rank::Eight)
         {
             let promotion = match self.promotion {
-                0 => Some(PieceType::Knight),
-                1 => Some(PieceType::Bishop),
-                2 => Some(PieceType::Rook),
-                3 => Some(PieceType::Queen),
+                0 => PieceType::Knight,
+                1 => PieceType::Bishop,
+                2 => PieceType::Rook,
+                3 => PieceType::Queen,
                 _ => unreachable!(),
             };
 
-            self.promotion += 1;
+            self.promotion = (self.promotion + 1) % 4;
 
-            promotion
+            Some(promotion)
         } else {
-            self.promotion = 0;
             None
         };
 
+        if self.promotion == 0 {
+            self.moves.to = self.moves.to.unset_square(to);
+        }
+
         Some(Move::new(self.moves.from, to, promotion))
     }
 }
@@ -133,7 +173,7 @@ impl fmt::Debug for PieceMoves {
                 }
             }
 
-            s.push_str("\n");
+            s.push('\n');
         }
 
         s.push_str("\n    a b c d e f g h");
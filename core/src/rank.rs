@@ -72,4 +72,36 @@ impl Rank {
 
         BitBoard(BITBOARD << (self as usize * 8))
     }
+
+    /// Returns the digit of the `Rank`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// assert_eq!(Rank::Three.to_char(), '3');
+    /// ```
+    pub const fn to_char(self) -> char {
+        (b'1' + self as u8) as char
+    }
+
+    /// Parses a `Rank` from its digit.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RankError`] if the character is not a rank digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// assert_eq!(Rank::from_char('3').unwrap(), Rank::Three);
+    /// assert!(Rank::from_char('9').is_err());
+    /// ```
+    pub fn from_char(rank: char) -> Result<Self, RankError> {
+        match rank {
+            '1'..='8' => Ok(Rank::new(rank as usize - '1' as usize)),
+            _ => Err(RankError(rank.to_string())),
+        }
+    }
 }
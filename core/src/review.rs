@@ -0,0 +1,79 @@
+use crate::{Color, Evaluation, MoveTree, Nag};
+
+/// The drop in winning chances from which a move is an inaccuracy.
+const INACCURACY: f64 = 0.1;
+
+/// The drop in winning chances from which a move is a mistake.
+const MISTAKE: f64 = 0.2;
+
+/// The drop in winning chances from which a move is a blunder.
+const BLUNDER: f64 = 0.3;
+
+/// Game review for the `MoveTree` struct.
+impl MoveTree {
+    /// Annotates the main line with the evaluations of its positions, and
+    /// judges its moves by the winning chances they lose, the way Lichess
+    /// does.
+    ///
+    /// `color` is the color to move at the root. The first evaluation is the
+    /// one of the root, and the others are the ones after each move of the
+    /// main line. A move losing a tenth of the winning chances is marked as
+    /// dubious (`?!`), a fifth as a mistake (`?`) and three tenths as a
+    /// blunder (`??`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// let mut tree = MoveTree::new();
+    /// let e4 = tree.add_move("e2e4".parse().unwrap());
+    /// let f6 = tree.add_move("f7f6".parse().unwrap());
+    /// let d4 = tree.add_move("d2d4".parse().unwrap());
+    /// let g5 = tree.add_move("g7g5".parse().unwrap());
+    ///
+    /// tree.annotate_mainline(
+    ///     Color::White,
+    ///     &[
+    ///         Evaluation::Centipawns(20),
+    ///         Evaluation::Centipawns(30),
+    ///         Evaluation::Centipawns(90),
+    ///         Evaluation::Centipawns(80),
+    ///         Evaluation::Mate(1),
+    ///     ],
+    /// );
+    ///
+    /// assert!(tree.node(e4).annotation.nags.is_empty());
+    /// assert!(tree.node(d4).annotation.nags.is_empty());
+    /// assert_eq!(tree.node(g5).annotation.nags, vec![Nag::BLUNDER]);
+    /// assert_eq!(tree.node(f6).annotation.eval, Some(Evaluation::Centipawns(90)));
+    /// ```
+    pub fn annotate_mainline(&mut self, color: Color, evals: &[Evaluation]) {
+        let mut id = MoveTree::ROOT;
+        let mut color = color;
+
+        for window in evals.windows(2) {
+            let Some(&next) = self.node(id).children().first() else {
+                break;
+            };
+
+            let loss = match color {
+                Color::White => window[0].winning_chances() - window[1].winning_chances(),
+                Color::Black => window[1].winning_chances() - window[0].winning_chances(),
+            };
+
+            let annotation = &mut self.node_mut(next).annotation;
+            annotation.eval = Some(window[1]);
+
+            if loss >= BLUNDER {
+                annotation.push_nag(Nag::BLUNDER);
+            } else if loss >= MISTAKE {
+                annotation.push_nag(Nag::MISTAKE);
+            } else if loss >= INACCURACY {
+                annotation.push_nag(Nag::DUBIOUS_MOVE);
+            }
+
+            id = next;
+            color = !color;
+        }
+    }
+}
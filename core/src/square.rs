@@ -82,10 +82,32 @@ impl Square {
     /// let square = Square::with_file_rank(File::F, Rank::Four);
     /// assert_eq!(square, Square::F4);
     /// ```
-    pub fn with_file_rank(file: File, rank: Rank) -> Self {
+    pub const fn with_file_rank(file: File, rank: Rank) -> Self {
         Self::new(rank as usize * 8 + file as usize)
     }
 
+    /// Creates a new `Square` from the letter of its [`File`], in lowercase
+    /// or uppercase, and the digit of its [`Rank`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SquareError`] if the file or the rank is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// assert_eq!(Square::from_coords('e', '4').unwrap(), Square::E4);
+    /// assert_eq!(Square::from_coords('E', '4').unwrap(), Square::E4);
+    /// assert!(Square::from_coords('e', '9').is_err());
+    /// ```
+    pub fn from_coords(file: char, rank: char) -> Result<Self, SquareError> {
+        Ok(Self::with_file_rank(
+            File::from_char(file)?,
+            Rank::from_char(rank)?,
+        ))
+    }
+
     /// Returns the [`File`] of the `Square`.
     ///
     /// # Examples
@@ -110,6 +132,30 @@ impl Square {
         Rank::new(self as usize / 8)
     }
 
+    /// Returns the letter of the [`File`] of the `Square`, in lowercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// assert_eq!(Square::G5.file_char(), 'g');
+    /// ```
+    pub const fn file_char(self) -> char {
+        self.file().to_char()
+    }
+
+    /// Returns the digit of the [`Rank`] of the `Square`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_core::*;
+    /// assert_eq!(Square::G5.rank_char(), '5');
+    /// ```
+    pub const fn rank_char(self) -> char {
+        self.rank().to_char()
+    }
+
     /// Returns the [`Color`] of the `Square`.
     ///
     /// # Examples
@@ -119,18 +165,10 @@ impl Square {
     /// assert_eq!(Square::A1.color(), Color::Black);
     /// ```
     pub const fn color(self) -> Color {
-        if self.rank() as u8 % 2 == 0 {
-            if self.file() as u8 % 2 == 0 {
-                Color::Black
-            } else {
-                Color::White
-            }
+        if (self.rank() as u8 + self.file() as u8).is_multiple_of(2) {
+            Color::Black
         } else {
-            if self.file() as u8 % 2 == 0 {
-                Color::White
-            } else {
-                Color::Black
-            }
+            Color::White
         }
     }
 }
@@ -149,7 +187,28 @@ impl Default for Square {
     }
 }
 
-/// Parses a `Square` from a string.
+/// Creates a `Square` from the letter of its file and the digit of its rank,
+/// like [`Square::from_coords`].
+///
+/// # Errors
+///
+/// Returns a [`SquareError`] if the file or the rank is not valid.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// assert_eq!(Square::try_from(('h', '7')).unwrap(), Square::H7);
+/// ```
+impl TryFrom<(char, char)> for Square {
+    type Error = SquareError;
+
+    fn try_from((file, rank): (char, char)) -> Result<Self, Self::Error> {
+        Self::from_coords(file, rank)
+    }
+}
+
+/// Parses a `Square` from a string, with the file in lowercase or uppercase.
 ///
 /// # Errors
 ///
@@ -163,22 +222,19 @@ impl Default for Square {
 ///    "h7".parse::<Square>().unwrap(),
 ///    Square::H7
 /// );
+/// assert_eq!("E4".parse::<Square>().unwrap(), Square::E4);
+/// assert!("é4".parse::<Square>().is_err());
 /// ```
 impl FromStr for Square {
     type Err = SquareError;
 
     fn from_str(str: &str) -> Result<Self, Self::Err> {
-        if str.len() != 2 {
-            return Err(SquareError::Length(str.len()));
-        }
-
-        let file = &str[0..1];
-        let rank = &str[1..2];
+        let chars: Vec<char> = str.chars().collect();
 
-        Ok(Square::with_file_rank(
-            file.to_string().parse()?,
-            rank.to_string().parse()?,
-        ))
+        match chars[..] {
+            [file, rank] => Self::from_coords(file, rank),
+            _ => Err(SquareError::Length(chars.len())),
+        }
     }
 }
 
@@ -197,3 +253,21 @@ impl fmt::Display for Square {
         write!(f, "{}{}", self.file(), self.rank())
     }
 }
+
+/// Generates a random `Square` for fuzzing.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_core::*;
+/// use arbitrary::{Arbitrary, Unstructured};
+///
+/// let mut u = Unstructured::new(&[42]);
+/// assert!(Square::ALL.contains(&Square::arbitrary(&mut u).unwrap()));
+/// ```
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Square {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(&Square::ALL).copied()
+    }
+}
@@ -1,15 +1,206 @@
-// use chess_engine_core::*;
-use chess_engine_movegen::*;
+use std::{process::ExitCode, sync::OnceLock, time::Instant};
 
-fn main() {
-    let board = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
-        .parse::<BoardBuilder>()
-        .unwrap()
-        .build();
+use chess_engine_core::Color;
+use chess_engine_movegen::{
+    eval::{Evaluator, Material},
+    notation::Notation,
+    perft::perft,
+    BoardBuilder,
+};
+use chess_engine_uci::BUILD_INFO;
+use clap::{Parser, Subcommand, ValueEnum};
 
-    println!("{:?}", board);
+/// Command line interface of the chess engine.
+#[derive(Parser)]
+#[command(version, long_version = long_version(), about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Prints a position as a board diagram
+    Show {
+        /// The position, as a FEN string
+        #[arg(default_value = STARTING_FEN)]
+        fen: String,
+
+        /// Draws the board from Black's point of view
+        #[arg(long)]
+        flip: bool,
+
+        /// Draws the pieces with Unicode symbols
+        #[arg(long)]
+        unicode: bool,
+    },
+
+    /// Lists the legal moves of a position
+    Moves {
+        /// The position, as a FEN string
+        #[arg(default_value = STARTING_FEN)]
+        fen: String,
+
+        /// Notation to write the moves in
+        #[arg(long, value_enum, default_value_t = NotationArg::Uci)]
+        notation: NotationArg,
+    },
+
+    /// Counts the leaf nodes of the legal move tree of a position, for each
+    /// move and in total
+    Perft {
+        /// The position, as a FEN string
+        fen: String,
+
+        /// The depth of the tree
+        #[arg(value_parser = clap::value_parser!(u8).range(1..))]
+        depth: u8,
+    },
+
+    /// Prints the material evaluation of a position, in centipawns from the
+    /// point of view of the color to move
+    Eval {
+        /// The position, as a FEN string
+        #[arg(default_value = STARTING_FEN)]
+        fen: String,
+    },
+
+    /// Times perft over a fixed set of positions and prints the nodes per
+    /// second
+    Bench {
+        /// The depth of the perft run on each position
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u8).range(1..))]
+        depth: u8,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum NotationArg {
+    Uci,
+    San,
+    Lan,
+    Iccf,
+}
+
+impl From<NotationArg> for Notation {
+    fn from(notation: NotationArg) -> Self {
+        match notation {
+            NotationArg::Uci => Notation::Uci,
+            NotationArg::San => Notation::San,
+            NotationArg::Lan => Notation::Lan,
+            NotationArg::Iccf => Notation::Iccf,
+        }
+    }
+}
+
+const STARTING_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+/// The positions timed by the `bench` subcommand: the starting position,
+/// Kiwipete, and an endgame rich in en passant and checks.
+const BENCH_POSITIONS: [&str; 3] = [
+    STARTING_FEN,
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+];
 
-    for moves in board.generate_moves() {
-        println!("{:?}", moves);
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Show { fen, flip, unicode } => {
+            let board = match fen.parse::<BoardBuilder>() {
+                Ok(board) => board.build(),
+                Err(error) => return fail(&error),
+            };
+
+            let perspective = if flip { Color::Black } else { Color::White };
+
+            println!(
+                "{}",
+                board
+                    .formatter()
+                    .perspective(perspective)
+                    .unicode(unicode)
+                    .coordinates(true)
+            );
+        }
+        Command::Moves { fen, notation } => {
+            let board = match fen.parse::<BoardBuilder>() {
+                Ok(board) => board.build(),
+                Err(error) => return fail(&error),
+            };
+
+            for mv in board.generate_legal_moves() {
+                println!("{}", board.format_move(mv, notation.into()));
+            }
+        }
+        Command::Perft { fen, depth } => {
+            let mut board = match fen.parse::<BoardBuilder>() {
+                Ok(board) => board.build(),
+                Err(error) => return fail(&error),
+            };
+
+            let mut total = 0;
+            for mv in board.generate_legal_moves() {
+                board.make_move(mv);
+                let nodes = perft(&mut board, depth - 1);
+                board.unmake_move(mv);
+
+                println!("{mv}: {nodes}");
+                total += nodes;
+            }
+
+            println!();
+            println!("Nodes searched: {total}");
+        }
+        Command::Eval { fen } => {
+            let board = match fen.parse::<BoardBuilder>() {
+                Ok(board) => board.build(),
+                Err(error) => return fail(&error),
+            };
+
+            println!("{}", Material.evaluate(&board));
+        }
+        Command::Bench { depth } => {
+            let start = Instant::now();
+            let mut total = 0;
+
+            for fen in BENCH_POSITIONS {
+                let mut board = fen
+                    .parse::<BoardBuilder>()
+                    .expect("bench positions are valid")
+                    .build();
+
+                let nodes = perft(&mut board, depth);
+                println!("{fen}: {nodes}");
+                total += nodes;
+            }
+
+            let elapsed = start.elapsed();
+
+            println!();
+            println!("Nodes searched: {total}");
+            println!("Time: {} ms", elapsed.as_millis());
+            println!(
+                "Nodes per second: {:.0}",
+                total as f64 / elapsed.as_secs_f64()
+            );
+        }
     }
+
+    ExitCode::SUCCESS
+}
+
+/// Returns the version printed by `--version`, with the commit and features
+/// of the build.
+fn long_version() -> &'static str {
+    static LONG_VERSION: OnceLock<String> = OnceLock::new();
+
+    LONG_VERSION.get_or_init(|| BUILD_INFO.long_version())
+}
+
+/// Prints an error and returns a failure exit code.
+fn fail(error: &dyn std::error::Error) -> ExitCode {
+    eprintln!("error: {}", error);
+    ExitCode::FAILURE
 }
@@ -0,0 +1,42 @@
+#ifndef CHESS_ENGINE_H
+#define CHESS_ENGINE_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* An opaque chessboard. */
+typedef struct ChessEngineBoard ChessEngineBoard;
+
+/* Creates a board with the starting position. */
+ChessEngineBoard *chess_engine_board_new(void);
+
+/* Frees a board created by `chess_engine_board_new`. */
+void chess_engine_board_free(ChessEngineBoard *board);
+
+/* Sets the position of a board from a FEN string.
+ *
+ * Returns 0 on success and -1 if the FEN string is invalid, in which case
+ * the board is left unchanged. */
+int chess_engine_board_set_fen(ChessEngineBoard *board, const char *fen);
+
+/* Returns the FEN string of a board. */
+char *chess_engine_board_fen(const ChessEngineBoard *board);
+
+/* Returns the legal moves of a board in UCI notation, separated by spaces. */
+char *chess_engine_board_moves(const ChessEngineBoard *board);
+
+/* Plays a legal move, written in UCI notation, on a board.
+ *
+ * Returns 0 on success and -1 if the move is invalid or illegal, in which
+ * case the board is left unchanged. */
+int chess_engine_board_make_move(ChessEngineBoard *board, const char *mv);
+
+/* Frees a string returned by the library. */
+void chess_engine_string_free(char *string);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
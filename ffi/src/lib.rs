@@ -0,0 +1,255 @@
+#![deny(missing_docs)]
+#![doc = include_str!("../README.md")]
+
+//! C bindings for the chess engine.
+
+use std::{
+    ffi::{c_char, c_int, CStr, CString},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+use chess_engine_core::Move;
+use chess_engine_movegen::{Board, BoardBuilder};
+
+/// An opaque chessboard handed out to C.
+pub struct ChessEngineBoard(Board);
+
+/// Creates a board with the starting position.
+///
+/// The board must be freed with [`chess_engine_board_free`].
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_ffi::*;
+/// let board = chess_engine_board_new();
+/// unsafe { chess_engine_board_free(board) };
+/// ```
+#[no_mangle]
+pub extern "C" fn chess_engine_board_new() -> *mut ChessEngineBoard {
+    Box::into_raw(Box::new(ChessEngineBoard(Board::default())))
+}
+
+/// Frees a board created by [`chess_engine_board_new`].
+///
+/// # Safety
+///
+/// `board` must be null or a pointer returned by [`chess_engine_board_new`]
+/// that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn chess_engine_board_free(board: *mut ChessEngineBoard) {
+    if !board.is_null() {
+        drop(Box::from_raw(board));
+    }
+}
+
+/// Sets the position of a board from a FEN string.
+///
+/// Returns `0` on success and `-1` if the FEN string is invalid, in which
+/// case the board is left unchanged.
+///
+/// # Safety
+///
+/// `board` must be a valid board and `fen` a valid nul-terminated string.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_ffi::*;
+/// # use std::ffi::CString;
+/// let board = chess_engine_board_new();
+/// let fen = CString::new("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1").unwrap();
+/// let invalid = CString::new("4k3/8/8 w - - 0 1").unwrap();
+///
+/// unsafe {
+///     assert_eq!(chess_engine_board_set_fen(board, fen.as_ptr()), 0);
+///     assert_eq!(chess_engine_board_set_fen(board, invalid.as_ptr()), -1);
+///     chess_engine_board_free(board);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn chess_engine_board_set_fen(
+    board: *mut ChessEngineBoard,
+    fen: *const c_char,
+) -> c_int {
+    if board.is_null() || fen.is_null() {
+        return -1;
+    }
+
+    let Ok(fen) = CStr::from_ptr(fen).to_str() else {
+        return -1;
+    };
+
+    catch_panic(-1, || match fen.parse::<BoardBuilder>() {
+        Ok(board_builder) => {
+            (*board).0 = board_builder.build();
+            0
+        }
+        Err(_) => -1,
+    })
+}
+
+/// Returns the FEN string of a board.
+///
+/// The string must be freed with [`chess_engine_string_free`].
+///
+/// # Safety
+///
+/// `board` must be a valid board.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_ffi::*;
+/// # use std::ffi::CStr;
+/// let board = chess_engine_board_new();
+///
+/// unsafe {
+///     let fen = chess_engine_board_fen(board);
+///     assert_eq!(
+///         CStr::from_ptr(fen).to_str().unwrap(),
+///         "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+///     );
+///     chess_engine_string_free(fen);
+///     chess_engine_board_free(board);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn chess_engine_board_fen(board: *const ChessEngineBoard) -> *mut c_char {
+    if board.is_null() {
+        return ptr::null_mut();
+    }
+
+    catch_panic(ptr::null_mut(), || into_c_string((*board).0.to_string()))
+}
+
+/// Returns the legal moves of a board in UCI notation, separated by spaces.
+///
+/// The string must be freed with [`chess_engine_string_free`].
+///
+/// # Safety
+///
+/// `board` must be a valid board.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_ffi::*;
+/// # use std::ffi::{CStr, CString};
+/// let board = chess_engine_board_new();
+/// let fen = CString::new("4k3/8/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+///
+/// unsafe {
+///     chess_engine_board_set_fen(board, fen.as_ptr());
+///
+///     let moves = chess_engine_board_moves(board);
+///     let moves = CStr::from_ptr(moves).to_str().unwrap().to_string();
+///     assert!(moves.split(' ').any(|m| m == "a1a8"));
+///
+///     chess_engine_board_free(board);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn chess_engine_board_moves(board: *const ChessEngineBoard) -> *mut c_char {
+    if board.is_null() {
+        return ptr::null_mut();
+    }
+
+    catch_panic(ptr::null_mut(), || {
+        let moves: Vec<String> = (*board)
+            .0
+            .generate_legal_moves()
+            .iter()
+            .map(|m| m.to_string())
+            .collect();
+
+        into_c_string(moves.join(" "))
+    })
+}
+
+/// Plays a legal move, written in UCI notation, on a board.
+///
+/// Returns `0` on success and `-1` if the move is invalid or illegal, in
+/// which case the board is left unchanged.
+///
+/// # Safety
+///
+/// `board` must be a valid board and `mv` a valid nul-terminated string.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_ffi::*;
+/// # use std::ffi::{CStr, CString};
+/// let board = chess_engine_board_new();
+/// let mv = CString::new("e2e4").unwrap();
+/// let illegal = CString::new("e2e5").unwrap();
+///
+/// unsafe {
+///     assert_eq!(chess_engine_board_make_move(board, mv.as_ptr()), 0);
+///     assert_eq!(chess_engine_board_make_move(board, illegal.as_ptr()), -1);
+///
+///     let fen = chess_engine_board_fen(board);
+///     assert_eq!(
+///         CStr::from_ptr(fen).to_str().unwrap(),
+///         "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+///     );
+///     chess_engine_string_free(fen);
+///
+///     // castling rights without a rook don't castle
+///     let fen = CString::new("4k3/8/8/8/8/8/8/4K3 w KQ - 0 1").unwrap();
+///     let castling = CString::new("e1g1").unwrap();
+///     chess_engine_board_set_fen(board, fen.as_ptr());
+///     assert_eq!(chess_engine_board_make_move(board, castling.as_ptr()), -1);
+///
+///     chess_engine_board_free(board);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn chess_engine_board_make_move(
+    board: *mut ChessEngineBoard,
+    mv: *const c_char,
+) -> c_int {
+    if board.is_null() || mv.is_null() {
+        return -1;
+    }
+
+    let Ok(Ok(mv)) = CStr::from_ptr(mv).to_str().map(str::parse::<Move>) else {
+        return -1;
+    };
+
+    catch_panic(-1, || {
+        if !(*board).0.generate_legal_moves().contains(&mv) {
+            return -1;
+        }
+
+        (*board).0.make_move(mv);
+        0
+    })
+}
+
+/// Frees a string returned by the library.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by the library that has not
+/// been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn chess_engine_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Runs `f`, returning `fallback` instead of unwinding into C if it panics.
+fn catch_panic<T>(fallback: T, f: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(fallback)
+}
+
+/// Hands a Rust string over to C.
+fn into_c_string(string: String) -> *mut c_char {
+    CString::new(string)
+        .map(CString::into_raw)
+        .unwrap_or(ptr::null_mut())
+}
@@ -0,0 +1,33 @@
+//! Feeds arbitrary strings to the parsers, which must return a value or an
+//! error, and never panic.
+
+#![no_main]
+
+use chess_engine_core::{BitBoard, Move, Square};
+use chess_engine_movegen::{fen::detect_variant, notation::Notation, Board, BoardBuilder};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    let _ = detect_variant(input);
+    let _ = input.parse::<Square>();
+    let _ = input.parse::<Move>();
+    let _ = input.parse::<BitBoard>();
+
+    // the first line is a position, the second a move to parse on it
+    let (fen, mv) = input.split_once('\n').unwrap_or((input, ""));
+
+    let board = match fen.parse::<BoardBuilder>() {
+        Ok(board_builder) => board_builder.build(),
+        Err(_) => Board::default(),
+    };
+
+    for notation in Notation::ALL {
+        let _ = board.parse_move(mv, notation);
+    }
+
+    let _ = board.convert_move(mv, Notation::San);
+});
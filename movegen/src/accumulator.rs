@@ -0,0 +1,86 @@
+use chess_engine_core::{Piece, Square};
+
+use crate::Board;
+
+/// An incremental representation of a board, like the accumulator of an
+/// NNUE, notified of every piece put on or taken off the board.
+///
+/// The unit type `()` is an accumulator that ignores the changes.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// # use chess_engine_core::*;
+/// /// Counts the pieces on the board.
+/// #[derive(Default)]
+/// struct Count(usize);
+///
+/// impl Accumulator for Count {
+///     fn add_piece(&mut self, _piece: Piece, _square: Square) {
+///         self.0 += 1;
+///     }
+///
+///     fn remove_piece(&mut self, _piece: Piece, _square: Square) {
+///         self.0 -= 1;
+///     }
+/// }
+///
+/// let mut board = Board::default();
+/// let mut count = Count::default();
+/// board.accumulate(&mut count);
+///
+/// // the knight on g1 takes the pawn on e7
+/// let knight = Piece::new(PieceType::Knight, Color::White);
+/// board.remove_piece_with(Square::G1, &mut count);
+/// board.put_piece_with(knight, Square::E7, &mut count);
+///
+/// assert_eq!(count.0, 31);
+/// ```
+pub trait Accumulator {
+    /// Called after a piece is put on an empty square.
+    fn add_piece(&mut self, piece: Piece, square: Square);
+
+    /// Called after a piece is taken off a square.
+    fn remove_piece(&mut self, piece: Piece, square: Square);
+}
+
+impl Accumulator for () {
+    fn add_piece(&mut self, _piece: Piece, _square: Square) {}
+
+    fn remove_piece(&mut self, _piece: Piece, _square: Square) {}
+}
+
+/// Accumulator updates for the `Board` struct.
+impl Board {
+    /// Adds every piece of the board to an accumulator, to initialize it.
+    pub fn accumulate(&self, accumulator: &mut impl Accumulator) {
+        for piece in Piece::ALL {
+            for square in self.piece_bitboard(piece) {
+                accumulator.add_piece(piece, square);
+            }
+        }
+    }
+
+    /// Puts a [`Piece`] on a [`Square`] like [`Board::put_piece`], replacing
+    /// the piece on the square, if any, and notifies the accumulator.
+    pub fn put_piece_with(
+        &mut self,
+        piece: Piece,
+        square: Square,
+        accumulator: &mut impl Accumulator,
+    ) {
+        self.remove_piece_with(square, accumulator);
+        self.put_piece(piece, square);
+        accumulator.add_piece(piece, square);
+    }
+
+    /// Removes the [`Piece`] on a [`Square`] like [`Board::remove_piece`],
+    /// and notifies the accumulator if there was one.
+    pub fn remove_piece_with(&mut self, square: Square, accumulator: &mut impl Accumulator) {
+        if let Some(piece) = self.get_piece(square) {
+            self.remove_piece(square);
+            accumulator.remove_piece(piece, square);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use chess_engine_core::{BitBoard, Color, Piece, PieceType, Square};
+
+use crate::{get_attacks, get_bishop_attacks, get_rook_attacks, Board};
+
+/// The attackers of every square, for both colors.
+///
+/// Attackers of the color of the piece on a square are its defenders.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// # use chess_engine_core::*;
+/// let board = "4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1"
+///     .parse::<BoardBuilder>()
+///     .unwrap()
+///     .build();
+///
+/// let attack_map = board.attack_map();
+///
+/// assert_eq!(attack_map.attackers(Square::D5, Color::White), Square::E4.bitboard());
+/// assert_eq!(attack_map.hanging(Color::Black), Square::D5.bitboard());
+/// ```
+#[derive(Clone, Debug)]
+pub struct AttackMap {
+    attackers: [[BitBoard; Square::LEN]; Color::LEN],
+    colors: [BitBoard; Color::LEN],
+}
+
+impl AttackMap {
+    /// Returns the pieces of a color attacking a square.
+    pub fn attackers(&self, square: Square, color: Color) -> BitBoard {
+        self.attackers[color as usize][square as usize]
+    }
+
+    /// Returns the pieces defending the piece on a square, or an empty
+    /// bitboard if the square is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let attack_map = Board::default().attack_map();
+    ///
+    /// assert_eq!(
+    ///     attack_map.defenders(Square::F2),
+    ///     Square::E1.bitboard()
+    /// );
+    /// assert_eq!(attack_map.defenders(Square::E4), BitBoard::EMPTY);
+    /// ```
+    pub fn defenders(&self, square: Square) -> BitBoard {
+        Color::ALL
+            .into_iter()
+            .find(|color| self.colors[*color as usize].is_get_square(square))
+            .map_or(BitBoard::EMPTY, |color| self.attackers(square, color))
+    }
+
+    /// Returns the squares attacked by a color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(
+    ///     board.attack_map().attacked(Color::Black),
+    ///     board.attacked_bitboard(Color::Black)
+    /// );
+    /// ```
+    pub fn attacked(&self, color: Color) -> BitBoard {
+        Square::ALL
+            .into_iter()
+            .filter(|square| !self.attackers(*square, color).is_empty())
+            .fold(BitBoard::EMPTY, |attacked, square| {
+                attacked.set_square(square)
+            })
+    }
+
+    /// Returns the pieces of a color attacked by the other color and not
+    /// defended.
+    pub fn hanging(&self, color: Color) -> BitBoard {
+        self.colors[color as usize]
+            .into_iter()
+            .filter(|square| {
+                !self.attackers(*square, !color).is_empty()
+                    && self.attackers(*square, color).is_empty()
+            })
+            .fold(BitBoard::EMPTY, |hanging, square| {
+                hanging.set_square(square)
+            })
+    }
+}
+
+/// Attack maps for the `Board` struct.
+impl Board {
+    /// Returns the squares attacked by the piece on a square, given the
+    /// pieces on the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.piece_attacks(Square::A1), Square::A2.bitboard() | Square::B1.bitboard());
+    /// assert_eq!(board.piece_attacks(Square::A4), BitBoard::EMPTY);
+    /// ```
+    pub fn piece_attacks(&self, square: Square) -> BitBoard {
+        let Some(piece) = self.get_piece(square) else {
+            return BitBoard::EMPTY;
+        };
+
+        get_attacks(piece, square, self.both_bitboard())
+    }
+
+    /// Returns the pieces of both colors attacking a square, each with the
+    /// squares its attack goes through, to explain why a square is unsafe.
+    ///
+    /// The path of a rook, bishop or queen is the squares between it and the
+    /// square. Knights, pawns and kings jump to the square, so their path is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/8/8/2n5/8/8/B3K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     board.attack_paths(Square::D2),
+    ///     vec![
+    ///         (Square::E1, BitBoard::EMPTY),
+    ///         (Square::C4, BitBoard::EMPTY),
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     board.attack_paths(Square::D4),
+    ///     vec![(Square::A1, Square::B2.bitboard() | Square::C3.bitboard())]
+    /// );
+    /// ```
+    pub fn attack_paths(&self, square: Square) -> Vec<(Square, BitBoard)> {
+        // the square blocks the rays, so they stop at it even if it is empty
+        let occupancy = self.both_bitboard().set_square(square);
+
+        self.both_bitboard()
+            .into_iter()
+            .filter(|&from| self.piece_attacks(from).is_get_square(square))
+            .map(|from| {
+                let piece_type = self.get_piece(from).map(|piece| piece.piece_type());
+                let straight = from.file() == square.file() || from.rank() == square.rank();
+
+                let path = match piece_type {
+                    Some(PieceType::Rook | PieceType::Queen) if straight => {
+                        get_rook_attacks(from, occupancy) & get_rook_attacks(square, occupancy)
+                    }
+                    Some(PieceType::Bishop | PieceType::Queen) if !straight => {
+                        get_bishop_attacks(from, occupancy) & get_bishop_attacks(square, occupancy)
+                    }
+                    _ => BitBoard::EMPTY,
+                };
+
+                (from, path)
+            })
+            .collect()
+    }
+
+    /// Returns the attackers and defenders of every square.
+    ///
+    /// Each piece's attack set is computed once, then spread to the squares
+    /// it attacks.
+    pub fn attack_map(&self) -> AttackMap {
+        let mut attackers = [[BitBoard::EMPTY; Square::LEN]; Color::LEN];
+
+        for color in Color::ALL {
+            for piece_type in PieceType::ALL {
+                for from in self.piece_bitboard(Piece::new(piece_type, color)) {
+                    for to in self.piece_attacks(from) {
+                        attackers[color as usize][to as usize] =
+                            attackers[color as usize][to as usize].set_square(from);
+                    }
+                }
+            }
+        }
+
+        AttackMap {
+            attackers,
+            colors: [
+                self.color_bitboard(Color::White),
+                self.color_bitboard(Color::Black),
+            ],
+        }
+    }
+}
@@ -1,8 +1,10 @@
 use std::fmt;
 
-use chess_engine_core::{BitBoard, CastleRightsType, Color, File, Piece, PieceType, Rank, Square};
+use chess_engine_core::{BitBoard, CastleRightsType, Color, Piece, PieceType, Square};
 
-use crate::{BoardBuilder, CastleRights, State, ZOBRIST};
+use crate::{
+    BoardBuilder, BoardFormatter, CastleRights, State, Zobrist, VERIFICATION_ZOBRIST, ZOBRIST,
+};
 
 /// Chessboard representation.
 ///
@@ -175,6 +177,21 @@ impl Board {
         BoardBuilder::new()
     }
 
+    /// Returns a new [`BoardFormatter`] to draw the board as text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let board = Board::default();
+    /// let mut formatter = board.formatter();
+    /// formatter.coordinates(false);
+    /// println!("{}", formatter);
+    /// ```
+    pub fn formatter(&self) -> BoardFormatter<'_> {
+        BoardFormatter::new(self)
+    }
+
     /// Puts a [`Piece`] on a [`Square`] on the board.
     ///
     /// # Examples
@@ -191,6 +208,32 @@ impl Board {
         self.piece_types_bitboards[piece_type] =
             self.piece_types_bitboards[piece_type].set_square(square);
         self.color_bitboards[color] = self.color_bitboards[color].set_square(square);
+
+        self.toggle_key(|zobrist| zobrist.piece(square, piece));
+    }
+
+    /// Removes the [`Piece`] on a [`Square`] of the board, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let mut board = Board::default();
+    /// board.remove_piece("a1".parse().unwrap());
+    /// assert_eq!(board.get_piece("a1".parse().unwrap()), None);
+    /// ```
+    pub fn remove_piece(&mut self, square: Square) {
+        if let Some(piece) = self.get_piece(square) {
+            self.toggle_key(|zobrist| zobrist.piece(square, piece));
+        }
+
+        for bitboard in self
+            .piece_types_bitboards
+            .iter_mut()
+            .chain(self.color_bitboards.iter_mut())
+        {
+            *bitboard = bitboard.unset_square(square);
+        }
     }
 
     /// Retruns a [`Piece`] from a [`Square`] on the board.
@@ -205,13 +248,9 @@ impl Board {
     /// assert_eq!(piece, Some(Piece::new(PieceType::Rook, Color::White)));
     /// ```
     pub fn get_piece(&self, square: Square) -> Option<Piece> {
-        for piece in Piece::ALL {
-            if self.piece_bitboard(piece).is_get_square(square) {
-                return Some(piece);
-            }
-        }
-
-        None
+        Piece::ALL
+            .into_iter()
+            .find(|&piece| self.piece_bitboard(piece).is_get_square(square))
     }
 
     /// Returns the hash of the board.
@@ -224,7 +263,45 @@ impl Board {
     /// let hash = board.hash();
     /// ```
     pub fn hash(&self) -> u64 {
-        let mut hash = self.state.partial_hash();
+        self.hash_with(&ZOBRIST)
+    }
+
+    /// Returns the 32-bit verification key of the board.
+    ///
+    /// The key is drawn from other random numbers than the hash, so two
+    /// boards whose hashes collide almost never share it too. Caches keyed
+    /// by the hash can store it to detect collisions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let board = Board::default();
+    /// assert_eq!(board.verification_key(), Board::default().verification_key());
+    /// ```
+    pub fn verification_key(&self) -> u32 {
+        self.hash_with(&VERIFICATION_ZOBRIST) as u32
+    }
+
+    /// Toggles a key in the hash and the verification key kept in the
+    /// state, so they follow the changes of the board without being
+    /// computed again.
+    pub(crate) fn toggle_key(&mut self, key: impl Fn(&Zobrist) -> u64) {
+        self.state.set_hash(self.state.hash() ^ key(&ZOBRIST));
+        self.state.set_verification_key(
+            self.state.verification_key() ^ key(&VERIFICATION_ZOBRIST) as u32,
+        );
+    }
+
+    /// Sets the hash and the verification key kept in the state to the ones
+    /// of the board.
+    pub(crate) fn sync_keys(&mut self) {
+        self.state.set_hash(self.hash());
+        self.state.set_verification_key(self.verification_key());
+    }
+
+    pub(crate) fn hash_with(&self, zobrist: &Zobrist) -> u64 {
+        let mut hash = self.state.partial_hash_with(zobrist);
 
         for piece_type in 0..PieceType::LEN {
             let piece_type = PieceType::new(piece_type);
@@ -232,7 +309,7 @@ impl Board {
                 let piece = Piece::new(piece_type, Color::new(color));
 
                 for square in self.piece_bitboard(piece) {
-                    hash ^= ZOBRIST.piece(square, piece);
+                    hash ^= zobrist.piece(square, piece);
                 }
             }
         }
@@ -270,7 +347,7 @@ impl Board {
 /// ```
 impl Default for Board {
     fn default() -> Self {
-        Self {
+        let mut board = Self {
             piece_types_bitboards: [
                 BitBoard(0x00FF00000000FF00),
                 BitBoard(0x4200000000000042),
@@ -288,54 +365,54 @@ impl Default for Board {
                 1,
             ),
             history: Vec::with_capacity(Self::AVERAGE_MOVES),
-        }
+        };
+
+        board.sync_keys();
+        board
     }
 }
 
-impl fmt::Display for Board {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+/// Converts a `Board` back to a [`BoardBuilder`] of the same position.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// let mut board = Board::default();
+/// board.make_move("e2e4".parse().unwrap());
+///
+/// let mut builder = BoardBuilder::from(&board);
+/// assert_eq!(builder.to_string(), board.to_string());
+///
+/// builder.mirror();
+/// assert_eq!(
+///     builder.to_string(),
+///     "rnbqkbnr/pppp1ppp/8/4p3/8/8/PPPPPPPP/RNBQKBNR w KQkq e6 0 1"
+/// );
+/// ```
+impl From<&Board> for BoardBuilder {
+    fn from(board: &Board) -> Self {
         let mut pieces = [None; Square::LEN];
 
         for square in Square::ALL {
-            pieces[square as usize] = self.get_piece(square);
+            pieces[square as usize] = board.get_piece(square);
         }
 
-        let board_builder = BoardBuilder {
-            pieces: pieces,
-            state: self.state,
-        };
+        BoardBuilder {
+            pieces,
+            state: board.state,
+        }
+    }
+}
 
-        write!(f, "{}", board_builder.to_string())
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", BoardBuilder::from(self))
     }
 }
 
 impl fmt::Debug for Board {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut s = String::from("\n");
-
-        for rank in Rank::ALL.into_iter().rev() {
-            s.push_str(&format!("  {} ", rank));
-
-            'file: for file in File::ALL {
-                let square = Square::with_file_rank(file, rank);
-
-                for piece in Piece::ALL {
-                    if self.piece_bitboard(piece).is_get_square(square) {
-                        s.push_str(&format!("{:?} ", piece));
-                        continue 'file;
-                    }
-                }
-
-                s.push_str(". ");
-            }
-
-            s.push_str("\n");
-        }
-
-        s.push_str("\n    a b c d e f g h\n\n");
-
-        s.push_str(&format!("{}", self.to_string()));
-
-        write!(f, "{}", s)
+        write!(f, "\n{}\n{}", self.formatter(), self)
     }
 }
@@ -1,6 +1,6 @@
 use crate::{Board, CastleRights, State};
 
-use chess_engine_core::{Color, Piece, Square};
+use chess_engine_core::{CastleRightsType, Color, File, Piece, PieceType, Rank, Square};
 
 /// A builder for creating a [`Board`].
 ///
@@ -148,8 +148,123 @@ impl BoardBuilder {
         self
     }
 
+    /// Creates a `BoardBuilder` with pieces on squares, white to move and no
+    /// castling rights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let builder = BoardBuilder::from_pieces(&[
+    ///     (Square::E1, Piece::new(PieceType::King, Color::White)),
+    ///     (Square::E8, Piece::new(PieceType::King, Color::Black)),
+    ///     (Square::A1, Piece::new(PieceType::Rook, Color::White)),
+    /// ]);
+    ///
+    /// assert_eq!(builder.to_string(), "4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+    /// ```
+    pub fn from_pieces(pieces: &[(Square, Piece)]) -> Self {
+        let mut builder = Self::new();
+
+        for &(square, piece) in pieces {
+            builder.put_piece(piece, square);
+        }
+
+        builder
+    }
+
+    /// Mirrors the board between the colors: flips the ranks and swaps the
+    /// colors of the pieces, the castling rights and the color to move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let mut builder: BoardBuilder = "4k3/8/8/8/4P3/8/8/R3K3 b Q e3 0 1".parse().unwrap();
+    /// builder.mirror();
+    ///
+    /// assert_eq!(builder.to_string(), "r3k3/8/8/4p3/8/8/8/4K3 w q e6 0 1");
+    /// ```
+    pub fn mirror(&mut self) -> &mut BoardBuilder {
+        let mut pieces = [None; Square::LEN];
+
+        for (square, piece) in self.pieces.iter().enumerate() {
+            pieces[square ^ 56] = piece.map(|piece| Piece::new(piece.piece_type(), !piece.color()));
+        }
+
+        let color = !self.state.color();
+        let [white, black] = self.state.castling_rights().0;
+        let en_passant_square = self
+            .state
+            .en_passant_square()
+            .map(|square| Square::new(square as usize ^ 56));
+
+        self.pieces(pieces)
+            .color(color)
+            .castling_rights(CastleRights([black, white]))
+            .en_passant_square(en_passant_square)
+    }
+
+    /// Mirrors the board between the sides: flips the files of the pieces
+    /// and of the en passant square.
+    ///
+    /// The castling rights are kept, so this is only the same position for
+    /// boards without them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let mut builder: BoardBuilder = "4k3/8/8/8/4Pp2/8/8/R3K3 b - e3 0 1".parse().unwrap();
+    /// builder.mirror_files();
+    ///
+    /// assert_eq!(builder.to_string(), "3k4/8/8/8/2pP4/8/8/3K3R b - d3 0 1");
+    /// ```
+    pub fn mirror_files(&mut self) -> &mut BoardBuilder {
+        let mut pieces = [None; Square::LEN];
+
+        for (square, piece) in self.pieces.iter().enumerate() {
+            pieces[square ^ 7] = *piece;
+        }
+
+        let en_passant_square = self
+            .state
+            .en_passant_square()
+            .map(|square| Square::new(square as usize ^ 7));
+
+        self.pieces(pieces).en_passant_square(en_passant_square)
+    }
+
+    /// Removes the pieces of a [`PieceType`] of both colors from the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let mut builder = BoardBuilder::default();
+    /// builder.put_piece("P".parse().unwrap(), Square::E4);
+    /// builder.put_piece("k".parse().unwrap(), Square::E8);
+    /// builder.without(PieceType::Pawn);
+    ///
+    /// assert_eq!(builder.to_string(), "4k3/8/8/8/8/8/8/8 w - - 0 1");
+    /// ```
+    pub fn without(&mut self, piece_type: PieceType) -> &mut BoardBuilder {
+        for piece in self.pieces.iter_mut() {
+            if piece.is_some_and(|piece| piece.piece_type() == piece_type) {
+                *piece = None;
+            }
+        }
+
+        self
+    }
+
     /// Builds the [`Board`].
     ///
+    /// Castling rights are only kept for a king on its starting square with
+    /// its rook in the corner, so castling never moves a missing rook.
+    ///
     /// # Examples
     ///
     /// ```
@@ -159,11 +274,31 @@ impl BoardBuilder {
     /// builder.castling_rights(CastleRights::default());
     /// let board = builder.build();
     /// ```
+    ///
+    /// Rights without a rook are dropped:
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let mut board = "4k3/8/8/8/8/8/8/R3K3 w KQ - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(board.to_string(), "4k3/8/8/8/8/8/8/R3K3 w Q - 0 1");
+    ///
+    /// let castling = Move::new(Square::E1, Square::G1, None);
+    /// assert!(!board.generate_moves().contains(&castling));
+    /// assert!(board.check_move(castling).is_err());
+    /// ```
     pub fn build(self) -> Board {
+        let mut state = self.state;
+        state.set_castling_rights(self.playable_castling_rights());
+
         let mut board = Board {
             piece_types_bitboards: Default::default(),
             color_bitboards: Default::default(),
-            state: self.state,
+            state,
             history: Vec::with_capacity(Board::AVERAGE_MOVES),
         };
 
@@ -173,8 +308,45 @@ impl BoardBuilder {
             }
         }
 
+        board.sync_keys();
         board
     }
+
+    /// Returns the castling rights of the sides whose king and rook are
+    /// still on their starting squares.
+    fn playable_castling_rights(&self) -> CastleRights {
+        let mut castling_rights = self.state.castling_rights();
+
+        for color in Color::ALL {
+            let rank = match color {
+                Color::White => Rank::One,
+                Color::Black => Rank::Eight,
+            };
+
+            let is_on = |piece_type, file| {
+                self.pieces[Square::with_file_rank(file, rank) as usize]
+                    == Some(Piece::new(piece_type, color))
+            };
+
+            let mut rights = castling_rights.0[color as usize] as usize;
+
+            if !is_on(PieceType::King, File::E) {
+                rights = CastleRightsType::None as usize;
+            }
+
+            if !is_on(PieceType::Rook, File::H) {
+                rights &= !(CastleRightsType::KingSide as usize);
+            }
+
+            if !is_on(PieceType::Rook, File::A) {
+                rights &= !(CastleRightsType::QueenSide as usize);
+            }
+
+            castling_rights.0[color as usize] = CastleRightsType::new(rights);
+        }
+
+        castling_rights
+    }
 }
 
 /// Default implementation for `BoardBuilder`, empty board with white to move
@@ -0,0 +1,203 @@
+use std::fmt;
+
+use chess_engine_core::{BitBoard, Color, File, Rank, Square};
+
+use crate::Board;
+
+/// A configurable text renderer for a [`Board`].
+///
+/// By default the board is drawn from White's perspective, with coordinates
+/// and Unicode pieces, the same way as the [`Debug`](fmt::Debug) output of the
+/// board.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// # use chess_engine_core::*;
+/// let board = Board::default();
+///
+/// let mut formatter = board.formatter();
+/// formatter
+///     .perspective(Color::Black)
+///     .unicode(false)
+///     .highlight(Square::E2.bitboard());
+///
+/// assert_eq!(
+///     formatter.to_string(),
+///     concat!(
+///         "  1 R N B K Q B N R \n",
+///         "  2 P P P[P]P P P P \n",
+///         "  3 . . . . . . . . \n",
+///         "  4 . . . . . . . . \n",
+///         "  5 . . . . . . . . \n",
+///         "  6 . . . . . . . . \n",
+///         "  7 p p p p p p p p \n",
+///         "  8 r n b k q b n r \n",
+///         "\n",
+///         "    h g f e d c b a\n",
+///     )
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct BoardFormatter<'a> {
+    board: &'a Board,
+    perspective: Color,
+    coordinates: bool,
+    unicode: bool,
+    highlights: BitBoard,
+}
+
+impl<'a> BoardFormatter<'a> {
+    /// Creates a new `BoardFormatter` for a [`Board`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let board = Board::default();
+    /// let formatter = BoardFormatter::new(&board);
+    /// ```
+    pub fn new(board: &'a Board) -> Self {
+        Self {
+            board,
+            perspective: Color::White,
+            coordinates: true,
+            unicode: true,
+            highlights: BitBoard::EMPTY,
+        }
+    }
+
+    /// Sets the [`Color`] drawn at the bottom of the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = Board::default();
+    /// let mut formatter = board.formatter();
+    /// formatter.perspective(Color::Black);
+    /// ```
+    pub fn perspective(&mut self, perspective: Color) -> &mut BoardFormatter<'a> {
+        self.perspective = perspective;
+        self
+    }
+
+    /// Sets whether the rank and file coordinates are drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let board = Board::default();
+    /// let mut formatter = board.formatter();
+    /// formatter.coordinates(false);
+    ///
+    /// assert!(formatter.to_string().starts_with(" ♜ ♞ ♝ ♛ ♚ ♝ ♞ ♜ \n"));
+    /// ```
+    pub fn coordinates(&mut self, coordinates: bool) -> &mut BoardFormatter<'a> {
+        self.coordinates = coordinates;
+        self
+    }
+
+    /// Sets whether the pieces are drawn as Unicode symbols or as FEN letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let board = Board::default();
+    /// let mut formatter = board.formatter();
+    /// formatter.unicode(false);
+    ///
+    /// assert!(formatter.to_string().starts_with("  8 r n b q k b n r \n"));
+    /// ```
+    pub fn unicode(&mut self, unicode: bool) -> &mut BoardFormatter<'a> {
+        self.unicode = unicode;
+        self
+    }
+
+    /// Sets the squares drawn between brackets.
+    ///
+    /// Neighbouring highlighted squares on a rank share the same brackets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = Board::default();
+    /// let mut formatter = board.formatter();
+    /// formatter
+    ///     .unicode(false)
+    ///     .highlight(Square::A8.bitboard() | Square::B8.bitboard());
+    ///
+    /// assert!(formatter.to_string().starts_with("  8[r n]b q k b n r \n"));
+    /// ```
+    pub fn highlight(&mut self, highlights: BitBoard) -> &mut BoardFormatter<'a> {
+        self.highlights = highlights;
+        self
+    }
+}
+
+/// Draws the board, one rank per line, followed by the files if the
+/// coordinates are enabled.
+impl fmt::Display for BoardFormatter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (ranks, files) = match self.perspective {
+            Color::White => (
+                Rank::ALL.into_iter().rev().collect::<Vec<_>>(),
+                File::ALL.to_vec(),
+            ),
+            Color::Black => (
+                Rank::ALL.to_vec(),
+                File::ALL.into_iter().rev().collect::<Vec<_>>(),
+            ),
+        };
+
+        let mut s = String::new();
+
+        for rank in &ranks {
+            if self.coordinates {
+                s.push_str(&format!("  {}", rank));
+            }
+
+            let mut highlighted = false;
+
+            for file in &files {
+                let square = Square::with_file_rank(*file, *rank);
+                let is_highlighted = self.highlights.is_get_square(square);
+
+                s.push(match (highlighted, is_highlighted) {
+                    (false, true) => '[',
+                    (true, false) => ']',
+                    _ => ' ',
+                });
+
+                match self.board.get_piece(square) {
+                    Some(piece) if self.unicode => s.push_str(&format!("{:?}", piece)),
+                    Some(piece) => s.push_str(&piece.to_string()),
+                    None => s.push('.'),
+                }
+
+                highlighted = is_highlighted;
+            }
+
+            s.push(if highlighted { ']' } else { ' ' });
+            s.push('\n');
+        }
+
+        if self.coordinates {
+            s.push_str("\n   ");
+
+            for file in &files {
+                s.push_str(&format!(" {}", file));
+            }
+
+            s.push('\n');
+        }
+
+        write!(f, "{}", s)
+    }
+}
@@ -3,5 +3,5 @@ mod gen_consts;
 fn main() {
     println!("cargo:rerun-if-changed=src/build.rs");
 
-    gen_consts::generate_all();
+    gen_consts::generate::generate_all();
 }
@@ -0,0 +1,97 @@
+//! Identity of the compiled engine.
+//!
+//! [`BUILD_INFO`] records the version and compile features of the binary, so
+//! the `uci` identification and test results can be traced back to the exact
+//! build that produced them. The commit is added with
+//! [`BuildInfo::with_git_hash`] by the crates with a build script reading it,
+//! so this crate isn't rebuilt on every commit.
+
+use std::fmt;
+
+/// Name, version and build options of the engine.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::build_info::*;
+/// println!("{}", BUILD_INFO);
+/// assert!(BUILD_INFO.to_string().starts_with("Chess Engine 0."));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// The name of the engine.
+    pub name: &'static str,
+    /// The version of the crates, from their manifests.
+    pub version: &'static str,
+    /// The short hash of the commit built, or `None` if it was not built
+    /// from a git checkout.
+    pub git_hash: Option<&'static str>,
+    /// The optional features compiled in, like `json`.
+    pub features: &'static [&'static str],
+}
+
+/// The [`BuildInfo`] of this build, without its commit.
+pub const BUILD_INFO: BuildInfo = BuildInfo {
+    name: "Chess Engine",
+    version: env!("CARGO_PKG_VERSION"),
+    git_hash: None,
+    features: FEATURES,
+};
+
+const FEATURES: &[&str] = &[
+    #[cfg(feature = "arbitrary")]
+    "arbitrary",
+    #[cfg(feature = "image")]
+    "image",
+    #[cfg(feature = "json")]
+    "json",
+];
+
+/// Methods for the `BuildInfo` struct.
+impl BuildInfo {
+    /// Returns the build info with the short hash of the commit built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::build_info::*;
+    /// let build_info = BUILD_INFO.with_git_hash(Some("76e2564"));
+    /// assert_eq!(build_info.git_hash, Some("76e2564"));
+    /// assert!(build_info.long_version().contains("76e2564"));
+    /// ```
+    pub const fn with_git_hash(self, git_hash: Option<&'static str>) -> Self {
+        Self { git_hash, ..self }
+    }
+
+    /// Returns `true` if the feature was compiled in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::build_info::*;
+    /// assert!(!BUILD_INFO.has_feature("pext"));
+    /// ```
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.contains(&feature)
+    }
+
+    /// Returns the version, followed by the commit and features in
+    /// parentheses when known, like `0.1.0 (76e2564, json)`.
+    pub fn long_version(&self) -> String {
+        let details: Vec<&str> = self.git_hash.iter().chain(self.features).copied().collect();
+
+        if details.is_empty() {
+            self.version.to_string()
+        } else {
+            format!("{} ({})", self.version, details.join(", "))
+        }
+    }
+}
+
+/// Formats the name followed by the [long version](BuildInfo::long_version),
+/// like `Chess Engine 0.1.0 (76e2564, json)`.
+impl fmt::Display for BuildInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.long_version())
+    }
+}
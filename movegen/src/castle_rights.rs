@@ -35,7 +35,7 @@ impl FromStr for CastleRights {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut castle_rights = CastleRights::default().0;
 
-        if s.len() > 4 || s.len() == 0 {
+        if s.len() > 4 || s.is_empty() {
             return Err(CastleRightsTypeError(s.to_string()));
         }
 
@@ -101,3 +101,25 @@ impl Default for CastleRights {
         CastleRights([CastleRightsType::None; Color::LEN])
     }
 }
+
+/// Generates random `CastleRights` for fuzzing.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// use arbitrary::{Arbitrary, Unstructured};
+///
+/// let mut u = Unstructured::new(&[3, 1]);
+/// let castle_rights = CastleRights::arbitrary(&mut u).unwrap();
+/// assert_eq!(castle_rights.to_string().parse::<CastleRights>().unwrap(), castle_rights);
+/// ```
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CastleRights {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(CastleRights([
+            *u.choose(&CastleRightsType::ALL)?,
+            *u.choose(&CastleRightsType::ALL)?,
+        ]))
+    }
+}
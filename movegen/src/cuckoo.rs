@@ -0,0 +1,138 @@
+use chess_engine_core::{BitBoard, Color, Move, Piece, PieceType, Square};
+use once_cell::sync::Lazy;
+
+use crate::{get_attacks, Board, ZOBRIST};
+
+/// Number of entries of the cuckoo table, a power of 2.
+const LEN: usize = 0x2000;
+
+/// The reversible moves of every piece on an empty board, keyed by the
+/// difference they make to the Zobrist hash.
+///
+/// The keys depend on [`ZOBRIST`], which is random, so the table is built
+/// lazily at runtime instead of with the other constants.
+static CUCKOO: Lazy<Cuckoo> = Lazy::new(Cuckoo::new);
+
+struct Cuckoo {
+    keys: Box<[u64; LEN]>,
+    moves: Box<[Option<Move>; LEN]>,
+}
+
+impl Cuckoo {
+    fn new() -> Self {
+        let mut cuckoo = Cuckoo {
+            keys: Box::new([0; LEN]),
+            moves: Box::new([None; LEN]),
+        };
+
+        for color in Color::ALL {
+            for piece_type in PieceType::ALL {
+                if piece_type == PieceType::Pawn {
+                    continue;
+                }
+
+                let piece = Piece::new(piece_type, color);
+
+                for from in Square::ALL {
+                    for to in get_attacks(piece, from, BitBoard::EMPTY) {
+                        if (to as usize) <= (from as usize) {
+                            continue;
+                        }
+
+                        let key =
+                            ZOBRIST.piece(from, piece) ^ ZOBRIST.piece(to, piece) ^ ZOBRIST.color();
+
+                        cuckoo.insert(key, Move::new(from, to, None));
+                    }
+                }
+            }
+        }
+
+        cuckoo
+    }
+
+    /// Inserts the move, pushing the entry in its slot to the other slot of
+    /// that entry until an empty slot is found.
+    fn insert(&mut self, key: u64, mv: Move) {
+        let mut key = key;
+        let mut mv = Some(mv);
+        let mut index = h1(key);
+
+        loop {
+            std::mem::swap(&mut self.keys[index], &mut key);
+            std::mem::swap(&mut self.moves[index], &mut mv);
+
+            if mv.is_none() {
+                break;
+            }
+
+            index = if index == h1(key) { h2(key) } else { h1(key) };
+        }
+    }
+
+    fn probe(&self, key: u64) -> Option<Move> {
+        [h1(key), h2(key)]
+            .into_iter()
+            .find(|&index| self.keys[index] == key)
+            .and_then(|index| self.moves[index])
+    }
+}
+
+fn h1(key: u64) -> usize {
+    (key & (LEN as u64 - 1)) as usize
+}
+
+fn h2(key: u64) -> usize {
+    ((key >> 16) & (LEN as u64 - 1)) as usize
+}
+
+/// Upcoming repetition detection for the `Board` struct.
+impl Board {
+    /// Returns `true` if the color to move has a reversible move that
+    /// repeats a position of the history, so it can force a repetition
+    /// before it is on the board.
+    ///
+    /// Only positions since the last capture or pawn move are looked at. The
+    /// moves are found with a cuckoo table of the hash differences of every
+    /// reversible move, the scheme used by Stockfish, instead of generating
+    /// and playing them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let board = Board::default();
+    /// assert!(!board.upcoming_repetition());
+    /// ```
+    pub fn upcoming_repetition(&self) -> bool {
+        let end = (self.halfmove_clock() as usize).min(self.history.len());
+        let hash = self.hash();
+
+        for plies in (3..=end).step_by(2) {
+            let state = &self.history[self.history.len() - plies];
+
+            let Some(mv) = CUCKOO.probe(hash ^ state.hash()) else {
+                continue;
+            };
+
+            let (from, to) = if self.both_bitboard().is_get_square(mv.from()) {
+                (mv.from(), mv.to())
+            } else {
+                (mv.to(), mv.from())
+            };
+
+            let movable = self.get_piece(from).is_some_and(|piece| {
+                piece.color() == self.color() && piece.piece_type() != PieceType::Pawn
+            });
+
+            if movable
+                && !self.both_bitboard().is_get_square(to)
+                && self.piece_attacks(from).is_get_square(to)
+            {
+                return true;
+            }
+        }
+
+        false
+    }
+}
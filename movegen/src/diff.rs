@@ -0,0 +1,161 @@
+use chess_engine_core::{Color, Piece, Square};
+
+use crate::{Board, CastleRights};
+
+/// A difference in the piece on a square between two boards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SquareChange {
+    /// A piece is on the square in the other board only.
+    Added {
+        /// The square
+        square: Square,
+        /// The added piece
+        piece: Piece,
+    },
+    /// A piece is on the square in this board only.
+    Removed {
+        /// The square
+        square: Square,
+        /// The removed piece
+        piece: Piece,
+    },
+    /// The square holds different pieces in the two boards.
+    Changed {
+        /// The square
+        square: Square,
+        /// The piece in this board
+        from: Piece,
+        /// The piece in the other board
+        to: Piece,
+    },
+}
+
+impl SquareChange {
+    /// Returns the square of the change.
+    pub fn square(&self) -> Square {
+        match self {
+            SquareChange::Added { square, .. }
+            | SquareChange::Removed { square, .. }
+            | SquareChange::Changed { square, .. } => *square,
+        }
+    }
+}
+
+/// A difference in the state between two boards, as `(this, other)` values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StateChange {
+    /// The color to move differs.
+    Color(Color, Color),
+    /// The castling rights differ.
+    CastlingRights(CastleRights, CastleRights),
+    /// The en passant square differs.
+    EnPassantSquare(Option<Square>, Option<Square>),
+    /// The halfmove clock differs.
+    HalfmoveClock(u8, u8),
+    /// The fullmove counter differs.
+    FullmoveCounter(u16, u16),
+}
+
+/// Differences between two `Board` structs.
+impl Board {
+    /// Returns the squares whose piece differs in another board, from a1 to
+    /// h8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let before = Board::default();
+    /// let after = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// let pawn = Piece::new(PieceType::Pawn, Color::White);
+    ///
+    /// assert_eq!(
+    ///     before.diff(&after),
+    ///     vec![
+    ///         SquareChange::Removed { square: Square::E2, piece: pawn },
+    ///         SquareChange::Added { square: Square::E4, piece: pawn },
+    ///     ]
+    /// );
+    /// ```
+    pub fn diff(&self, other: &Board) -> Vec<SquareChange> {
+        let occupied = self.both_bitboard() | other.both_bitboard();
+
+        occupied
+            .into_iter()
+            .filter_map(
+                |square| match (self.get_piece(square), other.get_piece(square)) {
+                    (None, Some(piece)) => Some(SquareChange::Added { square, piece }),
+                    (Some(piece), None) => Some(SquareChange::Removed { square, piece }),
+                    (Some(from), Some(to)) if from != to => {
+                        Some(SquareChange::Changed { square, from, to })
+                    }
+                    _ => None,
+                },
+            )
+            .collect()
+    }
+
+    /// Returns the differences in the state of another board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let before = Board::default();
+    /// let after = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     before.state_diff(&after),
+    ///     vec![
+    ///         StateChange::Color(Color::White, Color::Black),
+    ///         StateChange::EnPassantSquare(None, Some(Square::E3)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn state_diff(&self, other: &Board) -> Vec<StateChange> {
+        let mut changes = Vec::new();
+
+        if self.color() != other.color() {
+            changes.push(StateChange::Color(self.color(), other.color()));
+        }
+
+        if self.castling_rights() != other.castling_rights() {
+            changes.push(StateChange::CastlingRights(
+                self.castling_rights(),
+                other.castling_rights(),
+            ));
+        }
+
+        if self.en_passant_square() != other.en_passant_square() {
+            changes.push(StateChange::EnPassantSquare(
+                self.en_passant_square(),
+                other.en_passant_square(),
+            ));
+        }
+
+        if self.halfmove_clock() != other.halfmove_clock() {
+            changes.push(StateChange::HalfmoveClock(
+                self.halfmove_clock(),
+                other.halfmove_clock(),
+            ));
+        }
+
+        if self.fullmove_counter() != other.fullmove_counter() {
+            changes.push(StateChange::FullmoveCounter(
+                self.fullmove_counter(),
+                other.fullmove_counter(),
+            ));
+        }
+
+        changes
+    }
+}
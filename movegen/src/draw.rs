@@ -14,20 +14,31 @@ impl Board {
     /// assert_eq!(board.has_bishop_pair(Color::White), true);
     /// ```
     pub fn has_bishop_pair(&self, color: Color) -> bool {
-        let bitboard = self.piece_bitboard(Piece::new(PieceType::Bishop, color));
+        let pieces = self.color_bitboard(color);
 
-        let mut white_square = 0;
-        let mut black_square = 0;
+        !(self.bishops_on(Color::White) & pieces).is_empty()
+            && !(self.bishops_on(Color::Black) & pieces).is_empty()
+    }
 
-        for square in bitboard {
-            if square.color() == Color::White {
-                white_square += 1;
-            } else {
-                black_square += 1;
-            }
-        }
+    /// Returns the bishops of both colors on the squares of a color complex,
+    /// the light squares for [`Color::White`] and the dark squares for
+    /// [`Color::Black`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = Board::default();
+    /// assert_eq!(board.bishops_on(Color::White), Square::F1.bitboard() | Square::C8.bitboard());
+    /// ```
+    pub fn bishops_on(&self, color_complex: Color) -> BitBoard {
+        let squares = match color_complex {
+            Color::White => BitBoard::LIGHT_SQUARES,
+            Color::Black => BitBoard::DARK_SQUARES,
+        };
 
-        white_square >= 1 && black_square >= 1
+        self.piece_types_bitboard(PieceType::Bishop) & squares
     }
 
     /// Returns `true` if the game is a draw by insufficient material.
@@ -87,35 +98,74 @@ impl Board {
 
         let b_knights = self.piece_bitboard(Piece::new(PieceType::Knight, Color::Black));
 
-        if w_bishops.0.count_ones() != 0 && b_bishops.0.count_ones() != 0 {
-            let mut w = false;
-            let mut b = false;
-            for square in w_bishops {
-                if square.color() == Color::White {
-                    w = true
-                } else {
-                    b = true
-                }
-            }
-
-            for square in b_bishops {
-                if square.color() == Color::White {
-                    w = true
-                } else {
-                    b = true
-                }
-            }
-
-            if w && b {
-                return false;
-            }
+        if w_bishops.0.count_ones() != 0
+            && b_bishops.0.count_ones() != 0
+            && !self.bishops_on(Color::White).is_empty()
+            && !self.bishops_on(Color::Black).is_empty()
+        {
+            return false;
         }
 
         if w_knights.0.count_ones() != 0 && b_knights.0.count_ones() != 0 {
             return false;
         }
 
-        return true;
+        true
+    }
+
+    /// Returns `true` if the [`Color`] has enough material to checkmate with
+    /// some sequence of legal moves, even with the help of the opponent.
+    ///
+    /// This is the rule used to adjudicate games lost on time: if the
+    /// opponent of the flagging side can't checkmate, the game is a draw. It
+    /// is looser than [`Board::draw_by_insufficient_material`], since it
+    /// counts the opponent's pieces that can block their own king.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = |fen: &str| fen.parse::<BoardBuilder>().unwrap().build();
+    ///
+    /// // a lone knight can't mate a lone king
+    /// assert!(!board("4k3/8/8/8/8/8/8/1N2K3 w - - 0 1").can_checkmate(Color::White));
+    ///
+    /// // but it can mate a king hemmed in by its own pawn
+    /// assert!(board("4k3/4p3/8/8/8/8/8/1N2K3 w - - 0 1").can_checkmate(Color::White));
+    ///
+    /// // bishops on the same color can never mate
+    /// assert!(!board("4kb2/8/8/8/8/8/8/2B1K3 w - - 0 1").can_checkmate(Color::White));
+    /// assert!(board("4k1b1/8/8/8/8/8/8/2B1K3 w - - 0 1").can_checkmate(Color::White));
+    /// ```
+    pub fn can_checkmate(&self, color: Color) -> bool {
+        let pieces = self.color_bitboard(color);
+
+        let pawns = self.piece_types_bitboard(PieceType::Pawn);
+        let knights = self.piece_types_bitboard(PieceType::Knight);
+        let bishops = self.piece_types_bitboard(PieceType::Bishop);
+        let rooks = self.piece_types_bitboard(PieceType::Rook);
+        let queens = self.piece_types_bitboard(PieceType::Queen);
+        let kings = self.piece_types_bitboard(PieceType::King);
+
+        if !(pieces & (pawns | rooks | queens)).is_empty() {
+            return true;
+        }
+
+        if !(pieces & knights).is_empty() {
+            let blockers = self.color_bitboard(!color) & !kings & !queens;
+
+            return pieces.len() > 2 || !blockers.is_empty();
+        }
+
+        if !(pieces & bishops).is_empty() {
+            let both_complexes = !self.bishops_on(Color::White).is_empty()
+                && !self.bishops_on(Color::Black).is_empty();
+
+            return both_complexes || !pawns.is_empty() || !knights.is_empty();
+        }
+
+        false
     }
 
     /// Returns `true` if the game is a draw by the fifty moves rule.
@@ -137,8 +187,18 @@ impl Board {
     ///
     /// ```
     /// # use chess_engine_movegen::*;
-    /// let board = Board::default();
+    /// # use chess_engine_core::*;
+    /// let mut board = Board::default();
     /// assert_eq!(board.draw_by_repetition(), false);
+    ///
+    /// for _ in 0..2 {
+    ///     board.make_move(Move::new(Square::G1, Square::F3, None));
+    ///     board.make_move(Move::new(Square::G8, Square::F6, None));
+    ///     board.make_move(Move::new(Square::F3, Square::G1, None));
+    ///     board.make_move(Move::new(Square::F6, Square::G8, None));
+    /// }
+    ///
+    /// assert_eq!(board.draw_by_repetition(), true);
     /// ```
     pub fn draw_by_repetition(&self) -> bool {
         let mut repetitions = 0;
@@ -146,13 +206,14 @@ impl Board {
         let hash = self.hash();
 
         for state in self.history.iter().rev() {
-            if state.halfmove_clock() == 0 {
-                break;
-            }
-
             if state.hash() == hash {
                 repetitions += 1;
             }
+
+            // the boundary itself can repeat, but no state before it
+            if state.is_irreversible_boundary() {
+                break;
+            }
         }
 
         repetitions >= 2
@@ -0,0 +1,604 @@
+//! Evaluation and policy hooks
+//!
+//! An [`Evaluator`] scores a position and a [`Policy`] gives the prior
+//! probability of each move, the two questions a search asks about a
+//! position. Both are traits, so a neural network backend can replace the
+//! hand-crafted [`Material`] evaluator and [`UniformPolicy`] without changing
+//! the code that calls them.
+//!
+//! Any `Fn(&Board) -> i32` closure is an [`Evaluator`] as well, and
+//! [`EndgameScaling`] wraps any of them to score dead and drawish endgames.
+//! [`EvalCache`] wraps them to remember the scores of positions evaluated
+//! again.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chess_engine_movegen::{eval::*, *};
+//! let board = "4k3/8/8/8/8/8/8/3QK3 w - - 0 1"
+//!     .parse::<BoardBuilder>()
+//!     .unwrap()
+//!     .build();
+//!
+//! assert_eq!(Material.evaluate(&board), 900);
+//!
+//! let random = |_: &Board| 42;
+//! assert_eq!(random.evaluate(&board), 42);
+//! ```
+
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+};
+
+use chess_engine_core::{Color, Move, Piece, PieceType};
+use rand::prelude::*;
+use thiserror::Error;
+
+use crate::{
+    kpk::{probe_board, KpkResult},
+    Board, BoardBuilder, CastleRights,
+};
+
+/// Scores positions for a search.
+pub trait Evaluator {
+    /// Returns the score of the board in centipawns, from the point of view
+    /// of the color to move.
+    fn evaluate(&self, board: &Board) -> i32;
+}
+
+/// Gives the prior probabilities of moves for a search.
+pub trait Policy {
+    /// Returns the prior probability of each move of the board, in the order
+    /// of the moves. The priors add up to 1.
+    fn priors(&self, board: &Board, moves: &[Move]) -> Vec<f32>;
+}
+
+impl<F: Fn(&Board) -> i32> Evaluator for F {
+    fn evaluate(&self, board: &Board) -> i32 {
+        self(board)
+    }
+}
+
+/// An [`Evaluator`] counting material, with a bonus for the bishop pair.
+///
+/// It evaluates with the weights of [`EvalParams::DEFAULT`], the single
+/// source of the piece values.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Material;
+
+/// Methods for the `Material` struct.
+impl Material {
+    /// The bonus for having bishops on both square colors, in centipawns.
+    pub const BISHOP_PAIR: i32 = EvalParams::DEFAULT.bishop_pair;
+
+    /// Returns the value of a [`PieceType`] in centipawns. The king has no
+    /// value, since it can't be traded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::eval::*;
+    /// # use chess_engine_core::*;
+    /// assert_eq!(Material::value(PieceType::Rook), 500);
+    /// ```
+    pub const fn value(piece_type: PieceType) -> i32 {
+        EvalParams::DEFAULT.value(piece_type)
+    }
+}
+
+impl Evaluator for Material {
+    fn evaluate(&self, board: &Board) -> i32 {
+        EvalParams::DEFAULT.evaluate(board)
+    }
+}
+
+/// The weights of the [`Material`] evaluation, as values that can be
+/// changed at runtime, for tuning.
+///
+/// With the `json` feature, the weights can be saved to and loaded from a
+/// JSON file.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::{eval::*, *};
+/// let board = "4k3/8/8/8/8/8/8/3QK3 w - - 0 1"
+///     .parse::<BoardBuilder>()
+///     .unwrap()
+///     .build();
+///
+/// let params = EvalParams {
+///     queen: 950,
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(params.evaluate(&board), 950);
+/// assert_eq!(EvalParams::default().evaluate(&board), Material.evaluate(&board));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvalParams {
+    /// The value of a pawn, in centipawns.
+    pub pawn: i32,
+    /// The value of a knight, in centipawns.
+    pub knight: i32,
+    /// The value of a bishop, in centipawns.
+    pub bishop: i32,
+    /// The value of a rook, in centipawns.
+    pub rook: i32,
+    /// The value of a queen, in centipawns.
+    pub queen: i32,
+    /// The bonus for having bishops on both square colors, in centipawns.
+    pub bishop_pair: i32,
+}
+
+/// Methods for the `EvalParams` struct.
+impl EvalParams {
+    /// The weights of [`Material`].
+    pub const DEFAULT: EvalParams = EvalParams {
+        pawn: 100,
+        knight: 320,
+        bishop: 330,
+        rook: 500,
+        queen: 900,
+        bishop_pair: 50,
+    };
+
+    /// Returns the value of a [`PieceType`] in centipawns. The king has no
+    /// value, since it can't be traded.
+    pub const fn value(&self, piece_type: PieceType) -> i32 {
+        match piece_type {
+            PieceType::Pawn => self.pawn,
+            PieceType::Knight => self.knight,
+            PieceType::Bishop => self.bishop,
+            PieceType::Rook => self.rook,
+            PieceType::Queen => self.queen,
+            PieceType::King => 0,
+        }
+    }
+
+    /// Returns the name and value of every weight, in declaration order.
+    pub fn weights(&self) -> [(&'static str, i32); 6] {
+        [
+            ("pawn", self.pawn),
+            ("knight", self.knight),
+            ("bishop", self.bishop),
+            ("rook", self.rook),
+            ("queen", self.queen),
+            ("bishop_pair", self.bishop_pair),
+        ]
+    }
+
+    fn color_score(&self, board: &Board, color: Color) -> i32 {
+        let mut score = 0;
+
+        for piece_type in PieceType::ALL {
+            let count = board.piece_bitboard(Piece::new(piece_type, color)).len();
+            score += self.value(piece_type) * count as i32;
+        }
+
+        if board.has_bishop_pair(color) {
+            score += self.bishop_pair;
+        }
+
+        score
+    }
+}
+
+impl Default for EvalParams {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Evaluator for EvalParams {
+    fn evaluate(&self, board: &Board) -> i32 {
+        self.color_score(board, board.color()) - self.color_score(board, !board.color())
+    }
+}
+
+/// An [`Evaluator`] scaling the scores of another one in endgames.
+///
+/// Dead positions, where neither color can mate, score exactly 0, and so do
+/// positions where the color ahead can't mate, and king and pawn versus king
+/// positions the [KPK bitbase](crate::kpk) knows are drawn. Endgames with bishops on
+/// opposite colors and no other pieces than pawns are drawish, so their
+/// scores are halved.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::{eval::*, *};
+/// let board = |fen: &str| fen.parse::<BoardBuilder>().unwrap().build();
+/// let evaluator = EndgameScaling(Material);
+///
+/// // a knight can't mate alone
+/// assert_eq!(evaluator.evaluate(&board("4k3/8/8/8/8/8/8/1N2K3 w - - 0 1")), 0);
+///
+/// // opposite-colored bishops with a pawn up
+/// let bishops = board("2b1k3/8/8/8/8/8/4P3/2B1K3 w - - 0 1");
+/// assert_eq!(Material.evaluate(&bishops), 100);
+/// assert_eq!(evaluator.evaluate(&bishops), 50);
+///
+/// // the black king holds the opposition in front of the pawn
+/// let kpk = board("8/8/4k3/8/4K3/4P3/8/8 w - - 0 1");
+/// assert_eq!(Material.evaluate(&kpk), 100);
+/// assert_eq!(evaluator.evaluate(&kpk), 0);
+///
+/// // with Black to move, White takes the opposition and wins
+/// let kpk = board("8/8/4k3/8/4K3/4P3/8/8 b - - 0 1");
+/// assert_eq!(evaluator.evaluate(&kpk), -100);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EndgameScaling<E>(pub E);
+
+/// Methods for the `EndgameScaling` struct.
+impl<E> EndgameScaling<E> {
+    /// The scale factor of positions that are not scaled.
+    pub const NORMAL_SCALE: i32 = 128;
+
+    /// Returns the scale factor of the board, out of
+    /// [`EndgameScaling::NORMAL_SCALE`].
+    pub fn scale(board: &Board) -> i32 {
+        let bishops = board.piece_types_bitboard(PieceType::Bishop);
+        let others = board.piece_types_bitboard(PieceType::Knight)
+            | board.piece_types_bitboard(PieceType::Rook)
+            | board.piece_types_bitboard(PieceType::Queen);
+
+        let opposite_bishops = Color::ALL
+            .into_iter()
+            .all(|color| (bishops & board.color_bitboard(color)).len() == 1)
+            && board.bishops_on(Color::White).len() == 1;
+
+        if opposite_bishops && others.is_empty() {
+            Self::NORMAL_SCALE / 2
+        } else {
+            Self::NORMAL_SCALE
+        }
+    }
+}
+
+impl<E: Evaluator> Evaluator for EndgameScaling<E> {
+    fn evaluate(&self, board: &Board) -> i32 {
+        if board.draw_by_insufficient_material() || probe_board(board) == Some(KpkResult::Draw) {
+            return 0;
+        }
+
+        let score = self.0.evaluate(board);
+        let ahead = if score > 0 {
+            board.color()
+        } else {
+            !board.color()
+        };
+
+        if !board.can_checkmate(ahead) {
+            return 0;
+        }
+
+        score * Self::scale(board) / Self::NORMAL_SCALE
+    }
+}
+
+/// An [`Evaluator`] remembering the scores of another one, keyed by the hash
+/// of the board.
+///
+/// A search evaluates the same positions many times, through transpositions
+/// and re-searches. The cache holds a fixed number of entries, each
+/// replaced by the last board evaluated with the same index, and checks the
+/// [verification key](Board::verification_key) of the board so hash
+/// collisions are not mistaken for hits. Hashing the board costs about as
+/// much as counting material, so the cache pays off for costlier
+/// evaluators.
+///
+/// The memory of the cache can be bounded with [`EvalCache::with_memory`],
+/// and shrunk later with [`EvalCache::set_memory`] when the budget of the
+/// embedder gets tighter, or by a [`MemoryBudget`](crate::memory::MemoryBudget)
+/// shared with the tablebases.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::{eval::*, *};
+/// let cache = EvalCache::new(Material, 1024);
+/// let board = Board::default();
+///
+/// assert_eq!(cache.evaluate(&board), 0);
+/// assert_eq!(cache.evaluate(&board), 0);
+///
+/// let stats = cache.stats();
+/// assert_eq!((stats.probes, stats.hits), (2, 1));
+/// assert_eq!(stats.hit_rate(), 0.5);
+/// ```
+#[derive(Debug)]
+pub struct EvalCache<E> {
+    evaluator: E,
+    entries: RefCell<Vec<Option<CacheEntry>>>,
+    stats: Cell<CacheStats>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct CacheEntry {
+    verification_key: u32,
+    score: i32,
+}
+
+/// The number of lookups in an [`EvalCache`] and how many found a score.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of boards evaluated.
+    pub probes: u64,
+    /// The number of boards whose score was in the cache.
+    pub hits: u64,
+}
+
+/// Methods for the `CacheStats` struct.
+impl CacheStats {
+    /// Returns the fraction of the probes that hit, or 0 without probes.
+    pub fn hit_rate(&self) -> f64 {
+        if self.probes == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.probes as f64
+        }
+    }
+}
+
+/// Methods for the `EvalCache` struct.
+impl<E> EvalCache<E> {
+    /// The size of an entry of the cache, in bytes.
+    pub const ENTRY_SIZE: usize = std::mem::size_of::<Option<CacheEntry>>();
+
+    /// Creates an empty `EvalCache` with room for `entries` scores, rounded
+    /// up to a power of two.
+    pub fn new(evaluator: E, entries: usize) -> Self {
+        Self {
+            evaluator,
+            entries: RefCell::new(vec![None; entries.max(1).next_power_of_two()]),
+            stats: Cell::new(CacheStats::default()),
+        }
+    }
+
+    /// Creates an empty `EvalCache` using at most `bytes` of memory for its
+    /// entries, and at least one entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::eval::*;
+    /// let cache = EvalCache::with_memory(Material, 1 << 20);
+    /// assert!(cache.memory() <= 1 << 20);
+    /// assert!(cache.memory() > 1 << 19);
+    /// ```
+    pub fn with_memory(evaluator: E, bytes: usize) -> Self {
+        Self::new(evaluator, Self::entries_in(bytes))
+    }
+
+    /// Returns the memory used by the entries of the cache, in bytes.
+    pub fn memory(&self) -> usize {
+        self.entries.borrow().len() * Self::ENTRY_SIZE
+    }
+
+    /// Resizes the cache to use at most `bytes` of memory, to fit a smaller
+    /// budget or use a larger one. The scores and the stats are cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::eval::*;
+    /// let mut cache = EvalCache::with_memory(Material, 1 << 20);
+    /// cache.set_memory(1 << 10);
+    /// assert!(cache.memory() <= 1 << 10);
+    /// ```
+    pub fn set_memory(&mut self, bytes: usize) {
+        *self.entries.get_mut() = vec![None; Self::entries_in(bytes)];
+        self.stats.set(CacheStats::default());
+    }
+
+    /// Returns the largest power of two of entries fitting in `bytes`, and
+    /// at least one.
+    fn entries_in(bytes: usize) -> usize {
+        match bytes / Self::ENTRY_SIZE {
+            0 => 1,
+            entries => 1 << entries.ilog2(),
+        }
+    }
+
+    /// Returns the cached evaluator.
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    /// Returns the lookups made since the cache was created or cleared.
+    pub fn stats(&self) -> CacheStats {
+        self.stats.get()
+    }
+
+    /// Forgets every score and resets the stats, for example when the
+    /// weights of the evaluator change.
+    pub fn clear(&mut self) {
+        self.entries.get_mut().fill(None);
+        self.stats.set(CacheStats::default());
+    }
+}
+
+impl<E: Evaluator> Evaluator for EvalCache<E> {
+    fn evaluate(&self, board: &Board) -> i32 {
+        let mut entries = self.entries.borrow_mut();
+        let index = board.hash() as usize & (entries.len() - 1);
+        let verification_key = board.verification_key();
+
+        let mut stats = self.stats.get();
+        stats.probes += 1;
+
+        let score = match entries[index] {
+            Some(entry) if entry.verification_key == verification_key => {
+                stats.hits += 1;
+                entry.score
+            }
+            _ => {
+                let score = self.evaluator.evaluate(board);
+                entries[index] = Some(CacheEntry {
+                    verification_key,
+                    score,
+                });
+                score
+            }
+        };
+
+        self.stats.set(stats);
+        score
+    }
+}
+
+/// A [`Policy`] giving every move the same prior.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::{eval::*, *};
+/// let board = Board::default();
+/// let moves = board.generate_moves();
+///
+/// let priors = UniformPolicy.priors(&board, &moves);
+/// assert_eq!(priors, vec![0.05; 20]);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UniformPolicy;
+
+impl Policy for UniformPolicy {
+    fn priors(&self, _board: &Board, moves: &[Move]) -> Vec<f32> {
+        vec![1.0 / moves.len() as f32; moves.len()]
+    }
+}
+
+/// A transformation of a board that keeps its evaluation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symmetry {
+    /// Flips the ranks and swaps the colors of the pieces, the castling
+    /// rights and the color to move.
+    ColorFlip,
+    /// Flips the files, for boards without castling rights.
+    FileMirror,
+}
+
+/// Formats a `Symmetry` as its name.
+impl fmt::Display for Symmetry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Symmetry::ColorFlip => write!(f, "color flip"),
+            Symmetry::FileMirror => write!(f, "file mirror"),
+        }
+    }
+}
+
+/// An evaluation that changes under a [`Symmetry`] of the board.
+#[derive(Error, Debug)]
+#[error("{symmetry} changes the evaluation of {fen} from {score} to {transformed}")]
+pub struct SymmetryError {
+    /// The FEN of the board
+    pub fen: String,
+    /// The symmetry that changes the evaluation
+    pub symmetry: Symmetry,
+    /// The evaluation of the board
+    pub score: i32,
+    /// The evaluation of the transformed board
+    pub transformed: i32,
+}
+
+/// Checks that the evaluation of the board doesn't change when the colors
+/// are flipped, or the files mirrored if no color can castle.
+///
+/// Evaluations are from the point of view of the color to move, so the
+/// evaluation of the flipped board, from the other color's point of view,
+/// must be the same. Every evaluation term should keep this property.
+///
+/// # Errors
+///
+/// Returns a [`SymmetryError`] with the first symmetry that changes the
+/// evaluation.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::{eval::*, *};
+/// # use chess_engine_core::*;
+/// let board = Board::default();
+/// assert!(verify_symmetry(&Material, &board).is_ok());
+///
+/// // an evaluation that likes pawns on the a-file
+/// let a_pawns = |board: &Board| {
+///     let pawns = board.piece_bitboard(Piece::new(PieceType::Pawn, board.color()));
+///     (pawns & File::A.bitboard()).len() as i32
+/// };
+///
+/// let board = "4k3/8/8/8/8/8/P7/4K3 w - - 0 1"
+///     .parse::<BoardBuilder>()
+///     .unwrap()
+///     .build();
+///
+/// let error = verify_symmetry(&a_pawns, &board).unwrap_err();
+/// assert_eq!(error.symmetry, Symmetry::FileMirror);
+/// ```
+pub fn verify_symmetry(evaluator: &impl Evaluator, board: &Board) -> Result<(), SymmetryError> {
+    let score = evaluator.evaluate(board);
+    let castling = board.castling_rights() != CastleRights::default();
+
+    for symmetry in [Symmetry::ColorFlip, Symmetry::FileMirror] {
+        if symmetry == Symmetry::FileMirror && castling {
+            continue;
+        }
+
+        let transformed = evaluator.evaluate(&transform(board, symmetry));
+
+        if transformed != score {
+            return Err(SymmetryError {
+                fen: board.to_string(),
+                symmetry,
+                score,
+                transformed,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the symmetry of the evaluation, like [`verify_symmetry`], on a
+/// number of random boards.
+///
+/// The boards are [`BoardBuilder::random`] positions.
+///
+/// # Errors
+///
+/// Returns a [`SymmetryError`] for the first board whose evaluation changes.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::eval::*;
+/// # use rand::{rngs::StdRng, SeedableRng};
+/// let mut rng = StdRng::seed_from_u64(0);
+/// assert!(verify_symmetry_random(&Material, &mut rng, 100).is_ok());
+/// ```
+pub fn verify_symmetry_random<R: Rng + ?Sized>(
+    evaluator: &impl Evaluator,
+    rng: &mut R,
+    boards: usize,
+) -> Result<(), SymmetryError> {
+    for _ in 0..boards {
+        verify_symmetry(evaluator, &BoardBuilder::random(rng).build())?;
+    }
+
+    Ok(())
+}
+
+fn transform(board: &Board, symmetry: Symmetry) -> Board {
+    let mut builder = BoardBuilder::from(board);
+
+    match symmetry {
+        Symmetry::ColorFlip => builder.mirror(),
+        Symmetry::FileMirror => builder.mirror_files(),
+    };
+
+    builder.build()
+}
@@ -0,0 +1,203 @@
+//! Opening explorer over a set of games
+//!
+//! An [`OpeningExplorer`] replays games from the starting position and
+//! records, for every position they went through, the moves played from it
+//! and the results of the games. Positions are keyed by their
+//! [hash](Board::hash), so move orders that transpose into the same
+//! position are merged, while the lines themselves are kept in a
+//! [`MoveTree`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use chess_engine_movegen::{explorer::*, *};
+//! # use chess_engine_core::*;
+//! let line = |moves: &str| {
+//!     moves
+//!         .split_whitespace()
+//!         .map(|mv| mv.parse().unwrap())
+//!         .collect::<Vec<Move>>()
+//! };
+//!
+//! let mut explorer = OpeningExplorer::new();
+//! explorer.add_game(&line("g1f3 g8f6 b1c3"), GameResult::Draw);
+//! explorer.add_game(&line("b1c3 g8f6 g1f3"), GameResult::WhiteWins);
+//!
+//! let mut board = Board::default();
+//! for mv in line("b1c3 g8f6 g1f3") {
+//!     board.make_move(mv);
+//! }
+//!
+//! assert_eq!(explorer.stats(&board).unwrap().total(), 2);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use chess_engine_core::{GameResult, Move, MoveTree};
+
+use crate::Board;
+
+/// The results of the games that went through a position.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResultStats {
+    /// The games White won.
+    pub white_wins: u32,
+    /// The games drawn.
+    pub draws: u32,
+    /// The games Black won.
+    pub black_wins: u32,
+}
+
+/// Methods for the `ResultStats` struct.
+impl ResultStats {
+    /// Returns the number of games.
+    pub fn total(&self) -> u32 {
+        self.white_wins + self.draws + self.black_wins
+    }
+
+    fn add(&mut self, result: GameResult) {
+        match result {
+            GameResult::WhiteWins => self.white_wins += 1,
+            GameResult::Draw => self.draws += 1,
+            GameResult::BlackWins => self.black_wins += 1,
+        }
+    }
+}
+
+/// A move played from a position of an [`OpeningExplorer`], with the
+/// results of the games that continued with it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExplorerMove {
+    /// The move played.
+    pub mv: Move,
+    /// The results of the games with the move.
+    pub stats: ResultStats,
+}
+
+/// The games that went through a position.
+#[derive(Clone, Debug, Default)]
+struct Position {
+    stats: ResultStats,
+    moves: Vec<ExplorerMove>,
+}
+
+/// The moves played from each position of a set of games, with their
+/// results.
+///
+/// A game that goes through a position more than once counts once for it.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::{explorer::*, *};
+/// # use chess_engine_core::*;
+/// let line = |moves: &str| {
+///     moves
+///         .split_whitespace()
+///         .map(|mv| mv.parse().unwrap())
+///         .collect::<Vec<Move>>()
+/// };
+///
+/// let mut explorer = OpeningExplorer::new();
+/// explorer.add_game(&line("e2e4 e7e5 g1f3"), GameResult::WhiteWins);
+/// explorer.add_game(&line("e2e4 c7c5"), GameResult::Draw);
+/// explorer.add_game(&line("e2e4 e7e5 f1c4"), GameResult::BlackWins);
+/// explorer.add_game(&line("d2d4 d7d5"), GameResult::Draw);
+///
+/// let mut board = Board::default();
+/// board.make_move("e2e4".parse().unwrap());
+///
+/// let moves = explorer.moves(&board);
+/// assert_eq!(moves[0].mv, "e7e5".parse().unwrap());
+/// assert_eq!(moves[0].stats.total(), 2);
+/// assert_eq!(moves[1].stats.draws, 1);
+///
+/// assert_eq!(explorer.stats(&Board::default()).unwrap().total(), 4);
+/// assert_eq!(explorer.tree().node(MoveTree::ROOT).children().len(), 2);
+///
+/// board.make_move("g8f6".parse().unwrap());
+/// assert_eq!(explorer.stats(&board), None);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct OpeningExplorer {
+    tree: MoveTree,
+    positions: HashMap<u64, Position>,
+}
+
+/// Methods for the `OpeningExplorer` struct.
+impl OpeningExplorer {
+    /// Creates an empty `OpeningExplorer`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the tree of the lines of the games.
+    pub fn tree(&self) -> &MoveTree {
+        &self.tree
+    }
+
+    /// Adds the moves of a game from the starting position, and its result.
+    ///
+    /// The moves are played with [`Board::make_move`] and are not checked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a move has no piece on its origin square.
+    pub fn add_game(&mut self, moves: &[Move], result: GameResult) {
+        let mut board = Board::default();
+        let mut seen = HashSet::new();
+
+        self.tree.go_to_start();
+
+        for &mv in moves {
+            if seen.insert(board.hash()) {
+                self.add_result(board.hash(), Some(mv), result);
+            }
+
+            self.tree.add_move(mv);
+            board.make_move(mv);
+        }
+
+        if seen.insert(board.hash()) {
+            self.add_result(board.hash(), None, result);
+        }
+    }
+
+    /// Returns the results of the games that went through the position of
+    /// the board, or `None` if no game did.
+    pub fn stats(&self, board: &Board) -> Option<ResultStats> {
+        self.positions
+            .get(&board.hash())
+            .map(|position| position.stats)
+    }
+
+    /// Returns the moves played from the position of the board, the most
+    /// played first.
+    pub fn moves(&self, board: &Board) -> Vec<ExplorerMove> {
+        let mut moves = self
+            .positions
+            .get(&board.hash())
+            .map_or_else(Vec::new, |position| position.moves.clone());
+
+        moves.sort_by_key(|mv| std::cmp::Reverse(mv.stats.total()));
+        moves
+    }
+
+    fn add_result(&mut self, hash: u64, mv: Option<Move>, result: GameResult) {
+        let position = self.positions.entry(hash).or_default();
+        position.stats.add(result);
+
+        let Some(mv) = mv else {
+            return;
+        };
+
+        match position.moves.iter_mut().find(|played| played.mv == mv) {
+            Some(played) => played.stats.add(result),
+            None => {
+                let mut stats = ResultStats::default();
+                stats.add(result);
+                position.moves.push(ExplorerMove { mv, stats });
+            }
+        }
+    }
+}
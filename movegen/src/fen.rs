@@ -18,13 +18,22 @@
 //!
 //! assert_eq!(board.to_string(), fen_str);
 //! ```
+//!
+//! Positions known at compile time can use the [`fen!`] macro instead, which
+//! fails to compile if the FEN string is invalid.
+//!
+//! [`fen!`]: crate::fen!
 
-use crate::{BoardBuilder, State};
+use crate::{BoardBuilder, CastleRights, State};
 use chess_engine_core::{
-    CastleRightsTypeError, Color, ColorError, File, Piece, PieceType, PieceTypeError, Rank, Square,
-    SquareError,
+    CastleRightsType, CastleRightsTypeError, Color, ColorError, File, Piece, PieceType,
+    PieceTypeError, Rank, Square, SquareError,
+};
+use std::{
+    fmt,
+    io::{BufRead, Lines},
+    str::FromStr,
 };
-use std::{fmt, str::FromStr};
 use thiserror::Error;
 
 /// Errors that can occur when parsing a FEN string
@@ -59,6 +68,15 @@ pub enum FenError {
         num_pawns: u8,
     },
 
+    /// Color has more than one king
+    #[error("color {color} has too many kings (expected 1 or fewer, got {num_kings})")]
+    TooManyKings {
+        /// [`Color`] with too many kings
+        color: Color,
+        /// Number of kings
+        num_kings: u8,
+    },
+
     /// Color has too many pieces
     #[error("color {color} has too many pieces (expected 16 or fewer, got {num_pieces})")]
     ToManyPieces {
@@ -91,6 +109,10 @@ pub enum FenError {
     /// Invalid fullmove counter
     #[error("invalid fullmove counter")]
     FullmoveCounter,
+
+    /// Failed to read the FEN string
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
 }
 
 /// Parses a [`BoardBuilder`] from a [`FEN`] string
@@ -108,174 +130,280 @@ pub enum FenError {
 /// assert_eq!(board.to_string(), fen_str);
 /// ```
 ///
+/// Malformed strings return an error instead of panicking:
+///
+/// ```
+/// # use chess_engine_movegen::{fen::*, *};
+/// let too_long = "rnbqkbnr/ppppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+/// assert!(matches!(too_long.parse::<BoardBuilder>(), Err(FenError::Files(9))));
+///
+/// let truncated = "rnbqkbnr/pppppppp/8/8/5/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+/// assert!(matches!(truncated.parse::<BoardBuilder>(), Err(FenError::Files(5))));
+///
+/// let two_kings = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBKKBNR w KQkq - 0 1";
+/// assert!(matches!(
+///     two_kings.parse::<BoardBuilder>(),
+///     Err(FenError::TooManyKings { num_kings: 2, .. })
+/// ));
+///
+/// let late = "8/8/8/8/8/8/8/8 w - - 0 40000";
+/// assert_eq!(late.parse::<BoardBuilder>().unwrap().to_string(), late);
+/// ```
+///
 /// [`FEN`]: fen/index.html
 impl FromStr for BoardBuilder {
     type Err = FenError;
 
     fn from_str(fen: &str) -> Result<Self, Self::Err> {
-        let mut board_builder = BoardBuilder::new();
-
-        let fen = split_fen_string(fen)?;
-
-        let pieces = piece_placement(fen[0])?;
-
-        board_builder.pieces(pieces);
-
-        board_builder.color(fen[1].parse()?);
-
-        board_builder.castling_rights(fen[2].parse()?);
-
-        let en_passant_square = {
-            if fen[3] == "-" {
-                None
-            } else {
-                let square: Square = fen[3].parse()?;
-                let rank = square.rank();
-
-                if rank != Rank::Three && rank != Rank::Six {
-                    return Err(FenError::EnPassantRank(rank));
-                }
-
-                Some(square)
-            }
-        };
+        parse_fen(fen.as_bytes()).map_err(InvalidFen::to_fen_error)
+    }
+}
 
-        board_builder.en_passant_square(en_passant_square);
+/// An iterator over the positions of a file with one FEN or EPD per line.
+///
+/// Lines are read one at a time, so files of any size can be streamed. Empty
+/// lines and lines starting with `#` are skipped. EPD operations after the
+/// first four sections are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::{fen::*, *};
+/// let file = "
+///     ## a FEN, an EPD and a mistake
+///     rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1
+///     4k3/8/8/8/8/8/8/4K2R w K - bm Rh8+; id \"mate in 1\";
+///     8/8/8 w - -
+/// ";
+///
+/// let mut positions = FenReader::new(file.as_bytes());
+///
+/// assert_eq!(
+///     positions.next().unwrap().unwrap().to_string(),
+///     "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+/// );
+/// assert_eq!(
+///     positions.next().unwrap().unwrap().to_string(),
+///     "4k3/8/8/8/8/8/8/4K2R w K - 0 1"
+/// );
+/// assert!(matches!(positions.next(), Some(Err(FenError::Ranks(3)))));
+/// assert!(positions.next().is_none());
+/// ```
+#[derive(Debug)]
+pub struct FenReader<R> {
+    lines: Lines<R>,
+}
 
-        let mut halfmove_clock = 0;
+/// Methods for the `FenReader` struct.
+impl<R: BufRead> FenReader<R> {
+    /// Creates a `FenReader` over the lines of a reader.
+    pub fn new(reader: R) -> Self {
+        FenReader {
+            lines: reader.lines(),
+        }
+    }
+}
 
-        if fen.len() > 4 {
-            halfmove_clock = fen[4].parse().map_err(|_| FenError::HalfmoveClock)?;
+impl<R: BufRead> Iterator for FenReader<R> {
+    type Item = Result<BoardBuilder, FenError>;
 
-            // TODO: if is not a checkmate and halfmove_clock is 100, it is a draw
+    fn next(&mut self) -> Option<Self::Item> {
+        for line in self.lines.by_ref() {
+            let line = match line {
+                Ok(line) => line,
+                Err(error) => return Some(Err(error.into())),
+            };
+            let line = line.trim();
 
-            if halfmove_clock > State::MAX_HALFMOVE_CLOCK {
-                return Err(FenError::HalfmoveClock);
+            if line.is_empty() || line.starts_with('#') {
+                continue;
             }
 
-            board_builder.halfmove_clock(halfmove_clock);
-        } else {
-            board_builder.halfmove_clock(0);
-        }
+            let sections: Vec<&str> = line.split_whitespace().collect();
 
-        if fen.len() > 5 {
-            let fullmove_counter: u16 = fen[5].parse().map_err(|_| FenError::FullmoveCounter)?;
+            // the clocks of a FEN are numbers, the operations of an EPD are not
+            let is_fen = sections.len() <= 6
+                && sections
+                    .iter()
+                    .skip(4)
+                    .all(|section| section.parse::<u16>().is_ok());
 
-            if fullmove_counter == 0 {
-                return Err(FenError::FullmoveCounter);
+            if is_fen {
+                return Some(line.parse());
             }
 
-            if halfmove_clock as u16 > fullmove_counter * 2 {
-                return Err(FenError::HalfmoveClock);
-            }
-
-            board_builder.fullmove_counter(fullmove_counter);
-        } else {
-            board_builder.fullmove_counter(1);
+            return Some(sections[..4].join(" ").parse());
         }
 
-        Ok(board_builder)
+        None
     }
 }
 
-/// Splits a FEN string into its sections
+/// A chess variant a FEN string can describe.
 ///
-/// Returns a vector of strings, where each string is a section of the FEN
-/// string.
-fn split_fen_string(fen: &str) -> Result<Vec<&str>, FenError> {
-    let fen: Vec<&str> = fen.split_whitespace().collect();
+/// Only [`Variant::Standard`] positions can be parsed into a
+/// [`BoardBuilder`]. The others are recognized by [`detect_variant`] so they
+/// can be told apart from mistakes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// Standard chess.
+    Standard,
+    /// Chess960, with Shredder-FEN castling files or X-FEN castling rights
+    /// the standard rules can't have.
+    Chess960,
+    /// Crazyhouse, with the pockets of captured pieces.
+    Crazyhouse,
+    /// Three-check, with the check counters.
+    ThreeCheck,
+}
 
-    if fen.len() < 4 || fen.len() > 6 {
-        return Err(FenError::Sections(fen.len()));
-    }
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Variant::Standard => "Standard",
+            Variant::Chess960 => "Chess960",
+            Variant::Crazyhouse => "Crazyhouse",
+            Variant::ThreeCheck => "Three-check",
+        };
 
-    Ok(fen)
+        write!(f, "{}", name)
+    }
 }
 
-/// Parses the piece placement section of a FEN string
+/// Returns the [`Variant`] a FEN string implies, from the fields only that
+/// variant has.
 ///
-/// Returns an array of pieces, where the index is the square index on the
-/// board and the value is the piece on that square.
-fn piece_placement(piece_section: &str) -> Result<[Option<Piece>; Square::LEN], FenError> {
-    let mut pieces = [None; Square::LEN];
-
-    let ranks: Vec<&str> = piece_section.split('/').collect();
+/// The string is not validated: a string without variant fields is
+/// [`Variant::Standard`], even if it is not a valid FEN string.
+///
+/// - Crazyhouse pockets follow the piece placement, in brackets
+///   (`RNBQKBNR[Pp]`) or as a ninth rank (`RNBQKBNR/Pp`), and promoted pieces
+///   are marked with `~`.
+/// - Three-check counters are a section like `3+3`, the checks left, or
+///   `+0+0`, the checks given.
+/// - Chess960 castling rights are files (`HAha`), or `KQkq` with the king
+///   off the e-file or castling with a rook other than the corner one.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::fen::*;
+/// assert_eq!(
+///     detect_variant("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+///     Variant::Standard
+/// );
+/// assert_eq!(
+///     detect_variant("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1"),
+///     Variant::Crazyhouse
+/// );
+/// assert_eq!(
+///     detect_variant("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 3+3 0 1"),
+///     Variant::ThreeCheck
+/// );
+/// assert_eq!(
+///     detect_variant("bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9"),
+///     Variant::Chess960
+/// );
+/// assert_eq!(
+///     detect_variant("rkrnnqbb/pppppppp/8/8/8/8/PPPPPPPP/RKRNNQBB w KQkq - 0 1"),
+///     Variant::Chess960
+/// );
+/// ```
+pub fn detect_variant(fen: &str) -> Variant {
+    let sections: Vec<&str> = fen.split_whitespace().collect();
+    let Some(&placement) = sections.first() else {
+        return Variant::Standard;
+    };
 
-    if ranks.len() != Rank::LEN {
-        return Err(FenError::Ranks(ranks.len()));
+    if placement.contains(['[', '~']) || placement.split('/').count() > Rank::LEN {
+        return Variant::Crazyhouse;
     }
 
-    let (mut num_white_pawns, mut num_white_pieces) = (0, 0);
-    let (mut num_black_pawns, mut num_black_pieces) = (0, 0);
+    if sections
+        .iter()
+        .skip(1)
+        .any(|section| is_check_counter(section))
+    {
+        return Variant::ThreeCheck;
+    }
 
-    for (rank_index, rank) in ranks.iter().rev().enumerate() {
-        let mut file_index = 0;
+    if let Some(&castling_rights) = sections.get(2) {
+        if castling_rights
+            .chars()
+            .any(|c| matches!(c, 'A'..='H' | 'a'..='h'))
+            || is_castling_960(placement, castling_rights)
+        {
+            return Variant::Chess960;
+        }
+    }
 
-        for file in rank.chars() {
-            if let Some(digit) = file.to_digit(10) {
-                file_index += digit as usize;
-                continue;
-            }
+    Variant::Standard
+}
 
-            let piece: Piece = file.to_string().as_str().parse()?;
+/// Returns `true` if the section is a Three-check counter, `3+3` or `+0+0`.
+fn is_check_counter(section: &str) -> bool {
+    let counters: Vec<&str> = section
+        .strip_prefix('+')
+        .unwrap_or(section)
+        .split('+')
+        .collect();
 
-            if piece.color() == Color::White {
-                num_white_pieces += 1;
-            } else {
-                num_black_pieces += 1;
-            }
+    counters.len() == 2
+        && counters
+            .iter()
+            .all(|counter| !counter.is_empty() && counter.chars().all(|c| c.is_ascii_digit()))
+}
 
-            if piece.piece_type() == PieceType::Pawn {
-                if rank_index == 0 || rank_index == 7 {
-                    return Err(FenError::PawnOnFirstOrLastRank);
-                }
+/// Returns `true` if `KQkq` castling rights can only be the X-FEN rights of a
+/// Chess960 position: the king is on its back rank but not on the e-file, or
+/// the rook it castles with is not in the corner.
+fn is_castling_960(placement: &str, castling_rights: &str) -> bool {
+    let ranks: Vec<&str> = placement.split('/').collect();
+    let (Some(&rank_8), Some(&rank_1)) = (ranks.first(), ranks.get(Rank::LEN - 1)) else {
+        return false;
+    };
 
-                if piece.color() == Color::White {
-                    num_white_pawns += 1;
-                } else {
-                    num_black_pawns += 1;
+    // the king, its queen side castling right and its rook
+    [(rank_1, 'K', 'Q', 'R'), (rank_8, 'k', 'q', 'r')]
+        .into_iter()
+        .any(|(rank, king, queen_side, rook)| {
+            let mut files = Vec::with_capacity(File::LEN);
+            for c in rank.chars() {
+                match c.to_digit(10) {
+                    Some(digit) => files.extend((0..digit).map(|_| None)),
+                    None => files.push(Some(c)),
                 }
             }
 
-            pieces[Square::with_file_rank(File::new(file_index), Rank::new(rank_index)) as usize] =
-                Some(piece);
-            file_index += 1;
-        }
+            let Some(king_file) = files.iter().position(|&c| c == Some(king)) else {
+                return false;
+            };
 
-        if file_index != File::LEN {
-            return Err(FenError::Files(file_index));
-        }
-    }
+            let rook_between = |range: std::ops::Range<usize>| {
+                range
+                    .into_iter()
+                    .any(|file| files.get(file) == Some(&Some(rook)))
+            };
 
-    if num_white_pieces > Piece::MAX_PIECES_PER_COLOR {
-        return Err(FenError::ToManyPieces {
-            color: Color::White,
-            num_pieces: num_white_pieces as u8,
-        });
-    }
+            let king_side_960 = castling_rights.contains(king)
+                && (king_file != File::E as usize
+                    || files.get(File::H as usize) != Some(&Some(rook))
+                        && rook_between(king_file + 1..File::H as usize));
+            let queen_side_960 = castling_rights.contains(queen_side)
+                && (king_file != File::E as usize
+                    || files.first() != Some(&Some(rook)) && rook_between(1..king_file));
 
-    if num_black_pieces > Piece::MAX_PIECES_PER_COLOR {
-        return Err(FenError::ToManyPieces {
-            color: Color::Black,
-            num_pieces: num_black_pieces as u8,
-        });
-    }
-
-    if num_white_pawns > Piece::MAX_PAWNS_PER_COLOR {
-        return Err(FenError::ToManyPawns {
-            color: Color::White,
-            num_pawns: num_white_pawns as u8,
-        });
-    }
-
-    if num_black_pawns > Piece::MAX_PAWNS_PER_COLOR {
-        return Err(FenError::ToManyPawns {
-            color: Color::Black,
-            num_pawns: num_black_pawns as u8,
-        });
-    }
+            king_side_960 || queen_side_960
+        })
+}
 
-    Ok(pieces)
+/// Checks that the pieces can be on a board: at most one king, eight pawns
+/// and sixteen pieces per color, and no pawn on the first or last rank.
+///
+/// Every way of placing pieces, FEN or JSON, goes through these checks.
+#[cfg(feature = "json")]
+pub(crate) fn check_pieces(pieces: &[Option<Piece>; Square::LEN]) -> Result<(), FenError> {
+    validate_pieces(pieces).map_err(InvalidFen::to_fen_error)
 }
 
 /// Formats a [`BoardBuilder`] as a [`FEN`] string
@@ -336,3 +464,737 @@ impl fmt::Display for BoardBuilder {
         )
     }
 }
+
+/// Creates a [`BoardBuilder`] constant from a FEN string, parsed at compile
+/// time.
+///
+/// The FEN string is checked like [`BoardBuilder::from_str`] checks it, and
+/// an invalid one is a compile error.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// const KIWIPETE: BoardBuilder =
+///     fen!("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1");
+///
+/// let board = KIWIPETE.build();
+/// assert_eq!(board.generate_moves().len(), 48);
+/// ```
+///
+/// ```compile_fail
+/// # use chess_engine_movegen::*;
+/// let board = fen!("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP w KQkq - 0 1");
+/// ```
+///
+/// [`BoardBuilder::from_str`]: std::str::FromStr::from_str
+#[macro_export]
+macro_rules! fen {
+    ($fen:expr) => {{
+        const BOARD_BUILDER: $crate::BoardBuilder = $crate::BoardBuilder::from_fen($fen);
+        BOARD_BUILDER
+    }};
+}
+
+/// Creates a [`BoardBuilder`] constant from a grid of pieces, checked at
+/// compile time.
+///
+/// The grid has 64 squares, rank 8 first, each a FEN piece letter or `.` for
+/// an empty square. It can be followed by `;` and the state sections of a FEN
+/// string, which default to `w - - 0 1`.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// let board = board! {
+///     r n b q k b n r
+///     p p p p . p p p
+///     . . . . . . . .
+///     . . . . p . . .
+///     . . . . P . . .
+///     . . . . . . . .
+///     P P P P . P P P
+///     R N B Q K B N R;
+///     w KQkq e6 0 2
+/// };
+///
+/// assert_eq!(
+///     board.to_string(),
+///     "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2"
+/// );
+/// ```
+///
+/// ```compile_fail
+/// # use chess_engine_movegen::*;
+/// let board = board! {
+///     . . . . k . . .
+///     . . . . . . . .
+/// };
+/// ```
+#[macro_export]
+macro_rules! board {
+    (@board [$($square:tt)*] [$($state:tt)*]) => {{
+        const BOARD_BUILDER: $crate::BoardBuilder = $crate::BoardBuilder::from_grid(
+            concat!($(stringify!($square)),*),
+            stringify!($($state)*),
+        );
+        BOARD_BUILDER
+    }};
+    (
+        $a8:tt $b8:tt $c8:tt $d8:tt $e8:tt $f8:tt $g8:tt $h8:tt
+        $a7:tt $b7:tt $c7:tt $d7:tt $e7:tt $f7:tt $g7:tt $h7:tt
+        $a6:tt $b6:tt $c6:tt $d6:tt $e6:tt $f6:tt $g6:tt $h6:tt
+        $a5:tt $b5:tt $c5:tt $d5:tt $e5:tt $f5:tt $g5:tt $h5:tt
+        $a4:tt $b4:tt $c4:tt $d4:tt $e4:tt $f4:tt $g4:tt $h4:tt
+        $a3:tt $b3:tt $c3:tt $d3:tt $e3:tt $f3:tt $g3:tt $h3:tt
+        $a2:tt $b2:tt $c2:tt $d2:tt $e2:tt $f2:tt $g2:tt $h2:tt
+        $a1:tt $b1:tt $c1:tt $d1:tt $e1:tt $f1:tt $g1:tt $h1:tt
+        $(; $($state:tt)*)?
+    ) => {
+        $crate::board! {
+            @board [
+                $a8 $b8 $c8 $d8 $e8 $f8 $g8 $h8
+                $a7 $b7 $c7 $d7 $e7 $f7 $g7 $h7
+                $a6 $b6 $c6 $d6 $e6 $f6 $g6 $h6
+                $a5 $b5 $c5 $d5 $e5 $f5 $g5 $h5
+                $a4 $b4 $c4 $d4 $e4 $f4 $g4 $h4
+                $a3 $b3 $c3 $d3 $e3 $f3 $g3 $h3
+                $a2 $b2 $c2 $d2 $e2 $f2 $g2 $h2
+                $a1 $b1 $c1 $d1 $e1 $f1 $g1 $h1
+            ] [$($($state)*)?]
+        }
+    };
+    ($($token:tt)*) => {
+        compile_error!("Expected 64 squares, optionally followed by `;` and a FEN state")
+    };
+}
+
+/// Compile-time FEN parsing for the `BoardBuilder` struct.
+impl BoardBuilder {
+    /// Parses a [`BoardBuilder`] from a FEN string in a const context.
+    ///
+    /// Prefer the [`fen!`] macro, which makes sure the string is parsed at
+    /// compile time. For strings only known at runtime, parse them with
+    /// [`str::parse`] to get a [`FenError`] instead of a panic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the FEN string is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// const EMPTY: BoardBuilder = BoardBuilder::from_fen("8/8/8/8/8/8/8/8 b - - 10 40");
+    /// assert_eq!(EMPTY.to_string(), "8/8/8/8/8/8/8/8 b - - 10 40");
+    /// ```
+    ///
+    /// It reads the same grammar as [`str::parse`], so the two agree on every
+    /// string:
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let fen = "r3k2r/8/8/8/4Pp2/8/8/R3K2R\tb Kq e3 0 1";
+    ///
+    /// assert_eq!(
+    ///     BoardBuilder::from_fen(fen).to_string(),
+    ///     fen.parse::<BoardBuilder>().unwrap().to_string()
+    /// );
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use chess_engine_movegen::*;
+    /// BoardBuilder::from_fen("8/8/8/8/8/8/8/8 w - - 1 +2");
+    /// ```
+    ///
+    /// [`fen!`]: crate::fen!
+    pub const fn from_fen(fen: &str) -> Self {
+        match parse_fen(fen.as_bytes()) {
+            Ok(board_builder) => board_builder,
+            Err(error) => panic!("{}", error.message()),
+        }
+    }
+
+    /// Parses a [`BoardBuilder`] from a grid of pieces and the state sections
+    /// of a FEN string, in a const context.
+    ///
+    /// The grid holds 64 squares, rank 8 first, each a FEN piece letter or
+    /// `.` for an empty square. An empty state defaults to `w - - 0 1`.
+    ///
+    /// Prefer the [`board!`] macro, which makes sure the grid is parsed at
+    /// compile time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid or the state is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let grid = concat!(
+    ///     "....k...", "........", "........", "........",
+    ///     "........", "........", "........", "....K..R",
+    /// );
+    /// let board = BoardBuilder::from_grid(grid, "b K - 3 20");
+    ///
+    /// assert_eq!(board.to_string(), "4k3/8/8/8/8/8/8/4K2R b K - 3 20");
+    /// ```
+    ///
+    /// [`board!`]: crate::board!
+    pub const fn from_grid(grid: &str, state: &str) -> Self {
+        const MAX_LEN: usize = 128;
+
+        let (grid, mut state) = (grid.as_bytes(), state.as_bytes());
+
+        if grid.len() != Square::LEN {
+            panic!("invalid number of squares (expected 64)");
+        }
+
+        if state.is_empty() {
+            state = b"w - - 0 1";
+        }
+
+        let mut fen = [0; MAX_LEN];
+        let (mut len, mut index, mut empty) = (0, 0, 0);
+
+        while index < Square::LEN {
+            if grid[index] == b'.' {
+                empty += 1;
+            } else {
+                if piece_from_ascii(grid[index]).is_none() {
+                    panic!("invalid piece (expected one of PNBRQKpnbrqk or .)");
+                }
+
+                if empty > 0 {
+                    fen[len] = b'0' + empty;
+                    len += 1;
+                    empty = 0;
+                }
+
+                fen[len] = grid[index];
+                len += 1;
+            }
+
+            index += 1;
+
+            if index % File::LEN == 0 {
+                if empty > 0 {
+                    fen[len] = b'0' + empty;
+                    len += 1;
+                    empty = 0;
+                }
+
+                fen[len] = if index == Square::LEN { b' ' } else { b'/' };
+                len += 1;
+            }
+        }
+
+        if len + state.len() > MAX_LEN {
+            panic!("invalid number of FEN sections (expected 4-6)");
+        }
+
+        index = 0;
+
+        while index < state.len() {
+            fen[len] = state[index];
+            len += 1;
+            index += 1;
+        }
+
+        let (fen, _) = fen.split_at(len);
+
+        match std::str::from_utf8(fen) {
+            Ok(fen) => BoardBuilder::from_fen(fen),
+            Err(_) => panic!("invalid FEN state"),
+        }
+    }
+}
+
+/// Why a FEN string is invalid, as found by [`parse_fen`].
+///
+/// Unlike [`FenError`] it is `Copy`, so the const parser can return it. The
+/// slices are the invalid parts of the string.
+#[derive(Clone, Copy, Debug)]
+enum InvalidFen<'a> {
+    Sections(usize),
+    Ranks(usize),
+    Files(usize),
+    Piece(&'a [u8]),
+    PawnOnFirstOrLastRank,
+    TooManyPawns { color: Color, num_pawns: u8 },
+    TooManyKings { color: Color, num_kings: u8 },
+    TooManyPieces { color: Color, num_pieces: u8 },
+    Color(&'a [u8]),
+    CastleRights(&'a [u8]),
+    EnPassantSquare(&'a [u8]),
+    EnPassantRank(Rank),
+    HalfmoveClock,
+    FullmoveCounter,
+}
+
+/// Methods for the `InvalidFen` enum.
+impl InvalidFen<'_> {
+    /// Returns the message [`BoardBuilder::from_fen`] panics with.
+    const fn message(self) -> &'static str {
+        match self {
+            InvalidFen::Sections(_) => "invalid number of FEN sections (expected 4-6)",
+            InvalidFen::Ranks(_) => "invalid number of ranks (expected 8)",
+            InvalidFen::Files(_) => "invalid number of files (expected 8)",
+            InvalidFen::Piece(_) => "invalid piece (expected one of PNBRQKpnbrqk)",
+            InvalidFen::PawnOnFirstOrLastRank => "pawns cannot be on the first or last rank",
+            InvalidFen::TooManyPawns { .. } => "a color has too many pawns (expected 8 or fewer)",
+            InvalidFen::TooManyKings { .. } => "a color has too many kings (expected 1 or fewer)",
+            InvalidFen::TooManyPieces { .. } => {
+                "a color has too many pieces (expected 16 or fewer)"
+            }
+            InvalidFen::Color(_) => "invalid Color (expected w-b)",
+            InvalidFen::CastleRights(_) => "invalid castle rights (expected KQkq or -)",
+            InvalidFen::EnPassantSquare(_) | InvalidFen::EnPassantRank(_) => {
+                "invalid en passant square (expected a square on rank 3 or 6, or -)"
+            }
+            InvalidFen::HalfmoveClock => "invalid halfmove clock",
+            InvalidFen::FullmoveCounter => "invalid fullmove counter",
+        }
+    }
+
+    /// Returns the [`FenError`] parsing with [`str::parse`] returns.
+    fn to_fen_error(self) -> FenError {
+        let text = |bytes: &[u8]| String::from_utf8_lossy(bytes).into_owned();
+
+        match self {
+            InvalidFen::Sections(num_sections) => FenError::Sections(num_sections),
+            InvalidFen::Ranks(num_ranks) => FenError::Ranks(num_ranks),
+            InvalidFen::Files(num_files) => FenError::Files(num_files),
+            InvalidFen::Piece(symbol) => {
+                FenError::Piece(PieceTypeError(text(symbol).to_lowercase()))
+            }
+            InvalidFen::PawnOnFirstOrLastRank => FenError::PawnOnFirstOrLastRank,
+            InvalidFen::TooManyPawns { color, num_pawns } => {
+                FenError::ToManyPawns { color, num_pawns }
+            }
+            InvalidFen::TooManyKings { color, num_kings } => {
+                FenError::TooManyKings { color, num_kings }
+            }
+            InvalidFen::TooManyPieces { color, num_pieces } => {
+                FenError::ToManyPieces { color, num_pieces }
+            }
+            InvalidFen::Color(section) => FenError::Color(ColorError(text(section))),
+            InvalidFen::CastleRights(section) => {
+                FenError::CastleRights(CastleRightsTypeError(text(section)))
+            }
+            InvalidFen::EnPassantSquare(section) => match text(section).parse::<Square>() {
+                Err(error) => FenError::EnPassantSquare(error),
+                Ok(square) => FenError::EnPassantRank(square.rank()),
+            },
+            InvalidFen::EnPassantRank(rank) => FenError::EnPassantRank(rank),
+            InvalidFen::HalfmoveClock => FenError::HalfmoveClock,
+            InvalidFen::FullmoveCounter => FenError::FullmoveCounter,
+        }
+    }
+}
+
+/// Parses a FEN string. This is the one FEN grammar, shared by
+/// [`BoardBuilder::from_str`] and the const [`BoardBuilder::from_fen`].
+///
+/// The sections are separated by ASCII whitespace. Without them, the
+/// halfmove clock is 0 and the fullmove counter 1.
+///
+/// [`BoardBuilder::from_str`]: std::str::FromStr::from_str
+const fn parse_fen(fen: &[u8]) -> Result<BoardBuilder, InvalidFen<'_>> {
+    let num_sections = count_sections(fen);
+
+    if num_sections < 4 || num_sections > 6 {
+        return Err(InvalidFen::Sections(num_sections));
+    }
+
+    let pieces = match parse_placement(nth_section(fen, 0)) {
+        Ok(pieces) => pieces,
+        Err(error) => return Err(error),
+    };
+
+    if let Err(error) = validate_pieces(&pieces) {
+        return Err(error);
+    }
+
+    let color = match nth_section(fen, 1) {
+        b"w" => Color::White,
+        b"b" => Color::Black,
+        section => return Err(InvalidFen::Color(section)),
+    };
+
+    let castling_rights = match parse_castling_rights(nth_section(fen, 2)) {
+        Ok(castling_rights) => castling_rights,
+        Err(error) => return Err(error),
+    };
+
+    let en_passant_square = match parse_en_passant_square(nth_section(fen, 3)) {
+        Ok(en_passant_square) => en_passant_square,
+        Err(error) => return Err(error),
+    };
+
+    let mut halfmove_clock = 0;
+
+    if num_sections > 4 {
+        halfmove_clock = match parse_number(nth_section(fen, 4)) {
+            Some(halfmove_clock) if halfmove_clock <= State::MAX_HALFMOVE_CLOCK as u32 => {
+                halfmove_clock as u8
+            }
+            _ => return Err(InvalidFen::HalfmoveClock),
+        };
+    }
+
+    let mut fullmove_counter = 1;
+
+    if num_sections > 5 {
+        fullmove_counter = match parse_number(nth_section(fen, 5)) {
+            Some(fullmove_counter)
+                if fullmove_counter > 0 && fullmove_counter <= u16::MAX as u32 =>
+            {
+                fullmove_counter as u16
+            }
+            _ => return Err(InvalidFen::FullmoveCounter),
+        };
+
+        if halfmove_clock as u32 > fullmove_counter as u32 * 2 {
+            return Err(InvalidFen::HalfmoveClock);
+        }
+    }
+
+    Ok(BoardBuilder {
+        pieces,
+        state: State::new(
+            color,
+            castling_rights,
+            en_passant_square,
+            halfmove_clock,
+            fullmove_counter,
+        ),
+    })
+}
+
+/// Parses the piece placement section of a FEN string
+///
+/// Returns an array of pieces, where the index is the square index on the
+/// board and the value is the piece on that square. The number of ranks and
+/// files is checked before the pieces.
+const fn parse_placement(placement: &[u8]) -> Result<[Option<Piece>; Square::LEN], InvalidFen<'_>> {
+    let mut num_ranks = 1;
+    let mut index = 0;
+
+    while index < placement.len() {
+        if placement[index] == b'/' {
+            num_ranks += 1;
+        }
+
+        index += 1;
+    }
+
+    if num_ranks != Rank::LEN {
+        return Err(InvalidFen::Ranks(num_ranks));
+    }
+
+    let mut num_files = 0;
+    index = 0;
+
+    while index <= placement.len() {
+        if index == placement.len() || placement[index] == b'/' {
+            if num_files != File::LEN {
+                return Err(InvalidFen::Files(num_files));
+            }
+
+            num_files = 0;
+        } else {
+            num_files += symbol_files(placement[index]);
+        }
+
+        index += 1;
+    }
+
+    let mut pieces = [None; Square::LEN];
+    let (mut rank, mut file) = (Rank::LEN - 1, 0);
+    index = 0;
+
+    while index < placement.len() {
+        let symbol = placement[index];
+
+        if symbol == b'/' {
+            rank -= 1;
+            file = 0;
+        } else if symbol.is_ascii_digit() {
+            file += symbol_files(symbol);
+        } else if !is_continuation_byte(symbol) {
+            let piece = match piece_from_ascii(symbol) {
+                Some(piece) => piece,
+                None => {
+                    let (_, rest) = placement.split_at(index);
+                    let (symbol, _) = rest.split_at(char_len(symbol));
+
+                    return Err(InvalidFen::Piece(symbol));
+                }
+            };
+
+            let square = Square::with_file_rank(File::new(file), Rank::new(rank));
+            pieces[square as usize] = Some(piece);
+            file += 1;
+        }
+
+        index += 1;
+    }
+
+    Ok(pieces)
+}
+
+/// Checks that the pieces can be on a board: at most one king, eight pawns
+/// and sixteen pieces per color, and no pawn on the first or last rank.
+const fn validate_pieces(pieces: &[Option<Piece>; Square::LEN]) -> Result<(), InvalidFen<'static>> {
+    let mut num_pieces = [0; Color::LEN];
+    let mut num_pawns = [0; Color::LEN];
+    let mut num_kings = [0; Color::LEN];
+    let mut square = 0;
+
+    while square < Square::LEN {
+        if let Some(piece) = pieces[square] {
+            let color = piece.color() as usize;
+            num_pieces[color] += 1;
+
+            match piece.piece_type() {
+                PieceType::King => num_kings[color] += 1,
+                PieceType::Pawn => {
+                    let rank = square / File::LEN;
+
+                    if rank == 0 || rank == Rank::LEN - 1 {
+                        return Err(InvalidFen::PawnOnFirstOrLastRank);
+                    }
+
+                    num_pawns[color] += 1;
+                }
+                _ => {}
+            }
+        }
+
+        square += 1;
+    }
+
+    let mut color = 0;
+
+    while color < Color::LEN {
+        if num_kings[color] > 1 {
+            return Err(InvalidFen::TooManyKings {
+                color: Color::new(color),
+                num_kings: num_kings[color],
+            });
+        }
+
+        color += 1;
+    }
+
+    color = 0;
+
+    while color < Color::LEN {
+        if num_pieces[color] > Piece::MAX_PIECES_PER_COLOR {
+            return Err(InvalidFen::TooManyPieces {
+                color: Color::new(color),
+                num_pieces: num_pieces[color] as u8,
+            });
+        }
+
+        color += 1;
+    }
+
+    color = 0;
+
+    while color < Color::LEN {
+        if num_pawns[color] > Piece::MAX_PAWNS_PER_COLOR {
+            return Err(InvalidFen::TooManyPawns {
+                color: Color::new(color),
+                num_pawns: num_pawns[color] as u8,
+            });
+        }
+
+        color += 1;
+    }
+
+    Ok(())
+}
+
+/// Parses the castling rights section of a FEN string, `-` or any of
+/// `KQkq`.
+const fn parse_castling_rights(section: &[u8]) -> Result<CastleRights, InvalidFen<'_>> {
+    let mut castling_rights = [0; Color::LEN];
+
+    if section.len() > 4 {
+        return Err(InvalidFen::CastleRights(section));
+    }
+
+    if let b"-" = section {
+        return Ok(CastleRights([CastleRightsType::None; Color::LEN]));
+    }
+
+    let mut index = 0;
+
+    while index < section.len() {
+        let (color, side) = match section[index] {
+            b'K' => (Color::White, CastleRightsType::KingSide),
+            b'Q' => (Color::White, CastleRightsType::QueenSide),
+            b'k' => (Color::Black, CastleRightsType::KingSide),
+            b'q' => (Color::Black, CastleRightsType::QueenSide),
+            _ => return Err(InvalidFen::CastleRights(section)),
+        };
+
+        castling_rights[color as usize] |= side as usize;
+        index += 1;
+    }
+
+    Ok(CastleRights([
+        CastleRightsType::new(castling_rights[0]),
+        CastleRightsType::new(castling_rights[1]),
+    ]))
+}
+
+/// Parses the en passant section of a FEN string, `-` or a square on rank 3
+/// or 6.
+const fn parse_en_passant_square(section: &[u8]) -> Result<Option<Square>, InvalidFen<'_>> {
+    match section {
+        b"-" => Ok(None),
+        [file @ b'a'..=b'h', rank @ b'1'..=b'8'] => {
+            let square = Square::with_file_rank(
+                File::new((*file - b'a') as usize),
+                Rank::new((*rank - b'1') as usize),
+            );
+
+            match square.rank() {
+                Rank::Three | Rank::Six => Ok(Some(square)),
+                rank => Err(InvalidFen::EnPassantRank(rank)),
+            }
+        }
+        _ => Err(InvalidFen::EnPassantSquare(section)),
+    }
+}
+
+/// Returns the number of whitespace separated sections of a FEN string.
+const fn count_sections(fen: &[u8]) -> usize {
+    let mut num_sections = 0;
+    let mut index = skip_whitespace(fen, 0);
+
+    while index < fen.len() {
+        num_sections += 1;
+        index = skip_whitespace(fen, index + section(fen, index).len());
+    }
+
+    num_sections
+}
+
+/// Returns the section of a FEN string at position `n`, counting from 0.
+const fn nth_section(fen: &[u8], n: usize) -> &[u8] {
+    let mut index = skip_whitespace(fen, 0);
+    let mut skipped = 0;
+
+    while skipped < n {
+        index = skip_whitespace(fen, index + section(fen, index).len());
+        skipped += 1;
+    }
+
+    section(fen, index)
+}
+
+/// Returns the index of the first non-whitespace byte from `index`.
+const fn skip_whitespace(fen: &[u8], mut index: usize) -> usize {
+    while index < fen.len() && fen[index].is_ascii_whitespace() {
+        index += 1;
+    }
+
+    index
+}
+
+/// Returns the bytes from `index` up to the next whitespace.
+const fn section(fen: &[u8], index: usize) -> &[u8] {
+    let mut end = index;
+
+    while end < fen.len() && !fen[end].is_ascii_whitespace() {
+        end += 1;
+    }
+
+    let (_, rest) = fen.split_at(index);
+    let (section, _) = rest.split_at(end - index);
+
+    section
+}
+
+/// Parses a decimal number, or returns `None` if it is empty, has something
+/// other than digits or does not fit in a `u32`.
+const fn parse_number(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+
+    let (mut number, mut index) = (0u32, 0);
+
+    while index < digits.len() {
+        let digit = match digits[index] {
+            digit @ b'0'..=b'9' => (digit - b'0') as u32,
+            _ => return None,
+        };
+
+        number = match number.checked_mul(10) {
+            Some(number) => match number.checked_add(digit) {
+                Some(number) => number,
+                None => return None,
+            },
+            None => return None,
+        };
+
+        index += 1;
+    }
+
+    Some(number)
+}
+
+/// Returns the number of files a byte of a piece placement takes: the
+/// number for a digit, nothing for the rest of a multi-byte character and
+/// one for anything else.
+const fn symbol_files(symbol: u8) -> usize {
+    if symbol.is_ascii_digit() {
+        (symbol - b'0') as usize
+    } else if is_continuation_byte(symbol) {
+        0
+    } else {
+        1
+    }
+}
+
+/// Returns `true` if the byte continues a multi-byte UTF-8 character.
+const fn is_continuation_byte(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+/// Returns the length of the UTF-8 character starting with a byte.
+const fn char_len(byte: u8) -> usize {
+    if byte.is_ascii() {
+        1
+    } else {
+        byte.leading_ones() as usize
+    }
+}
+
+/// Returns the [`Piece`] of a FEN piece letter.
+const fn piece_from_ascii(symbol: u8) -> Option<Piece> {
+    let color = if symbol.is_ascii_uppercase() {
+        Color::White
+    } else {
+        Color::Black
+    };
+
+    let piece_type = match symbol.to_ascii_lowercase() {
+        b'p' => PieceType::Pawn,
+        b'n' => PieceType::Knight,
+        b'b' => PieceType::Bishop,
+        b'r' => PieceType::Rook,
+        b'q' => PieceType::Queen,
+        b'k' => PieceType::King,
+        _ => return None,
+    };
+
+    Some(Piece::new(piece_type, color))
+}
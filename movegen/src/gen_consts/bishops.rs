@@ -1,6 +1,6 @@
 use chess_engine_core::{bitboard, BitBoard, Square};
 
-use crate::gen_consts::magic::mask_blockers;
+use crate::gen_consts::magic::{magic_index, mask_blockers};
 
 /// Returns the mask of the relevant bishop blockers for a square.
 ///
@@ -120,100 +120,78 @@ pub const fn mask_bishop_attacks(square: Square, blockers: BitBoard) -> BitBoard
 
 /// Writes to a file
 ///
-/// - the precomputed bishop attacks for all squares and blockers.
+/// - the relevant bishop blockers for all squares.
 /// - the relevant bishop blockers count for all squares.
-pub fn write(f: &mut std::fs::File, magic_numbers: &[u64; Square::LEN]) {
+///
+/// Returns the bishop attacks of every square, indexed by magic index.
+pub fn write(f: &mut std::fs::File, magic_numbers: &[u64; Square::LEN]) -> Vec<Vec<BitBoard>> {
     use std::io::Write;
 
     let mut blockers_count = [0; Square::LEN];
 
     // relevant bishop blockers
 
-    write!(f, "/// Relevant bishop blockers for all squares\n").unwrap();
+    writeln!(f, "/// Relevant bishop blockers for all squares").unwrap();
 
-    write!(
+    writeln!(
         f,
-        "pub const RELEVANT_BISHOP_BLOCKERS: [BitBoard; {}] = [\n",
+        "pub const RELEVANT_BISHOP_BLOCKERS: [BitBoard; {}] = [",
         Square::LEN
     )
     .unwrap();
 
     for square in Square::ALL {
-        write!(
+        writeln!(
             f,
-            "\tBitBoard({}),\n",
+            "\tBitBoard({}),",
             mask_relevant_bishop_blockers(square).0
         )
         .unwrap();
     }
 
-    write!(f, "];\n").unwrap();
+    writeln!(f, "];").unwrap();
 
-    write!(f, "/// Relevant bishop blockers count for all squares\n").unwrap();
+    writeln!(f, "/// Relevant bishop blockers count for all squares").unwrap();
 
-    write!(
+    writeln!(
         f,
-        "pub const RELEVANT_BISHOP_BLOCKERS_COUNT: [u8; {}] = [\n",
+        "pub const RELEVANT_BISHOP_BLOCKERS_COUNT: [u8; {}] = [",
         Square::LEN
     )
     .unwrap();
 
     for square in Square::ALL {
         let count = mask_relevant_bishop_blockers(square).0.count_ones() as u8;
+
         blockers_count[square as usize] = count;
-        write!(f, "\t{},\n", count).unwrap();
+        writeln!(f, "\t{},", count).unwrap();
     }
 
-    write!(f, "];\n").unwrap();
+    writeln!(f, "];").unwrap();
 
     // precomputed bishop attacks
 
-    let max_blockers = 1 << blockers_count.iter().max().unwrap();
-
-    let mut attacks: Vec<Vec<BitBoard>> = vec![vec![BitBoard::EMPTY; max_blockers]; Square::LEN];
+    let mut attacks = Vec::with_capacity(Square::LEN);
 
     for square in Square::ALL {
-        for blockers_pattern in 0..max_blockers {
-            let blockers = mask_blockers(
-                blockers_pattern as u64,
-                mask_relevant_bishop_blockers(square),
-            );
-
-            let magic_index = (blockers.0.wrapping_mul(magic_numbers[square as usize]))
-                >> (64 - blockers_count[square as usize]);
+        let relevant_blockers = mask_relevant_bishop_blockers(square);
+        let mut square_attacks = vec![BitBoard::EMPTY; 1 << blockers_count[square as usize]];
 
-            attacks[square as usize][magic_index as usize] = mask_bishop_attacks(square, blockers);
-        }
-    }
-
-    write!(
-        f,
-        "/// Precomputed bishop attacks for all squares and blockers\n"
-    )
-    .unwrap();
+        for blockers_pattern in 0..1u64 << blockers_count[square as usize] {
+            let blockers = mask_blockers(blockers_pattern, relevant_blockers);
 
-    write!(
-        f,
-        "pub const BISHOP_ATTACKS: [[BitBoard; {}]; {}] = [\n",
-        max_blockers,
-        Square::LEN
-    )
-    .unwrap();
+            let magic_index = magic_index(
+                blockers,
+                relevant_blockers,
+                magic_numbers[square as usize],
+                blockers_count[square as usize],
+            );
 
-    for square in Square::ALL {
-        write!(f, "\t[\n").unwrap();
-
-        for blockers_pattern in 0..max_blockers {
-            write!(
-                f,
-                "\t\tBitBoard({}),\n",
-                attacks[square as usize][blockers_pattern].0
-            )
-            .unwrap();
+            square_attacks[magic_index] = mask_bishop_attacks(square, blockers);
         }
 
-        write!(f, "\t],\n").unwrap();
+        attacks.push(square_attacks);
     }
 
-    write!(f, "];\n").unwrap();
+    attacks
 }
@@ -1,6 +1,6 @@
 use std::{env, fs::File, path::Path};
 
-use super::{king, knights, magic, pawns};
+use super::{king, knights, magic, pawns, spans};
 
 pub fn generate_all() {
     let out_dir = env::var("OUT_DIR").unwrap();
@@ -12,4 +12,5 @@ pub fn generate_all() {
     king::write(&mut f);
     knights::write(&mut f);
     magic::write(&mut f);
+    spans::write(&mut f);
 }
@@ -1,4 +1,4 @@
-use chess_engine_core::{BitBoard, Square};
+use chess_engine_core::{BitBoard, Color, File, Rank, Square};
 
 /// Returns the mask for the king attacks for a square.
 ///
@@ -37,22 +37,101 @@ pub const fn mask_king_attacks(square: Square) -> BitBoard {
     attacks
 }
 
-/// Writes to a file the precomputed king attacks for all squares.
+/// Returns the mask for the king zone of a king of a color on a square.
+///
+/// The zone is the king ring, the king and its neighbors, extended by the
+/// three squares two ranks in front of the king. The zone of a king on an
+/// edge, or too far forward, is moved inward so every zone has 12 squares.
+///
+/// # Example
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// # use chess_engine_core::*;
+/// let king_zone = mask_king_zone(Color::White, Square::G1);
+///
+/// assert_eq!(king_zone, bitboard!{
+///     . . . . . . . .
+///     . . . . . . . .
+///     . . . . . . . .
+///     . . . . . . . .
+///     . . . . . X X X
+///     . . . . . X X X
+///     . . . . . X X X
+///     . . . . . X X X
+/// });
+/// ```
+pub const fn mask_king_zone(color: Color, square: Square) -> BitBoard {
+    let file = clamp(square.file() as usize, File::B as usize, File::G as usize);
+    let rank = match color {
+        Color::White => clamp(
+            square.rank() as usize,
+            Rank::Two as usize,
+            Rank::Six as usize,
+        ),
+        Color::Black => clamp(
+            square.rank() as usize,
+            Rank::Three as usize,
+            Rank::Seven as usize,
+        ),
+    };
+
+    let center = Square::with_file_rank(File::new(file), Rank::new(rank));
+    let ring = mask_king_attacks(center).set_square(center);
+
+    let front = match color {
+        Color::White => ring.up(),
+        Color::Black => ring.down(),
+    };
+
+    ring.set_bit(front.0)
+}
+
+const fn clamp(value: usize, min: usize, max: usize) -> usize {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Writes to a file
+///
+/// - the precomputed king attacks for all squares.
+/// - the precomputed king zones for all squares and colors.
 pub fn write(f: &mut std::fs::File) {
     use std::io::Write;
 
-    write!(f, "/// Precomputed king attacks for all squares\n").unwrap();
+    writeln!(f, "/// Precomputed king attacks for all squares").unwrap();
+
+    writeln!(f, "pub const KING_ATTACKS: [BitBoard; {}] = [", Square::LEN).unwrap();
+
+    for square in Square::ALL {
+        writeln!(f, "\tBitBoard({}),", mask_king_attacks(square).0).unwrap();
+    }
+
+    writeln!(f, "];").unwrap();
+
+    writeln!(f, "/// Precomputed king zones for all squares and colors").unwrap();
 
-    write!(
+    writeln!(
         f,
-        "pub const KING_ATTACKS: [BitBoard; {}] = [\n",
-        Square::LEN
+        "pub const KING_ZONES: [[BitBoard; {}]; {}] = [",
+        Square::LEN,
+        Color::LEN
     )
     .unwrap();
 
-    for square in Square::ALL {
-        write!(f, "\tBitBoard({}),\n", mask_king_attacks(square).0).unwrap();
-    }
+    for color in Color::ALL {
+        writeln!(f, "\t[").unwrap();
 
-    write!(f, "];\n").unwrap();
+        for square in Square::ALL {
+            writeln!(f, "\t\tBitBoard({}),", mask_king_zone(color, square).0).unwrap();
+        }
+
+        writeln!(f, "\t],").unwrap();
+    }
+    writeln!(f, "];").unwrap();
 }
@@ -41,18 +41,18 @@ pub const fn mask_knight_attacks(square: Square) -> BitBoard {
 pub fn write(f: &mut std::fs::File) {
     use std::io::Write;
 
-    write!(f, "/// Precomputed knight attacks for all squares\n").unwrap();
+    writeln!(f, "/// Precomputed knight attacks for all squares").unwrap();
 
-    write!(
+    writeln!(
         f,
-        "pub const KNIGHT_ATTACKS: [BitBoard; {}] = [\n",
+        "pub const KNIGHT_ATTACKS: [BitBoard; {}] = [",
         Square::LEN
     )
     .unwrap();
 
     for square in Square::ALL {
-        write!(f, "\tBitBoard({}),\n", mask_knight_attacks(square).0).unwrap();
+        writeln!(f, "\tBitBoard({}),", mask_knight_attacks(square).0).unwrap();
     }
 
-    write!(f, "];\n").unwrap();
+    writeln!(f, "];").unwrap();
 }
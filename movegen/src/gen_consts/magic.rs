@@ -66,6 +66,19 @@ pub const fn mask_blockers(pattern: u64, blockers: BitBoard) -> BitBoard {
     mask
 }
 
+/// Returns the index of the blockers in the attacks table of a square.
+///
+/// The blockers are masked with the relevant blockers, multiplied by the
+/// magic number and shifted so only the relevant bits are left.
+pub const fn magic_index(
+    blockers: BitBoard,
+    relevant_blockers: BitBoard,
+    magic_number: u64,
+    relevant_bits: u8,
+) -> usize {
+    ((blockers.0 & relevant_blockers.0).wrapping_mul(magic_number) >> (64 - relevant_bits)) as usize
+}
+
 /// Finds the magic number for a given square and sliding piece.
 fn magic_number(square: Square, sliding_piece: SlidingPiece) -> u64 {
     let relevant_bits: u8 = match sliding_piece {
@@ -109,12 +122,16 @@ fn magic_number(square: Square, sliding_piece: SlidingPiece) -> u64 {
         let mut fail = false;
 
         while index < blockers_index && !fail {
-            let magic_index =
-                (blockers[index].0.wrapping_mul(magic_number)) >> (64 - relevant_bits);
-
-            if used_attacks[magic_index as usize].0 == BitBoard::EMPTY.0 {
-                used_attacks[magic_index as usize] = attacks[index];
-            } else if used_attacks[magic_index as usize].0 != attacks[index].0 {
+            let magic_index = magic_index(
+                blockers[index],
+                relevant_blockers,
+                magic_number,
+                relevant_bits,
+            );
+
+            if used_attacks[magic_index].0 == BitBoard::EMPTY.0 {
+                used_attacks[magic_index] = attacks[index];
+            } else if used_attacks[magic_index].0 != attacks[index].0 {
                 fail = true;
             }
 
@@ -133,30 +150,88 @@ pub fn write(f: &mut std::fs::File) {
 
     let mut magic_numbers = [[0u64; Square::LEN]; SlidingPiece::LEN];
 
-    write!(f, "/// Magic numbers for all squares and sliding pieces\n").unwrap();
+    writeln!(f, "/// Magic numbers for all squares and sliding pieces").unwrap();
 
-    write!(
+    writeln!(
         f,
-        "pub const MAGIC_NUMBERS: [[u64; {}]; {}] = [\n",
+        "pub const MAGIC_NUMBERS: [[u64; {}]; {}] = [",
         Square::LEN,
         SlidingPiece::LEN
     )
     .unwrap();
 
     for sliding_piece in SlidingPiece::ALL {
-        write!(f, "\t[\n").unwrap();
+        writeln!(f, "\t[").unwrap();
         for square in Square::ALL {
             let magic_number = magic_number(square, sliding_piece);
 
             magic_numbers[sliding_piece as usize][square as usize] = magic_number;
-            write!(f, "\t\t{},\n", magic_number).unwrap();
+            writeln!(f, "\t\t{},", magic_number).unwrap();
         }
 
-        write!(f, "\t],\n").unwrap();
+        writeln!(f, "\t],").unwrap();
     }
 
-    write!(f, "];\n").unwrap();
+    writeln!(f, "];").unwrap();
+
+    let bishop_attacks = bishops::write(f, &magic_numbers[SlidingPiece::Bishop as usize]);
+    let rook_attacks = rooks::write(f, &magic_numbers[SlidingPiece::Rook as usize]);
+
+    write_attacks(f, [bishop_attacks, rook_attacks]);
+}
+
+/// Writes to a file the precomputed attacks for all sliding pieces.
+///
+/// Every sliding piece gets its own table, and every square only takes as
+/// many entries as its relevant blockers need.
+fn write_attacks(f: &mut std::fs::File, attacks: [Vec<Vec<BitBoard>>; SlidingPiece::LEN]) {
+    use std::io::Write;
+
+    for sliding_piece in SlidingPiece::ALL {
+        let name = match sliding_piece {
+            SlidingPiece::Bishop => "BISHOP",
+            SlidingPiece::Rook => "ROOK",
+        };
 
-    bishops::write(f, &magic_numbers[SlidingPiece::Bishop as usize]);
-    rooks::write(f, &magic_numbers[SlidingPiece::Rook as usize]);
+        let attacks = &attacks[sliding_piece as usize];
+
+        writeln!(
+            f,
+            "/// Offsets of every square into the {} attacks table",
+            name.to_lowercase()
+        )
+        .unwrap();
+
+        writeln!(
+            f,
+            "pub const {}_ATTACKS_OFFSETS: [usize; {}] = [",
+            name,
+            Square::LEN
+        )
+        .unwrap();
+
+        let mut len = 0;
+
+        for square_attacks in attacks {
+            writeln!(f, "\t{},", len).unwrap();
+            len += square_attacks.len();
+        }
+
+        writeln!(f, "];").unwrap();
+
+        writeln!(
+            f,
+            "/// Precomputed {} attacks for all squares and blockers",
+            name.to_lowercase()
+        )
+        .unwrap();
+
+        writeln!(f, "pub static {}_ATTACKS: [BitBoard; {}] = [", name, len).unwrap();
+
+        for attack in attacks.iter().flatten() {
+            writeln!(f, "\tBitBoard({}),", attack.0).unwrap();
+        }
+
+        writeln!(f, "];").unwrap();
+    }
 }
@@ -1,9 +1,15 @@
-mod bishops;
-mod generate;
-mod king;
-mod knights;
-mod magic;
-mod pawns;
-mod rooks;
+//! Attack masks, and the build script writing their tables.
+//!
+//! The `mask_*` functions compute attacks and masks square by square, with
+//! loops instead of lookups. The build script runs them for every square to
+//! write the tables of `magic_gen.rs`, and the library exports them for code
+//! that needs masks the tables don't cover.
 
-pub use generate::generate_all;
+pub mod bishops;
+pub mod generate;
+pub mod king;
+pub mod knights;
+pub mod magic;
+pub mod pawns;
+pub mod rooks;
+pub mod spans;
@@ -116,47 +116,47 @@ pub fn write(f: &mut std::fs::File) {
 
     // pawn attacks
 
-    write!(f, "/// Precomputed pawn attacks for all squares\n").unwrap();
+    writeln!(f, "/// Precomputed pawn attacks for all squares").unwrap();
 
-    write!(
+    writeln!(
         f,
-        "pub const PAWN_ATTACKS: [[BitBoard; {}]; {}] = [\n",
+        "pub const PAWN_ATTACKS: [[BitBoard; {}]; {}] = [",
         Square::LEN,
         Color::LEN
     )
     .unwrap();
 
     for color in Color::ALL {
-        write!(f, "\t[\n").unwrap();
+        writeln!(f, "\t[").unwrap();
 
         for square in Square::ALL {
-            write!(f, "\t\tBitBoard({}),\n", mask_pawn_attacks(color, square).0).unwrap();
+            writeln!(f, "\t\tBitBoard({}),", mask_pawn_attacks(color, square).0).unwrap();
         }
 
-        write!(f, "\t],\n").unwrap();
+        writeln!(f, "\t],").unwrap();
     }
-    write!(f, "];\n").unwrap();
+    writeln!(f, "];").unwrap();
 
     // pawn moves
 
-    write!(f, "/// Precomputed pawn moves for all squares\n").unwrap();
+    writeln!(f, "/// Precomputed pawn moves for all squares").unwrap();
 
-    write!(
+    writeln!(
         f,
-        "pub const PAWN_MOVES: [[BitBoard; {}]; {}] = [\n",
+        "pub const PAWN_MOVES: [[BitBoard; {}]; {}] = [",
         Square::LEN,
         Color::LEN
     )
     .unwrap();
 
     for color in Color::ALL {
-        write!(f, "\t[\n").unwrap();
+        writeln!(f, "\t[").unwrap();
 
         for square in Square::ALL {
-            write!(f, "\t\tBitBoard({}),\n", mask_pawn_moves(color, square).0).unwrap();
+            writeln!(f, "\t\tBitBoard({}),", mask_pawn_moves(color, square).0).unwrap();
         }
 
-        write!(f, "\t],\n").unwrap();
+        writeln!(f, "\t],").unwrap();
     }
-    write!(f, "];\n").unwrap();
+    writeln!(f, "];").unwrap();
 }
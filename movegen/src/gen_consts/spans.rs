@@ -0,0 +1,162 @@
+use chess_engine_core::{BitBoard, Color, File, Square};
+
+/// Returns the mask for the squares in front of a pawn on its file, up to the
+/// last rank.
+///
+/// # Example
+///
+/// ```
+/// # use chess_engine_core::*;
+/// # use chess_engine_movegen::*;
+/// let front_span = mask_pawn_front_span(Color::White, Square::E4);
+///
+/// assert_eq!(front_span, bitboard!{
+///     . . . . X . . .
+///     . . . . X . . .
+///     . . . . X . . .
+///     . . . . X . . .
+///     . . . . . . . .
+///     . . . . . . . .
+///     . . . . . . . .
+///     . . . . . . . .
+/// });
+/// ```
+pub const fn mask_pawn_front_span(color: Color, square: Square) -> BitBoard {
+    let mut square = BitBoard(1u64 << square as usize);
+
+    let mut span = BitBoard::EMPTY;
+
+    loop {
+        square = match color {
+            Color::White => square.up(),
+            Color::Black => square.down(),
+        };
+
+        if square.is_empty() {
+            break;
+        }
+
+        span = span.set_bit(square.0);
+    }
+
+    span
+}
+
+/// Returns the mask for the squares a pawn can attack while it advances: the
+/// front spans of the adjacent files.
+///
+/// # Example
+///
+/// ```
+/// # use chess_engine_core::*;
+/// # use chess_engine_movegen::*;
+/// let attack_span = mask_pawn_attack_span(Color::Black, Square::A5);
+///
+/// assert_eq!(attack_span, bitboard!{
+///     . . . . . . . .
+///     . . . . . . . .
+///     . . . . . . . .
+///     . . . . . . . .
+///     . X . . . . . .
+///     . X . . . . . .
+///     . X . . . . . .
+///     . X . . . . . .
+/// });
+/// ```
+pub const fn mask_pawn_attack_span(color: Color, square: Square) -> BitBoard {
+    let front_span = mask_pawn_front_span(color, square);
+
+    front_span.left().set_bit(front_span.right().0)
+}
+
+/// Returns the mask for the files next to a file.
+///
+/// # Example
+///
+/// ```
+/// # use chess_engine_core::*;
+/// # use chess_engine_movegen::*;
+/// assert_eq!(mask_adjacent_files(File::A), File::B.bitboard());
+/// ```
+pub const fn mask_adjacent_files(file: File) -> BitBoard {
+    let file = file.bitboard();
+
+    file.left().set_bit(file.right().0)
+}
+
+/// Writes to a file
+///
+/// - the precomputed pawn front spans for all squares and colors.
+/// - the precomputed pawn attack spans for all squares and colors.
+/// - the precomputed adjacent files for all files.
+pub fn write(f: &mut std::fs::File) {
+    use std::io::Write;
+
+    // pawn front spans
+
+    writeln!(f, "/// Precomputed pawn front spans for all squares").unwrap();
+
+    writeln!(
+        f,
+        "pub const PAWN_FRONT_SPANS: [[BitBoard; {}]; {}] = [",
+        Square::LEN,
+        Color::LEN
+    )
+    .unwrap();
+
+    for color in Color::ALL {
+        writeln!(f, "\t[").unwrap();
+
+        for square in Square::ALL {
+            writeln!(
+                f,
+                "\t\tBitBoard({}),",
+                mask_pawn_front_span(color, square).0
+            )
+            .unwrap();
+        }
+
+        writeln!(f, "\t],").unwrap();
+    }
+    writeln!(f, "];").unwrap();
+
+    // pawn attack spans
+
+    writeln!(f, "/// Precomputed pawn attack spans for all squares").unwrap();
+
+    writeln!(
+        f,
+        "pub const PAWN_ATTACK_SPANS: [[BitBoard; {}]; {}] = [",
+        Square::LEN,
+        Color::LEN
+    )
+    .unwrap();
+
+    for color in Color::ALL {
+        writeln!(f, "\t[").unwrap();
+
+        for square in Square::ALL {
+            writeln!(
+                f,
+                "\t\tBitBoard({}),",
+                mask_pawn_attack_span(color, square).0
+            )
+            .unwrap();
+        }
+
+        writeln!(f, "\t],").unwrap();
+    }
+    writeln!(f, "];").unwrap();
+
+    // adjacent files
+
+    writeln!(f, "/// Precomputed adjacent files for all files").unwrap();
+
+    writeln!(f, "pub const ADJACENT_FILES: [BitBoard; {}] = [", File::LEN).unwrap();
+
+    for file in File::ALL {
+        writeln!(f, "\tBitBoard({}),", mask_adjacent_files(file).0).unwrap();
+    }
+
+    writeln!(f, "];").unwrap();
+}
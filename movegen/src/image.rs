@@ -0,0 +1,516 @@
+//! PNG rendering of boards
+//!
+//! A [`BoardImage`] draws a [`Board`] as a PNG image, for bots that post
+//! diagrams to chat platforms and for generated documentation. The pieces are
+//! drawn with small embedded sprites, or with [`PieceSprites`] loaded from
+//! user-supplied PNG files.
+//!
+//! # Errors
+//!
+//! Returns an [`ImageError`] if a sprite can't be decoded or the image can't
+//! be encoded.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chess_engine_movegen::*;
+//! let board = Board::default();
+//! let png = board.image().to_png().unwrap();
+//!
+//! assert_eq!(png[..8], [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n']);
+//! ```
+
+use chess_engine_core::{BitBoard, Color, File, Piece, PieceType, Rank, Square};
+use thiserror::Error;
+
+use crate::Board;
+
+/// Errors that can occur when rendering a board image
+#[derive(Error, Debug)]
+pub enum ImageError {
+    /// Failed to decode a sprite
+    #[error("{0}")]
+    Decode(#[from] png::DecodingError),
+
+    /// Failed to encode the image
+    #[error("{0}")]
+    Encode(#[from] png::EncodingError),
+
+    /// Sprite is not square or its size differs from the other sprites
+    #[error("invalid sprite size (expected {expected}x{expected}, got {width}x{height})")]
+    SpriteSize {
+        /// Expected width and height
+        expected: u32,
+        /// Width of the sprite
+        width: u32,
+        /// Height of the sprite
+        height: u32,
+    },
+}
+
+/// An RGBA color.
+pub type Rgba = [u8; 4];
+
+/// Side of the embedded sprites, in pixels.
+const EMBEDDED_SIZE: usize = 16;
+
+/// Embedded sprites of every [`PieceType`], `#` marks the piece.
+const EMBEDDED_SPRITES: [[&str; EMBEDDED_SIZE]; PieceType::LEN] = [
+    [
+        "................",
+        "................",
+        "................",
+        "......####......",
+        ".....######.....",
+        ".....######.....",
+        "......####......",
+        ".....######.....",
+        "......####......",
+        "......####......",
+        ".....######.....",
+        "....########....",
+        "...##########...",
+        "...##########...",
+        "................",
+        "................",
+    ],
+    [
+        "................",
+        "................",
+        "......#.##......",
+        ".....#######....",
+        "....#########...",
+        "...###.#######..",
+        "..############..",
+        "..####..######..",
+        ".......#######..",
+        "......#######...",
+        ".....########...",
+        "....#########...",
+        "...###########..",
+        "..############..",
+        "..############..",
+        "................",
+    ],
+    [
+        "................",
+        ".......##.......",
+        "......####......",
+        ".....###.##.....",
+        ".....##.###.....",
+        ".....######.....",
+        "......####......",
+        ".......##.......",
+        "......####......",
+        "......####......",
+        ".....######.....",
+        "....########....",
+        "...##########...",
+        "...##########...",
+        "................",
+        "................",
+    ],
+    [
+        "................",
+        "................",
+        "...##.####.##...",
+        "...##########...",
+        "...##########...",
+        "....########....",
+        ".....######.....",
+        ".....######.....",
+        ".....######.....",
+        ".....######.....",
+        "....########....",
+        "...##########...",
+        "..############..",
+        "..############..",
+        "................",
+        "................",
+    ],
+    [
+        "................",
+        ".#....#..#....#.",
+        ".##..##..##..##.",
+        ".##..##..##..##.",
+        ".###.##..##.###.",
+        "..############..",
+        "..############..",
+        "...##########...",
+        "....########....",
+        "....########....",
+        "...##########...",
+        "..############..",
+        "..############..",
+        "................",
+        "................",
+        "................",
+    ],
+    [
+        "................",
+        ".......##.......",
+        "......####......",
+        ".......##.......",
+        "....##.##.##....",
+        "...##########...",
+        "..############..",
+        "..############..",
+        "...##########...",
+        "....########....",
+        "....########....",
+        "...##########...",
+        "..############..",
+        "..############..",
+        "................",
+        "................",
+    ],
+];
+
+/// Fill and outline colors of the embedded sprites of every [`Color`].
+const EMBEDDED_COLORS: [(Rgba, Rgba); Color::LEN] = [
+    ([0xFF, 0xFF, 0xFF, 0xFF], [0x20, 0x20, 0x20, 0xFF]),
+    ([0x20, 0x20, 0x20, 0xFF], [0xD0, 0xD0, 0xD0, 0xFF]),
+];
+
+/// Square sprites of every [`Piece`], as RGBA pixels.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::image::*;
+/// let sprites = PieceSprites::default();
+/// assert_eq!(sprites.size(), 16);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PieceSprites {
+    size: u32,
+    sprites: [[Vec<Rgba>; PieceType::LEN]; Color::LEN],
+}
+
+impl PieceSprites {
+    /// Decodes the sprites from PNG files, indexed by [`Color`] and
+    /// [`PieceType`].
+    ///
+    /// All the sprites must be square and have the same size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ImageError`] if a sprite can't be decoded or has the wrong
+    /// size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::image::*;
+    /// let png = [0u8; 8];
+    /// let pngs = [[&png[..]; 6]; 2];
+    ///
+    /// assert!(PieceSprites::from_png(pngs).is_err());
+    /// ```
+    pub fn from_png(pngs: [[&[u8]; PieceType::LEN]; Color::LEN]) -> Result<Self, ImageError> {
+        let mut size = None;
+        let mut sprites: [[Vec<Rgba>; PieceType::LEN]; Color::LEN] = Default::default();
+
+        for color in Color::ALL {
+            for piece_type in PieceType::ALL {
+                let mut decoder = png::Decoder::new(pngs[color as usize][piece_type as usize]);
+                decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+                let mut reader = decoder.read_info()?;
+                let mut buffer = vec![0; reader.output_buffer_size()];
+                let info = reader.next_frame(&mut buffer)?;
+
+                let expected = *size.get_or_insert(info.width);
+
+                if info.width != expected || info.height != expected {
+                    return Err(ImageError::SpriteSize {
+                        expected,
+                        width: info.width,
+                        height: info.height,
+                    });
+                }
+
+                let pixels = &buffer[..info.buffer_size()];
+
+                sprites[color as usize][piece_type as usize] = match info.color_type {
+                    png::ColorType::Grayscale => pixels.iter().map(|&v| [v, v, v, 0xFF]).collect(),
+                    png::ColorType::GrayscaleAlpha => pixels
+                        .chunks_exact(2)
+                        .map(|p| [p[0], p[0], p[0], p[1]])
+                        .collect(),
+                    png::ColorType::Rgb => pixels
+                        .chunks_exact(3)
+                        .map(|p| [p[0], p[1], p[2], 0xFF])
+                        .collect(),
+                    _ => pixels
+                        .chunks_exact(4)
+                        .map(|p| [p[0], p[1], p[2], p[3]])
+                        .collect(),
+                };
+            }
+        }
+
+        Ok(Self {
+            size: size.unwrap_or_default(),
+            sprites,
+        })
+    }
+
+    /// Returns the width and height of the sprites, in pixels.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Returns the color of a pixel of the sprite of a [`Piece`].
+    fn pixel(&self, piece: Piece, x: u32, y: u32) -> Rgba {
+        self.sprites[piece.color() as usize][piece.piece_type() as usize]
+            [(y * self.size + x) as usize]
+    }
+}
+
+/// Default implementation for `PieceSprites`, the embedded sprites.
+///
+/// The pieces are filled with their color and outlined with the other one.
+impl Default for PieceSprites {
+    fn default() -> Self {
+        let mut sprites: [[Vec<Rgba>; PieceType::LEN]; Color::LEN] = Default::default();
+
+        let is_set = |piece_type: PieceType, x: isize, y: isize| {
+            (0..EMBEDDED_SIZE as isize).contains(&x)
+                && (0..EMBEDDED_SIZE as isize).contains(&y)
+                && EMBEDDED_SPRITES[piece_type as usize][y as usize].as_bytes()[x as usize] == b'#'
+        };
+
+        for color in Color::ALL {
+            let (fill, outline) = EMBEDDED_COLORS[color as usize];
+
+            for piece_type in PieceType::ALL {
+                let mut sprite = vec![[0; 4]; EMBEDDED_SIZE * EMBEDDED_SIZE];
+
+                for y in 0..EMBEDDED_SIZE as isize {
+                    for x in 0..EMBEDDED_SIZE as isize {
+                        if !is_set(piece_type, x, y) {
+                            continue;
+                        }
+
+                        let is_edge = [(-1, 0), (1, 0), (0, -1), (0, 1)]
+                            .iter()
+                            .any(|(dx, dy)| !is_set(piece_type, x + dx, y + dy));
+
+                        sprite[y as usize * EMBEDDED_SIZE + x as usize] =
+                            if is_edge { outline } else { fill };
+                    }
+                }
+
+                sprites[color as usize][piece_type as usize] = sprite;
+            }
+        }
+
+        Self {
+            size: EMBEDDED_SIZE as u32,
+            sprites,
+        }
+    }
+}
+
+/// A configurable PNG renderer for a [`Board`].
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// # use chess_engine_core::*;
+/// let board = Board::default();
+///
+/// let mut image = board.image();
+/// image
+///     .square_size(32)
+///     .perspective(Color::Black)
+///     .highlight(Square::E2.bitboard() | Square::E4.bitboard());
+///
+/// let png = image.to_png().unwrap();
+/// ```
+#[derive(Clone, Copy)]
+pub struct BoardImage<'a> {
+    board: &'a Board,
+    sprites: Option<&'a PieceSprites>,
+    square_size: u32,
+    perspective: Color,
+    light: Rgba,
+    dark: Rgba,
+    highlight: Rgba,
+    highlights: BitBoard,
+}
+
+impl<'a> BoardImage<'a> {
+    /// Creates a new `BoardImage` for a [`Board`].
+    ///
+    /// By default squares are 48 pixels wide, White is at the bottom and the
+    /// embedded sprites are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::{image::*, *};
+    /// let board = Board::default();
+    /// let image = BoardImage::new(&board);
+    /// ```
+    pub fn new(board: &'a Board) -> Self {
+        Self {
+            board,
+            sprites: None,
+            square_size: 48,
+            perspective: Color::White,
+            light: [0xF0, 0xD9, 0xB5, 0xFF],
+            dark: [0xB5, 0x88, 0x63, 0xFF],
+            highlight: [0xF6, 0xF6, 0x69, 0x80],
+            highlights: BitBoard::EMPTY,
+        }
+    }
+
+    /// Sets the [`PieceSprites`] used to draw the pieces.
+    ///
+    /// The sprites are scaled to the size of the squares.
+    pub fn sprites(&mut self, sprites: &'a PieceSprites) -> &mut BoardImage<'a> {
+        self.sprites = Some(sprites);
+        self
+    }
+
+    /// Sets the width and height of the squares, in pixels.
+    pub fn square_size(&mut self, square_size: u32) -> &mut BoardImage<'a> {
+        self.square_size = square_size;
+        self
+    }
+
+    /// Sets the [`Color`] drawn at the bottom of the board.
+    pub fn perspective(&mut self, perspective: Color) -> &mut BoardImage<'a> {
+        self.perspective = perspective;
+        self
+    }
+
+    /// Sets the colors of the light and dark squares.
+    pub fn colors(&mut self, light: Rgba, dark: Rgba) -> &mut BoardImage<'a> {
+        self.light = light;
+        self.dark = dark;
+        self
+    }
+
+    /// Sets the squares tinted with the highlight color.
+    pub fn highlight(&mut self, highlights: BitBoard) -> &mut BoardImage<'a> {
+        self.highlights = highlights;
+        self
+    }
+
+    /// Sets the color blended over the highlighted squares.
+    pub fn highlight_color(&mut self, highlight: Rgba) -> &mut BoardImage<'a> {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Renders the board as a PNG image.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ImageError`] if the image can't be encoded.
+    pub fn to_png(&self) -> Result<Vec<u8>, ImageError> {
+        let embedded;
+        let sprites = match self.sprites {
+            Some(sprites) => sprites,
+            None => {
+                embedded = PieceSprites::default();
+                &embedded
+            }
+        };
+
+        let side = self.square_size * File::LEN as u32;
+        let mut pixels = vec![0; (side * side * 4) as usize];
+
+        for square in Square::ALL {
+            let (column, row) = match self.perspective {
+                Color::White => (
+                    square.file() as u32,
+                    (Rank::LEN - 1 - square.rank() as usize) as u32,
+                ),
+                Color::Black => (
+                    (File::LEN - 1 - square.file() as usize) as u32,
+                    square.rank() as u32,
+                ),
+            };
+
+            let mut background = match square.color() {
+                Color::White => self.light,
+                Color::Black => self.dark,
+            };
+
+            if self.highlights.is_get_square(square) {
+                background = blend(background, self.highlight);
+            }
+
+            let piece = self.board.get_piece(square);
+
+            for y in 0..self.square_size {
+                for x in 0..self.square_size {
+                    let mut pixel = background;
+
+                    if let (Some(piece), true) = (piece, sprites.size > 0) {
+                        let sprite_x = x * sprites.size / self.square_size;
+                        let sprite_y = y * sprites.size / self.square_size;
+
+                        pixel = blend(pixel, sprites.pixel(piece, sprite_x, sprite_y));
+                    }
+
+                    let index = ((row * self.square_size + y) * side
+                        + column * self.square_size
+                        + x) as usize
+                        * 4;
+
+                    pixels[index..index + 4].copy_from_slice(&pixel);
+                }
+            }
+        }
+
+        let mut png = Vec::new();
+
+        let mut encoder = png::Encoder::new(&mut png, side, side);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&pixels)?;
+        writer.finish()?;
+
+        Ok(png)
+    }
+}
+
+/// PNG rendering for the `Board` struct.
+impl Board {
+    /// Returns a new [`BoardImage`] to draw the board as a PNG image.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let board = Board::default();
+    /// let png = board.image().square_size(16).to_png().unwrap();
+    /// ```
+    pub fn image(&self) -> BoardImage<'_> {
+        BoardImage::new(self)
+    }
+}
+
+/// Blends a color over an opaque background.
+fn blend(background: Rgba, color: Rgba) -> Rgba {
+    let alpha = color[3] as u32;
+
+    let mix = |background: u8, color: u8| {
+        ((color as u32 * alpha + background as u32 * (255 - alpha)) / 255) as u8
+    };
+
+    [
+        mix(background[0], color[0]),
+        mix(background[1], color[1]),
+        mix(background[2], color[2]),
+        0xFF,
+    ]
+}
@@ -0,0 +1,411 @@
+//! JSON schema for boards and moves
+//!
+//! Web frontends can read a [`Board`] as a JSON object instead of parsing a
+//! FEN string. The pieces are keyed by square name, the state fields are
+//! spelled out, and the legal moves are listed as move objects, so no UCI
+//! parsing or legality checking is needed on the client either.
+//!
+//! ```json
+//! {
+//!   "pieces": { "a1": "R", "e1": "K", "e8": "k" },
+//!   "color": "w",
+//!   "castling_rights": "Q",
+//!   "en_passant_square": null,
+//!   "halfmove_clock": 0,
+//!   "fullmove_counter": 1,
+//!   "moves": [{ "from": "a1", "to": "a2", "promotion": null }]
+//! }
+//! ```
+//!
+//! The `moves` field is only written. It is ignored when reading a board,
+//! since the legal moves are generated from the position.
+//!
+//! [`EvalParams`] are saved as a versioned JSON object too, so tuned weights
+//! can be loaded at runtime:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "pawn": 100,
+//!   "knight": 320,
+//!   "bishop": 330,
+//!   "rook": 500,
+//!   "queen": 900,
+//!   "bishop_pair": 50
+//! }
+//! ```
+//!
+//! # Errors
+//!
+//! Returns a [`JsonError`] if the JSON is invalid.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chess_engine_movegen::*;
+//! let board = Board::default();
+//! let json = board.to_json();
+//!
+//! assert_eq!(Board::from_json(&json).unwrap().to_json(), json);
+//! ```
+
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use chess_engine_core::{
+    CastleRightsTypeError, ColorError, Move, PieceTypeError, Rank, Square, SquareError,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    eval::EvalParams,
+    fen::{check_pieces, FenError},
+    Board, BoardBuilder, State,
+};
+
+/// Errors that can occur when parsing a JSON board
+#[derive(Error, Debug)]
+pub enum JsonError {
+    /// Invalid JSON
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
+
+    /// Invalid square
+    #[error("{0}")]
+    Square(#[from] SquareError),
+
+    /// Invalid piece
+    #[error("{0}")]
+    Piece(#[from] PieceTypeError),
+
+    /// Pieces that can't be on a board, like two kings of a color or a pawn
+    /// on the first rank
+    #[error("{0}")]
+    Pieces(#[from] FenError),
+
+    /// Invalid color
+    #[error("{0}")]
+    Color(#[from] ColorError),
+
+    /// Invalid castle rights
+    #[error("{0}")]
+    CastleRights(#[from] CastleRightsTypeError),
+
+    /// Invalid en passant rank
+    #[error("invalid en passant rank (expected 3 or 6, got {0})")]
+    EnPassantRank(Rank),
+
+    /// Invalid halfmove clock
+    #[error("invalid halfmove clock")]
+    HalfmoveClock,
+
+    /// Invalid fullmove counter
+    #[error("invalid fullmove counter")]
+    FullmoveCounter,
+}
+
+/// Errors that can occur when loading [`EvalParams`]
+#[derive(Error, Debug)]
+pub enum ParamsError {
+    /// Failed to read or write the file
+    #[error("{0}")]
+    Io(#[from] io::Error),
+
+    /// Invalid JSON, or a missing or unknown weight
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The file was written for another version of the weights
+    #[error("unsupported version (expected {}, got {0})", EvalParams::VERSION)]
+    Version(u32),
+
+    /// A weight is out of its range
+    #[error("invalid weight {name} (expected {min} or more, got {value})")]
+    Weight {
+        /// Name of the weight
+        name: &'static str,
+        /// Smallest valid value
+        min: i32,
+        /// Value of the weight
+        value: i32,
+    },
+}
+
+/// JSON representation of [`EvalParams`].
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EvalParamsJson {
+    version: u32,
+    pawn: i32,
+    knight: i32,
+    bishop: i32,
+    rook: i32,
+    queen: i32,
+    bishop_pair: i32,
+}
+
+/// JSON conversions for the `EvalParams` struct.
+impl EvalParams {
+    /// The version of the JSON format, increased when weights are added or
+    /// removed.
+    pub const VERSION: u32 = 1;
+
+    /// Returns the weights as a JSON object, indented to be edited by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::eval::*;
+    /// let json = EvalParams::default().to_json();
+    /// assert!(json.contains(r#""version": 1"#));
+    /// assert!(json.contains(r#""pawn": 100"#));
+    /// ```
+    pub fn to_json(&self) -> String {
+        let json = EvalParamsJson {
+            version: Self::VERSION,
+            pawn: self.pawn,
+            knight: self.knight,
+            bishop: self.bishop,
+            rook: self.rook,
+            queen: self.queen,
+            bishop_pair: self.bishop_pair,
+        };
+
+        serde_json::to_string_pretty(&json).expect("weights always serialize to JSON")
+    }
+
+    /// Parses weights from a JSON object, and checks them.
+    ///
+    /// Every weight must be present. Piece values must be positive and the
+    /// bishop pair bonus can't be negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParamsError`] if the JSON is invalid, was written for
+    /// another version, or a weight is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::{eval::*, json::*};
+    /// let params = EvalParams {
+    ///     knight: 305,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(EvalParams::from_json(&params.to_json()).unwrap(), params);
+    ///
+    /// let negative = params.to_json().replace("305", "-305");
+    /// assert!(matches!(
+    ///     EvalParams::from_json(&negative),
+    ///     Err(ParamsError::Weight { name: "knight", .. })
+    /// ));
+    ///
+    /// let future = params.to_json().replace(r#""version": 1"#, r#""version": 2"#);
+    /// assert!(matches!(EvalParams::from_json(&future), Err(ParamsError::Version(2))));
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, ParamsError> {
+        // the version is checked first, since other versions have other fields
+        #[derive(Deserialize)]
+        struct Version {
+            version: u32,
+        }
+
+        let Version { version } = serde_json::from_str(json)?;
+
+        if version != Self::VERSION {
+            return Err(ParamsError::Version(version));
+        }
+
+        let json: EvalParamsJson = serde_json::from_str(json)?;
+
+        let params = EvalParams {
+            pawn: json.pawn,
+            knight: json.knight,
+            bishop: json.bishop,
+            rook: json.rook,
+            queen: json.queen,
+            bishop_pair: json.bishop_pair,
+        };
+
+        for (name, value) in params.weights() {
+            let min = if name == "bishop_pair" { 0 } else { 1 };
+
+            if value < min {
+                return Err(ParamsError::Weight { name, min, value });
+            }
+        }
+
+        Ok(params)
+    }
+
+    /// Loads weights from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParamsError`] if the file can't be read, or its weights
+    /// are invalid, like [`EvalParams::from_json`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ParamsError> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Saves the weights to a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParamsError`] if the file can't be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ParamsError> {
+        Ok(fs::write(path, self.to_json())?)
+    }
+}
+
+/// JSON representation of a [`Board`].
+#[derive(Serialize, Deserialize)]
+struct BoardJson {
+    pieces: BTreeMap<String, String>,
+    color: String,
+    castling_rights: String,
+    en_passant_square: Option<String>,
+    halfmove_clock: u8,
+    fullmove_counter: u16,
+    #[serde(default, skip_deserializing)]
+    moves: Vec<Move>,
+}
+
+/// JSON conversions for the `Board` struct.
+impl Board {
+    /// Returns the board as a JSON object, with its legal moves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let board: Board = "4k3/8/8/8/8/8/8/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(board
+    ///     .to_json()
+    ///     .starts_with(r#"{"pieces":{"e1":"K","e8":"k"},"color":"w","castling_rights":"-""#));
+    ///
+    /// // the pinned knight has no moves
+    /// let board: Board = "4k3/4r3/8/8/8/8/4N3/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(!board.to_json().contains(r#""from":"e2""#));
+    /// assert!(board.to_json().contains(r#"{"from":"e1","to":"d1","promotion":null}"#));
+    /// ```
+    pub fn to_json(&self) -> String {
+        let pieces = Square::ALL
+            .into_iter()
+            .filter_map(|square| {
+                self.get_piece(square)
+                    .map(|piece| (square.to_string(), piece.to_string()))
+            })
+            .collect();
+
+        let json = BoardJson {
+            pieces,
+            color: self.color().to_string(),
+            castling_rights: self.castling_rights().to_string(),
+            en_passant_square: self.en_passant_square().map(|square| square.to_string()),
+            halfmove_clock: self.halfmove_clock(),
+            fullmove_counter: self.fullmove_counter(),
+            moves: self.generate_legal_moves(),
+        };
+
+        serde_json::to_string(&json).expect("a board always serializes to JSON")
+    }
+
+    /// Parses a board from a JSON object.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`JsonError`] if the JSON is invalid, or its pieces can't be
+    /// on a board. Castling rights without their king and rook are dropped,
+    /// like for a FEN string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::{json::*, *};
+    /// # use chess_engine_core::*;
+    /// let board = Board::from_json(
+    ///     r#"{
+    ///         "pieces": { "e1": "K", "e8": "k" },
+    ///         "color": "b",
+    ///         "castling_rights": "-",
+    ///         "en_passant_square": null,
+    ///         "halfmove_clock": 0,
+    ///         "fullmove_counter": 1
+    ///     }"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(board.color(), Color::Black);
+    /// assert_eq!(board.get_piece(Square::E8), Some("k".parse().unwrap()));
+    ///
+    /// // the pieces are checked like the ones of a FEN string
+    /// let two_kings = Board::from_json(
+    ///     r#"{
+    ///         "pieces": { "a1": "P", "d1": "K", "e1": "K", "e8": "k" },
+    ///         "color": "w",
+    ///         "castling_rights": "KQ",
+    ///         "en_passant_square": null,
+    ///         "halfmove_clock": 0,
+    ///         "fullmove_counter": 1
+    ///     }"#,
+    /// );
+    /// assert!(matches!(two_kings, Err(JsonError::Pieces(_))));
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, JsonError> {
+        let json: BoardJson = serde_json::from_str(json)?;
+
+        let mut board_builder = BoardBuilder::new();
+
+        let mut pieces = [None; Square::LEN];
+
+        for (square, piece) in &json.pieces {
+            pieces[square.parse::<Square>()? as usize] = Some(piece.parse()?);
+        }
+
+        check_pieces(&pieces)?;
+        board_builder.pieces(pieces);
+
+        board_builder.color(json.color.parse()?);
+
+        board_builder.castling_rights(json.castling_rights.parse()?);
+
+        let en_passant_square = match json.en_passant_square {
+            Some(square) => {
+                let square: Square = square.parse()?;
+                let rank = square.rank();
+
+                if rank != Rank::Three && rank != Rank::Six {
+                    return Err(JsonError::EnPassantRank(rank));
+                }
+
+                Some(square)
+            }
+            None => None,
+        };
+
+        board_builder.en_passant_square(en_passant_square);
+
+        if json.halfmove_clock > State::MAX_HALFMOVE_CLOCK {
+            return Err(JsonError::HalfmoveClock);
+        }
+
+        board_builder.halfmove_clock(json.halfmove_clock);
+
+        if json.fullmove_counter == 0 {
+            return Err(JsonError::FullmoveCounter);
+        }
+
+        board_builder.fullmove_counter(json.fullmove_counter);
+
+        Ok(board_builder.build())
+    }
+}
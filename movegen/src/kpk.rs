@@ -0,0 +1,297 @@
+//! King and pawn versus king bitbase
+//!
+//! Every position with a king and a pawn against a lone king is either won
+//! for the side with the pawn or drawn. The bitbase stores the outcome of all
+//! of them, so these endings can be scored perfectly without tablebase files.
+//!
+//! The bitbase is generated by retrograde analysis the first time it is
+//! probed, and takes 24 KiB.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chess_engine_movegen::{kpk::*, *};
+//! # use chess_engine_core::*;
+//! // the king in front of its pawn on the sixth rank always wins
+//! let board = "4k3/8/4K3/4P3/8/8/8/8 b - - 0 1"
+//!     .parse::<BoardBuilder>()
+//!     .unwrap()
+//!     .build();
+//!
+//! assert_eq!(probe_board(&board), Some(KpkResult::Win));
+//!
+//! // a rook pawn can't drive the king out of the corner
+//! let board = "k7/8/K7/P7/8/8/8/8 w - - 0 1"
+//!     .parse::<BoardBuilder>()
+//!     .unwrap()
+//!     .build();
+//!
+//! assert_eq!(probe_board(&board), Some(KpkResult::Draw));
+//! ```
+
+use chess_engine_core::{BitBoard, Color, File, Piece, PieceType, Rank, Square};
+use once_cell::sync::Lazy;
+
+use crate::{get_king_attacks, get_pawn_attacks, Board};
+
+/// The outcome of a king and pawn versus king position, for the side with
+/// the pawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KpkResult {
+    /// The side with the pawn wins.
+    Win,
+    /// The position is a draw.
+    Draw,
+}
+
+/// Number of pawn squares in the bitbase, files a to d and ranks 2 to 7.
+const PAWN_SQUARES: usize = 24;
+
+/// Number of positions in the bitbase.
+const LEN: usize = Square::LEN * Square::LEN * Color::LEN * PAWN_SQUARES;
+
+/// The bitbase, one bit per position, set if the side with the pawn wins.
+static KPK: Lazy<Vec<u64>> = Lazy::new(generate);
+
+/// Probes the bitbase, with the pawn side as White.
+///
+/// Returns `None` if the position is not valid: the pawn is on the first or
+/// last rank, two pieces share a square, the kings touch, or the side that
+/// is not to move is in check.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::kpk::*;
+/// # use chess_engine_core::*;
+/// // with the opposition, White wins
+/// assert_eq!(
+///     probe_kpk(Square::E4, Square::E3, Square::E6, Color::Black),
+///     Some(KpkResult::Win)
+/// );
+/// assert_eq!(
+///     probe_kpk(Square::E4, Square::E3, Square::E6, Color::White),
+///     Some(KpkResult::Draw)
+/// );
+/// ```
+pub fn probe_kpk(
+    white_king: Square,
+    white_pawn: Square,
+    black_king: Square,
+    color: Color,
+) -> Option<KpkResult> {
+    let rank = white_pawn.rank();
+
+    if rank == Rank::One || rank == Rank::Eight {
+        return None;
+    }
+
+    let (white_king, white_pawn, black_king) = if white_pawn.file() as usize > File::D as usize {
+        (mirror(white_king), mirror(white_pawn), mirror(black_king))
+    } else {
+        (white_king, white_pawn, black_king)
+    };
+
+    let index = index(white_king, black_king, color, white_pawn);
+
+    if initial(white_king, black_king, color, white_pawn) == INVALID {
+        return None;
+    }
+
+    Some(if KPK[index / 64] >> (index % 64) & 1 == 1 {
+        KpkResult::Win
+    } else {
+        KpkResult::Draw
+    })
+}
+
+/// Probes the bitbase for a board, from the point of view of the side with
+/// the pawn.
+///
+/// Returns `None` if the board is not a valid king and pawn versus king
+/// position.
+pub fn probe_board(board: &Board) -> Option<KpkResult> {
+    let pawns = board.piece_types_bitboard(PieceType::Pawn);
+
+    if board.both_bitboard().len() != 3 || pawns.len() != 1 {
+        return None;
+    }
+
+    let pawn = pawns.least_significant_square()?;
+    let strong = board.get_piece(pawn)?.color();
+
+    let king = |color| {
+        board
+            .piece_bitboard(Piece::new(PieceType::King, color))
+            .least_significant_square()
+    };
+
+    let (strong_king, weak_king) = (king(strong)?, king(!strong)?);
+
+    match strong {
+        Color::White => probe_kpk(strong_king, pawn, weak_king, board.color()),
+        Color::Black => probe_kpk(
+            flip(strong_king),
+            flip(pawn),
+            flip(weak_king),
+            !board.color(),
+        ),
+    }
+}
+
+/// Mirrors a square from the a file to the h file.
+fn mirror(square: Square) -> Square {
+    Square::new(square as usize ^ 7)
+}
+
+/// Flips a square from the first rank to the eighth rank.
+fn flip(square: Square) -> Square {
+    Square::new(square as usize ^ 56)
+}
+
+/// Returns the index of a position, with the pawn on files a to d.
+fn index(white_king: Square, black_king: Square, color: Color, pawn: Square) -> usize {
+    let pawn = pawn.file() as usize + (pawn.rank() as usize - 1) * 4;
+
+    white_king as usize | (black_king as usize) << 6 | (color as usize) << 12 | pawn << 13
+}
+
+const INVALID: u8 = 0;
+const UNKNOWN: u8 = 1;
+const DRAW: u8 = 2;
+const WIN: u8 = 4;
+
+/// Classifies a position without looking at its moves.
+fn initial(white_king: Square, black_king: Square, color: Color, pawn: Square) -> u8 {
+    let white_attacks = get_king_attacks(white_king);
+    let black_attacks = get_king_attacks(black_king);
+    let pawn_attacks = get_pawn_attacks(Color::White, pawn);
+
+    if white_king == black_king
+        || white_king == pawn
+        || black_king == pawn
+        || white_attacks.is_get_square(black_king)
+        || (color == Color::White && pawn_attacks.is_get_square(black_king))
+    {
+        return INVALID;
+    }
+
+    if color == Color::White && pawn.rank() == Rank::Seven {
+        let push = Square::new(pawn as usize + 8);
+
+        if push != white_king
+            && push != black_king
+            && (!black_attacks.is_get_square(push) || white_attacks.is_get_square(push))
+        {
+            return WIN;
+        }
+    }
+
+    if color == Color::Black {
+        let escapes = black_attacks & !(white_attacks | pawn_attacks);
+
+        if escapes == BitBoard::EMPTY
+            || (black_attacks.is_get_square(pawn) && !white_attacks.is_get_square(pawn))
+        {
+            return DRAW;
+        }
+    }
+
+    UNKNOWN
+}
+
+/// Classifies a position from the positions its moves lead to.
+fn classify(db: &[u8], white_king: Square, black_king: Square, color: Color, pawn: Square) -> u8 {
+    let (good, bad) = match color {
+        Color::White => (WIN, DRAW),
+        Color::Black => (DRAW, WIN),
+    };
+
+    let mut result = INVALID;
+
+    match color {
+        Color::White => {
+            for to in get_king_attacks(white_king) {
+                result |= db[index(to, black_king, Color::Black, pawn)];
+            }
+
+            if pawn.rank() != Rank::Seven {
+                let push = Square::new(pawn as usize + 8);
+
+                if push != white_king && push != black_king {
+                    result |= db[index(white_king, black_king, Color::Black, push)];
+
+                    let double_push = Square::new(push as usize + 8);
+
+                    if pawn.rank() == Rank::Two
+                        && double_push != white_king
+                        && double_push != black_king
+                    {
+                        result |= db[index(white_king, black_king, Color::Black, double_push)];
+                    }
+                }
+            }
+        }
+        Color::Black => {
+            for to in get_king_attacks(black_king) {
+                result |= db[index(white_king, to, Color::White, pawn)];
+            }
+        }
+    }
+
+    if result & good != 0 {
+        good
+    } else if result & UNKNOWN != 0 {
+        UNKNOWN
+    } else {
+        bad
+    }
+}
+
+/// Generates the bitbase by retrograde analysis.
+fn generate() -> Vec<u64> {
+    let mut db = vec![INVALID; LEN];
+    let mut positions = Vec::with_capacity(LEN);
+
+    for pawn_file in 0..4 {
+        for pawn_rank in 1..7 {
+            let pawn = Square::with_file_rank(File::new(pawn_file), Rank::new(pawn_rank));
+
+            for color in Color::ALL {
+                for white_king in Square::ALL {
+                    for black_king in Square::ALL {
+                        let index = index(white_king, black_king, color, pawn);
+                        db[index] = initial(white_king, black_king, color, pawn);
+
+                        if db[index] == UNKNOWN {
+                            positions.push((index, white_king, black_king, color, pawn));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+
+        for &(index, white_king, black_king, color, pawn) in &positions {
+            if db[index] == UNKNOWN {
+                db[index] = classify(&db, white_king, black_king, color, pawn);
+                changed |= db[index] != UNKNOWN;
+            }
+        }
+    }
+
+    let mut bits = vec![0; LEN / 64];
+
+    for (index, result) in db.into_iter().enumerate() {
+        if result == WIN {
+            bits[index / 64] |= 1 << (index % 64);
+        }
+    }
+
+    bits
+}
@@ -0,0 +1,539 @@
+use chess_engine_core::{
+    BitBoard, CastleRightsType, Color, File, Move, Piece, PieceMoves, PieceType, Rank, Square,
+};
+use thiserror::Error;
+
+use crate::{
+    get_attacks, get_bishop_attacks, get_king_attacks, get_knight_attacks, get_pawn_attacks,
+    get_pawn_moves, get_rook_attacks, xray_bishop_attacks, xray_rook_attacks, Board,
+};
+
+/// The reason a move is illegal, as returned by [`Board::check_move`].
+#[derive(Error, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IllegalMoveReason {
+    /// No piece on the from square
+    #[error("no piece on {0}")]
+    EmptySquare(Square),
+
+    /// The piece on the from square is not of the color to move
+    #[error("the piece on {0} is not of the color to move")]
+    WrongColor(Square),
+
+    /// The destination holds a piece of the color to move
+    #[error("{0} is occupied by a piece of the color to move")]
+    OwnPiece(Square),
+
+    /// The piece can't move to the destination, even on an empty board
+    #[error("{piece} on {from} can't move to {to}")]
+    Unreachable {
+        /// The moving piece
+        piece: Piece,
+        /// The from square
+        from: Square,
+        /// The destination
+        to: Square,
+    },
+
+    /// Another piece stands between the from square and the destination
+    #[error("the path from {0} to {1} is blocked")]
+    Blocked(Square, Square),
+
+    /// A pawn moves diagonally without capturing
+    #[error("the pawn on {0} has nothing to capture on {1}")]
+    NothingToCapture(Square, Square),
+
+    /// A pawn reaches the last rank without promoting
+    #[error("the pawn must promote on {0}")]
+    MissingPromotion(Square),
+
+    /// The move promotes, but is not a pawn reaching the last rank, or
+    /// promotes to a pawn or a king
+    #[error("invalid promotion to {0}")]
+    InvalidPromotion(PieceType),
+
+    /// The king has lost the right to castle on that side
+    #[error("no right to castle on that side")]
+    NoCastlingRights,
+
+    /// The rook the king castles with is not in its corner
+    #[error("no rook to castle with")]
+    NoCastlingRook,
+
+    /// A piece stands between the king and the rook
+    #[error("castling is blocked")]
+    CastlingBlocked,
+
+    /// The king is in check, so it can't castle
+    #[error("can't castle out of check")]
+    CastlingOutOfCheck,
+
+    /// The king passes through an attacked square while castling
+    #[error("can't castle through an attacked square")]
+    CastlingThroughCheck,
+
+    /// The king is in check after the move
+    #[error("the move leaves the king in check")]
+    LeavesKingInCheck,
+}
+
+/// Move legality checks for the `Board` struct.
+impl Board {
+    /// Checks if a move is legal, and returns why it is not.
+    ///
+    /// The accepted moves are the moves of [`Board::generate_moves`] that
+    /// don't leave the king in check.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`IllegalMoveReason`] found for the move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.check_move("e2e4".parse().unwrap()), Ok(()));
+    /// assert_eq!(
+    ///     board.check_move("e1g1".parse().unwrap()),
+    ///     Err(IllegalMoveReason::OwnPiece(Square::G1))
+    /// );
+    /// assert_eq!(
+    ///     board.check_move("c1e3".parse().unwrap()),
+    ///     Err(IllegalMoveReason::Blocked(Square::C1, Square::E3))
+    /// );
+    ///
+    /// let pinned = "4k3/8/8/8/8/8/3B4/2K1r3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     pinned.check_move("d2e3".parse().unwrap()),
+    ///     Err(IllegalMoveReason::LeavesKingInCheck)
+    /// );
+    /// ```
+    pub fn check_move(&self, mv: Move) -> Result<(), IllegalMoveReason> {
+        let (from, to) = (mv.from(), mv.to());
+        let color = self.color();
+
+        let piece = self
+            .get_piece(from)
+            .ok_or(IllegalMoveReason::EmptySquare(from))?;
+
+        if piece.color() != color {
+            return Err(IllegalMoveReason::WrongColor(from));
+        }
+
+        if self.ally_bitboard().is_get_square(to) {
+            return Err(IllegalMoveReason::OwnPiece(to));
+        }
+
+        let piece_type = piece.piece_type();
+        let last_rank = match color {
+            Color::White => Rank::Eight,
+            Color::Black => Rank::One,
+        };
+
+        match mv.promotion() {
+            Some(promotion @ (PieceType::Pawn | PieceType::King)) => {
+                return Err(IllegalMoveReason::InvalidPromotion(promotion))
+            }
+            Some(promotion) if piece_type != PieceType::Pawn || to.rank() != last_rank => {
+                return Err(IllegalMoveReason::InvalidPromotion(promotion))
+            }
+            None if piece_type == PieceType::Pawn && to.rank() == last_rank => {
+                return Err(IllegalMoveReason::MissingPromotion(to))
+            }
+            _ => {}
+        }
+
+        let home_rank = match color {
+            Color::White => Rank::One,
+            Color::Black => Rank::Eight,
+        };
+
+        let is_castling = piece_type == PieceType::King
+            && from == Square::with_file_rank(File::E, home_rank)
+            && to.rank() == home_rank
+            && (to.file() == File::G || to.file() == File::C);
+
+        if is_castling {
+            return self.check_castling(to);
+        }
+
+        let reachable = match piece_type {
+            PieceType::Pawn => get_pawn_moves(color, from) | get_pawn_attacks(color, from),
+            _ => get_attacks(piece, from, BitBoard::EMPTY),
+        };
+
+        if !reachable.is_get_square(to) {
+            return Err(IllegalMoveReason::Unreachable { piece, from, to });
+        }
+
+        let generated = self
+            .generate_piece_moves(piece_type, from)
+            .is_some_and(|piece_moves| piece_moves.to().is_get_square(to));
+
+        if !generated {
+            return Err(match piece_type {
+                PieceType::Pawn if get_pawn_attacks(color, from).is_get_square(to) => {
+                    IllegalMoveReason::NothingToCapture(from, to)
+                }
+                PieceType::King => IllegalMoveReason::LeavesKingInCheck,
+                _ => IllegalMoveReason::Blocked(from, to),
+            });
+        }
+
+        let mut board = self.clone();
+
+        if self.is_en_passant(mv) {
+            board.remove_piece(Square::with_file_rank(to.file(), from.rank()));
+        }
+
+        board.remove_piece(from);
+        board.remove_piece(to);
+        board.put_piece(piece, to);
+
+        let king = board.piece_bitboard(Piece::new(PieceType::King, color));
+
+        if king
+            .into_iter()
+            .any(|king| board.is_square_attcked(king, !color))
+        {
+            return Err(IllegalMoveReason::LeavesKingInCheck);
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the king of the color to move can castle to `to`.
+    fn check_castling(&self, to: Square) -> Result<(), IllegalMoveReason> {
+        let color = self.color();
+        let rank = to.rank();
+
+        let (side, rook, between, path) = match to.file() {
+            File::G => (
+                CastleRightsType::KingSide,
+                File::H,
+                [File::F, File::G].as_slice(),
+                [File::F, File::G],
+            ),
+            _ => (
+                CastleRightsType::QueenSide,
+                File::A,
+                [File::D, File::C, File::B].as_slice(),
+                [File::D, File::C],
+            ),
+        };
+
+        let rights = self.castling_rights().0[color as usize];
+
+        if rights as usize & side as usize == 0 {
+            return Err(IllegalMoveReason::NoCastlingRights);
+        }
+
+        if self.get_piece(Square::with_file_rank(rook, rank))
+            != Some(Piece::new(PieceType::Rook, color))
+        {
+            return Err(IllegalMoveReason::NoCastlingRook);
+        }
+
+        let both = self.both_bitboard();
+
+        if between
+            .iter()
+            .any(|file| both.is_get_square(Square::with_file_rank(*file, rank)))
+        {
+            return Err(IllegalMoveReason::CastlingBlocked);
+        }
+
+        if self.is_square_attcked(Square::with_file_rank(File::E, rank), !color) {
+            return Err(IllegalMoveReason::CastlingOutOfCheck);
+        }
+
+        if path
+            .iter()
+            .any(|file| self.is_square_attcked(Square::with_file_rank(*file, rank), !color))
+        {
+            return Err(IllegalMoveReason::CastlingThroughCheck);
+        }
+
+        Ok(())
+    }
+}
+
+/// Legal move generation for the `Board` struct.
+impl Board {
+    /// Generates the legal moves of the position: the moves of
+    /// [`Board::generate_moves`] that don't leave the king in check.
+    ///
+    /// Instead of playing every move, the pieces giving check restrict the
+    /// destinations to the squares that capture or block the checker (the
+    /// check mask), pinned pieces only move along their pin, and the king
+    /// only steps to squares that are not attacked once it has left its
+    /// square. En passant captures, which can uncover a check along the
+    /// rank of both pawns, are played on a copy of the board.
+    ///
+    /// Boards without a king of the color to move get every generated move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// assert_eq!(Board::default().generate_legal_moves().len(), 20);
+    ///
+    /// // the knight on d2 is pinned, and the rook on e8 gives check
+    /// let board = "k3r3/8/8/8/1b6/8/3N4/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// let moves = board.generate_legal_moves();
+    /// assert!(moves.iter().all(|mv| mv.from() == Square::E1));
+    /// assert_eq!(moves.len(), 3);
+    /// ```
+    ///
+    /// Checks, pins and en passant:
+    ///
+    /// ```
+    /// # use chess_engine_movegen::{perft::perft, *};
+    /// # use chess_engine_core::*;
+    /// let board = |fen: &str| fen.parse::<BoardBuilder>().unwrap().build();
+    /// let legal = |fen: &str| {
+    ///     let mut moves: Vec<String> = board(fen)
+    ///         .generate_legal_moves()
+    ///         .iter()
+    ///         .map(Move::to_string)
+    ///         .collect();
+    ///     moves.sort();
+    ///     moves
+    /// };
+    ///
+    /// // in double check only the king moves, and not along the rook's ray
+    /// assert_eq!(legal("4k3/8/8/8/8/R4n2/8/4K2r w - - 0 1"), ["e1e2", "e1f2"]);
+    ///
+    /// // a check is evaded by capturing the checker, blocking it or moving
+    /// // the king
+    /// assert_eq!(
+    ///     legal("4k3/8/8/8/8/8/1B6/r3K3 w - - 0 1"),
+    ///     ["b2a1", "b2c1", "e1d2", "e1e2", "e1f2"]
+    /// );
+    ///
+    /// // the pinned rook only moves along its pin, up to the pinner
+    /// let rook: Vec<String> = legal("4k3/4r3/8/8/8/8/4R3/4K3 w - - 0 1")
+    ///     .into_iter()
+    ///     .filter(|mv| mv.starts_with("e2"))
+    ///     .collect();
+    /// assert_eq!(rook, ["e2e3", "e2e4", "e2e5", "e2e6", "e2e7"]);
+    ///
+    /// // taking en passant would uncover a check along the rank
+    /// let fen = "8/8/8/K2pP2r/8/8/8/7k w - d6 0 1";
+    /// let en_passant = Move::new(Square::E5, Square::D6, None);
+    /// assert!(board(fen).generate_moves().contains(&en_passant));
+    /// assert!(!board(fen).generate_legal_moves().contains(&en_passant));
+    ///
+    /// // castling needs the rook in its corner, even with the right to castle
+    /// let mut corner = board("4k3/8/8/8/8/8/8/4K2R w K - 0 1");
+    /// let castling = Move::new(Square::E1, Square::G1, None);
+    /// assert!(corner.generate_legal_moves().contains(&castling));
+    ///
+    /// corner.remove_piece(Square::H1);
+    /// assert!(!corner.generate_legal_moves().contains(&castling));
+    /// assert!(corner.check_move(castling).is_err());
+    ///
+    /// corner.put_piece("N".parse().unwrap(), Square::H1);
+    /// assert!(!corner.generate_legal_moves().contains(&castling));
+    /// assert!(corner.check_move(castling).is_err());
+    ///
+    /// // perft counts with the legal moves
+    /// let mut position = board("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1");
+    /// assert_eq!(perft(&mut position, 3), 2812);
+    /// ```
+    pub fn generate_legal_moves(&self) -> Vec<Move> {
+        let color = self.color();
+        let Some(king) = self.king_square(color) else {
+            return self.generate_moves();
+        };
+
+        let checkers = self.checkers();
+        let check_mask = match (checkers.len(), checkers.into_iter().next()) {
+            (0, _) => !BitBoard::EMPTY,
+            (1, Some(checker)) => checkers | between(king, checker),
+            _ => BitBoard::EMPTY,
+        };
+
+        let pins = self.pins(king);
+        let without_king = self.both_bitboard().unset_square(king);
+
+        let mut moves = Vec::new();
+
+        for piece_type in PieceType::ALL {
+            let piece = Piece::new(piece_type, color);
+
+            for from in self.piece_bitboard(piece) {
+                let Some(piece_moves) = self.generate_piece_moves(piece_type, from) else {
+                    continue;
+                };
+
+                let mut to = piece_moves.to();
+
+                if piece_type == PieceType::King {
+                    // castling is only generated out of check and through
+                    // squares that are not attacked
+                    for target in to {
+                        let is_castling =
+                            (from.file() as usize).abs_diff(target.file() as usize) == 2;
+
+                        if !is_castling
+                            && !self.attackers_of(target, !color, without_king).is_empty()
+                        {
+                            to = to.unset_square(target);
+                        }
+                    }
+                } else {
+                    let en_passant = self.en_passant_square().filter(|&square| {
+                        to.is_get_square(square)
+                            && self.is_en_passant(Move::new(from, square, None))
+                    });
+
+                    if let Some(square) = en_passant {
+                        to = to.unset_square(square);
+                    }
+
+                    to &= check_mask;
+
+                    if let Some((_, pin)) = pins.iter().find(|(pinned, _)| *pinned == from) {
+                        to &= *pin;
+                    }
+
+                    if let Some(square) = en_passant.filter(|&square| {
+                        let mut board = self.clone();
+                        board.make_move(Move::new(from, square, None));
+                        board
+                            .attackers_of(king, !color, board.both_bitboard())
+                            .is_empty()
+                    }) {
+                        to = to.set_square(square);
+                    }
+                }
+
+                moves.extend(PieceMoves::new(piece, from, to));
+            }
+        }
+
+        moves
+    }
+
+    /// Returns the pieces of the opponent giving check to the king of the
+    /// color to move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/8/8/8/5n2/8/4K2r w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(board.checkers(), Square::F3.bitboard() | Square::H1.bitboard());
+    /// assert_eq!(Board::default().checkers(), BitBoard::EMPTY);
+    /// ```
+    pub fn checkers(&self) -> BitBoard {
+        self.king_square(self.color())
+            .map_or(BitBoard::EMPTY, |king| {
+                self.attackers_of(king, !self.color(), self.both_bitboard())
+            })
+    }
+
+    /// Returns the pieces of the color to move pinned to their king by a
+    /// rook, bishop or queen of the opponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/8/8/1b6/8/3N4/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(board.pinned(), Square::D2.bitboard());
+    /// ```
+    pub fn pinned(&self) -> BitBoard {
+        self.king_square(self.color())
+            .map_or(Vec::new(), |king| self.pins(king))
+            .into_iter()
+            .fold(BitBoard::EMPTY, |pinned, (square, _)| {
+                pinned.set_square(square)
+            })
+    }
+
+    /// Returns the pinned pieces of the color to move, each with the squares
+    /// it can move to along its pin, the pinner included.
+    fn pins(&self, king: Square) -> Vec<(Square, BitBoard)> {
+        let (ally, opponent) = (self.ally_bitboard(), self.opponent_bitboard());
+        let queens = self.piece_types_bitboard(PieceType::Queen);
+
+        let pinners = (xray_rook_attacks(king, self.both_bitboard(), ally)
+            & (self.piece_types_bitboard(PieceType::Rook) | queens)
+            | xray_bishop_attacks(king, self.both_bitboard(), ally)
+                & (self.piece_types_bitboard(PieceType::Bishop) | queens))
+            & opponent;
+
+        pinners
+            .into_iter()
+            .filter_map(|pinner| {
+                let ray = between(king, pinner);
+                let pinned = (ray & ally).into_iter().next()?;
+
+                Some((pinned, ray.set_square(pinner)))
+            })
+            .collect()
+    }
+
+    /// Returns the square of the king of a color, if any.
+    fn king_square(&self, color: Color) -> Option<Square> {
+        self.piece_bitboard(Piece::new(PieceType::King, color))
+            .into_iter()
+            .next()
+    }
+
+    /// Returns the pieces of a color attacking a square, with only the
+    /// pieces of the occupancy blocking the sliding pieces.
+    fn attackers_of(&self, square: Square, color: Color, occupancy: BitBoard) -> BitBoard {
+        let pieces = |piece_type| self.piece_types_bitboard(piece_type);
+        let queens = pieces(PieceType::Queen);
+
+        (get_pawn_attacks(!color, square) & pieces(PieceType::Pawn)
+            | get_knight_attacks(square) & pieces(PieceType::Knight)
+            | get_bishop_attacks(square, occupancy) & (pieces(PieceType::Bishop) | queens)
+            | get_rook_attacks(square, occupancy) & (pieces(PieceType::Rook) | queens)
+            | get_king_attacks(square) & pieces(PieceType::King))
+            & self.color_bitboard(color)
+            & occupancy
+    }
+}
+
+/// Returns the squares strictly between two squares on a line, or an empty
+/// bitboard if they are not on the same rank, file or diagonal.
+fn between(a: Square, b: Square) -> BitBoard {
+    let occupancy = a.bitboard() | b.bitboard();
+    let files = (a.file() as usize).abs_diff(b.file() as usize);
+    let ranks = (a.rank() as usize).abs_diff(b.rank() as usize);
+
+    if a == b {
+        BitBoard::EMPTY
+    } else if files == 0 || ranks == 0 {
+        get_rook_attacks(a, occupancy) & get_rook_attacks(b, occupancy)
+    } else if files == ranks {
+        get_bishop_attacks(a, occupancy) & get_bishop_attacks(b, occupancy)
+    } else {
+        BitBoard::EMPTY
+    }
+}
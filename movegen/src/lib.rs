@@ -3,19 +3,62 @@
 
 //! Move generation library for the chess engine.
 
+mod accumulator;
+mod attack_map;
 mod board;
 mod board_builder;
+mod board_formatter;
+pub mod build_info;
 mod castle_rights;
+mod cuckoo;
+mod diff;
 mod draw;
+pub mod eval;
+pub mod explorer;
 pub mod fen;
+// the table writers only run in the build script
+#[allow(dead_code)]
+mod gen_consts;
+#[cfg(feature = "image")]
+pub mod image;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod kpk;
+mod legality;
 mod magic;
+mod make_move;
+pub mod memory;
 mod movegen;
+pub mod notation;
+pub mod ordering;
+pub mod packed;
+mod pawns;
+pub mod perft;
+pub mod puzzle;
+mod random;
+mod reachability;
 mod state;
+pub mod tablebase;
+mod tactics;
 mod zobrist;
 
+pub use accumulator::*;
+pub use attack_map::*;
 pub use board::*;
 pub use board_builder::*;
+pub use board_formatter::*;
 pub use castle_rights::*;
+pub use diff::*;
+pub use gen_consts::{
+    bishops::{mask_bishop_attacks, mask_relevant_bishop_blockers},
+    king::{mask_king_attacks, mask_king_zone},
+    knights::mask_knight_attacks,
+    magic::mask_blockers,
+    pawns::{mask_pawn_attacks, mask_pawn_moves},
+    rooks::{mask_relevant_rook_blockers, mask_rook_attacks},
+    spans::{mask_adjacent_files, mask_pawn_attack_span, mask_pawn_front_span},
+};
+pub use legality::*;
 pub use magic::*;
 pub use state::*;
 pub use zobrist::*;
@@ -1,7 +1,18 @@
-use chess_engine_core::{BitBoard, Color, SlidingPiece, Square};
+use chess_engine_core::{BitBoard, Color, File, Piece, PieceType, SlidingPiece, Square};
 
 include!(concat!(env!("OUT_DIR"), "/magic_gen.rs"));
 
+/// Returns the index of the blockers in the attacks table of a square.
+#[inline(always)]
+fn magic_index(
+    blockers: BitBoard,
+    relevant_blockers: BitBoard,
+    magic_number: u64,
+    relevant_bits: u8,
+) -> usize {
+    ((blockers.0 & relevant_blockers.0).wrapping_mul(magic_number) >> (64 - relevant_bits)) as usize
+}
+
 /// Returns the pawn attacks for a square and color.
 ///
 /// # Example
@@ -96,6 +107,35 @@ pub fn get_king_attacks(square: Square) -> BitBoard {
     KING_ATTACKS[square as usize]
 }
 
+/// Returns the king zone of a king of a color on a square: the king, its
+/// neighbors and the three squares two ranks in front of it.
+///
+/// The zone of a king on an edge, or too far forward, is moved inward so
+/// every zone has 12 squares.
+///
+/// # Example
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// # use chess_engine_core::*;
+/// let zone = king_zone(Color::Black, Square::E5);
+///
+/// assert_eq!(zone, bitboard!{
+///     . . . . . . . .
+///     . . . . . . . .
+///     . . . X X X . .
+///     . . . X X X . .
+///     . . . X X X . .
+///     . . . X X X . .
+///     . . . . . . . .
+///     . . . . . . . .
+/// });
+/// assert_eq!(king_zone(Color::White, Square::A8).len(), 12);
+/// ```
+pub fn king_zone(color: Color, square: Square) -> BitBoard {
+    KING_ZONES[color as usize][square as usize]
+}
+
 /// Returns the knight attacks for a square.
 ///
 /// # Example
@@ -150,12 +190,14 @@ pub fn get_knight_attacks(square: Square) -> BitBoard {
 /// });
 /// ```
 pub fn get_bishop_attacks(square: Square, blockers: BitBoard) -> BitBoard {
-    let magic_index = blockers.0 & RELEVANT_BISHOP_BLOCKERS[square as usize].0;
-    let magic_index = magic_index
-        .wrapping_mul(MAGIC_NUMBERS[SlidingPiece::Bishop as usize][square as usize])
-        >> (64 - RELEVANT_BISHOP_BLOCKERS_COUNT[square as usize]);
+    let magic_index = magic_index(
+        blockers,
+        RELEVANT_BISHOP_BLOCKERS[square as usize],
+        MAGIC_NUMBERS[SlidingPiece::Bishop as usize][square as usize],
+        RELEVANT_BISHOP_BLOCKERS_COUNT[square as usize],
+    );
 
-    BISHOP_ATTACKS[square as usize][magic_index as usize]
+    BISHOP_ATTACKS[BISHOP_ATTACKS_OFFSETS[square as usize] + magic_index]
 }
 
 /// Returns the rook attacks for a square with blockers.
@@ -188,12 +230,14 @@ pub fn get_bishop_attacks(square: Square, blockers: BitBoard) -> BitBoard {
 /// });
 /// ```
 pub fn get_rook_attacks(square: Square, blockers: BitBoard) -> BitBoard {
-    let magic_index = blockers.0 & RELEVANT_ROOK_BLOCKERS[square as usize].0;
-    let magic_index = magic_index
-        .wrapping_mul(MAGIC_NUMBERS[SlidingPiece::Rook as usize][square as usize])
-        >> (64 - RELEVANT_ROOK_BLOCKERS_COUNT[square as usize]);
+    let magic_index = magic_index(
+        blockers,
+        RELEVANT_ROOK_BLOCKERS[square as usize],
+        MAGIC_NUMBERS[SlidingPiece::Rook as usize][square as usize],
+        RELEVANT_ROOK_BLOCKERS_COUNT[square as usize],
+    );
 
-    ROOK_ATTACKS[square as usize][magic_index as usize]
+    ROOK_ATTACKS[ROOK_ATTACKS_OFFSETS[square as usize] + magic_index]
 }
 
 /// Returns the queen attacks for a square with blockers.
@@ -228,3 +272,138 @@ pub fn get_rook_attacks(square: Square, blockers: BitBoard) -> BitBoard {
 pub fn get_queen_attacks(square: Square, blockers: BitBoard) -> BitBoard {
     get_bishop_attacks(square, blockers) | get_rook_attacks(square, blockers)
 }
+
+/// Returns the squares in front of a pawn on its file, up to the last rank.
+///
+/// # Example
+///
+/// ```
+/// # use chess_engine_core::*;
+/// # use chess_engine_movegen::*;
+/// assert_eq!(pawn_front_span(Color::Black, Square::C3), Square::C2.bitboard() | Square::C1.bitboard());
+/// ```
+pub fn pawn_front_span(color: Color, square: Square) -> BitBoard {
+    PAWN_FRONT_SPANS[color as usize][square as usize]
+}
+
+/// Returns the squares a pawn can attack while it advances, on the files
+/// next to it.
+///
+/// # Example
+///
+/// ```
+/// # use chess_engine_core::*;
+/// # use chess_engine_movegen::*;
+/// let attack_span = pawn_attack_span(Color::White, Square::E4);
+///
+/// assert_eq!(attack_span, bitboard!{
+///     . . . X . X . .
+///     . . . X . X . .
+///     . . . X . X . .
+///     . . . X . X . .
+///     . . . . . . . .
+///     . . . . . . . .
+///     . . . . . . . .
+///     . . . . . . . .
+/// });
+/// ```
+pub fn pawn_attack_span(color: Color, square: Square) -> BitBoard {
+    PAWN_ATTACK_SPANS[color as usize][square as usize]
+}
+
+/// Returns the files next to a file.
+///
+/// # Example
+///
+/// ```
+/// # use chess_engine_core::*;
+/// # use chess_engine_movegen::*;
+/// assert_eq!(adjacent_files(File::E), File::D.bitboard() | File::F.bitboard());
+/// assert_eq!(adjacent_files(File::H), File::G.bitboard());
+/// ```
+pub fn adjacent_files(file: File) -> BitBoard {
+    ADJACENT_FILES[file as usize]
+}
+
+/// Returns the attacks of a [`Piece`] on a square with blockers.
+///
+/// Pawns attack in the direction of their [`Color`], and only diagonally.
+///
+/// # Example
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// # use chess_engine_core::*;
+/// let knight = Piece::new(PieceType::Knight, Color::White);
+/// let pawn = Piece::new(PieceType::Pawn, Color::Black);
+///
+/// assert_eq!(get_attacks(knight, Square::A1, BitBoard::EMPTY), get_knight_attacks(Square::A1));
+/// assert_eq!(
+///     get_attacks(pawn, Square::E4, BitBoard::EMPTY),
+///     Square::D3.bitboard() | Square::F3.bitboard()
+/// );
+/// ```
+pub fn get_attacks(piece: Piece, square: Square, blockers: BitBoard) -> BitBoard {
+    match piece.piece_type() {
+        PieceType::Pawn => get_pawn_attacks(piece.color(), square),
+        PieceType::Knight => get_knight_attacks(square),
+        PieceType::Bishop => get_bishop_attacks(square, blockers),
+        PieceType::Rook => get_rook_attacks(square, blockers),
+        PieceType::Queen => get_queen_attacks(square, blockers),
+        PieceType::King => get_king_attacks(square),
+    }
+}
+
+/// Returns the squares a rook attacks through the blockers it attacks
+/// directly, with the pieces of occupied on the board.
+///
+/// Only the squares behind the blockers are returned. The blockers are
+/// usually the pieces of one color, to find pins, skewers and batteries.
+///
+/// # Example
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// # use chess_engine_core::*;
+/// // a rook on e1 behind its own pawn on e2, with an enemy king on e6
+/// let occupied = Square::E2.bitboard() | Square::E6.bitboard();
+/// let blockers = Square::E2.bitboard();
+///
+/// assert_eq!(
+///     xray_rook_attacks(Square::E1, occupied, blockers),
+///     Square::E3.bitboard() | Square::E4.bitboard() | Square::E5.bitboard() | Square::E6.bitboard()
+/// );
+/// ```
+pub fn xray_rook_attacks(square: Square, occupied: BitBoard, blockers: BitBoard) -> BitBoard {
+    let attacks = get_rook_attacks(square, occupied);
+    let blockers = blockers & attacks;
+
+    attacks ^ get_rook_attacks(square, occupied ^ blockers)
+}
+
+/// Returns the squares a bishop attacks through the blockers it attacks
+/// directly, with the pieces of occupied on the board.
+///
+/// Only the squares behind the blockers are returned. The blockers are
+/// usually the pieces of one color, to find pins, skewers and batteries.
+///
+/// # Example
+///
+/// ```
+/// # use chess_engine_movegen::*;
+/// # use chess_engine_core::*;
+/// // a bishop on b2 pinning a knight on d4 to a king on f6
+/// let occupied = Square::D4.bitboard() | Square::F6.bitboard();
+/// let blockers = Square::D4.bitboard();
+///
+/// assert_eq!(
+///     xray_bishop_attacks(Square::B2, occupied, blockers),
+///     Square::E5.bitboard() | Square::F6.bitboard()
+/// );
+/// ```
+pub fn xray_bishop_attacks(square: Square, occupied: BitBoard, blockers: BitBoard) -> BitBoard {
+    let attacks = get_bishop_attacks(square, occupied);
+    let blockers = blockers & attacks;
+
+    attacks ^ get_bishop_attacks(square, occupied ^ blockers)
+}
@@ -0,0 +1,294 @@
+use chess_engine_core::{Color, File, Move, Piece, PieceType, Rank, Square};
+
+use crate::{Accumulator, Board, State, ZOBRIST};
+
+/// Playing and taking back moves on the `Board` struct.
+impl Board {
+    /// Plays a move: moves the piece, and the rook when castling, removes the
+    /// captured piece, en passant included, promotes pawns, and updates the
+    /// castling rights, the en passant square and the clocks.
+    ///
+    /// The previous [`State`] is pushed to the [history](Board::history),
+    /// with its hash, so repetitions can be detected and the move can be
+    /// taken back with [`Board::unmake_move`].
+    ///
+    /// The move is not checked, use [`Board::check_move`] first for moves
+    /// that may be illegal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no piece on the origin square of the move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let mut board = Board::default();
+    /// board.make_move(Move::new(Square::E2, Square::E4, None));
+    ///
+    /// assert_eq!(
+    ///     board.to_string(),
+    ///     "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+    /// );
+    /// assert_eq!(board.history().len(), 1);
+    /// ```
+    pub fn make_move(&mut self, mv: Move) {
+        self.make_move_with(mv, &mut ());
+    }
+
+    /// Plays a move like [`Board::make_move`], and notifies the accumulator
+    /// of every piece put on or taken off the board.
+    ///
+    /// The hash and the verification key kept in the [`State`] are updated
+    /// with the keys of the pieces, castling rights, en passant square and
+    /// color that changed, instead of being computed again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no piece on the origin square of the move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// /// Counts the pieces on the board.
+    /// #[derive(Default)]
+    /// struct Count(usize);
+    ///
+    /// impl Accumulator for Count {
+    ///     fn add_piece(&mut self, _piece: Piece, _square: Square) {
+    ///         self.0 += 1;
+    ///     }
+    ///
+    ///     fn remove_piece(&mut self, _piece: Piece, _square: Square) {
+    ///         self.0 -= 1;
+    ///     }
+    /// }
+    ///
+    /// let mut board = "4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    /// let mut count = Count::default();
+    /// board.accumulate(&mut count);
+    ///
+    /// let capture = Move::new(Square::E4, Square::D5, None);
+    /// board.make_move_with(capture, &mut count);
+    /// assert_eq!(count.0, 3);
+    ///
+    /// board.unmake_move_with(capture, &mut count);
+    /// assert_eq!(count.0, 4);
+    /// ```
+    pub fn make_move_with(&mut self, mv: Move, accumulator: &mut impl Accumulator) {
+        let (from, to) = (mv.from(), mv.to());
+        let color = self.color();
+        let piece = self
+            .get_piece(from)
+            .expect("a piece on the origin square of the move");
+
+        let previous = self.state;
+
+        let captured_piece = self.captured_piece(mv);
+        let castling_rights = self.castling_rights_after(mv);
+        let irreversible = castling_rights != self.castling_rights();
+
+        if self.is_en_passant(mv) {
+            self.remove_piece_with(Square::with_file_rank(to.file(), from.rank()), accumulator);
+        }
+
+        if self.is_castling(mv) {
+            let (rook_from, rook_to) = castling_rook_squares(to);
+            self.remove_piece_with(rook_from, accumulator);
+            self.put_piece_with(Piece::new(PieceType::Rook, color), rook_to, accumulator);
+        }
+
+        self.remove_piece_with(from, accumulator);
+        self.put_piece_with(
+            mv.promotion()
+                .map_or(piece, |promotion| Piece::new(promotion, color)),
+            to,
+            accumulator,
+        );
+
+        let is_pawn = piece.piece_type() == PieceType::Pawn;
+        let en_passant_square =
+            (is_pawn && (from.rank() as usize).abs_diff(to.rank() as usize) == 2).then(|| {
+                Square::with_file_rank(
+                    from.file(),
+                    Rank::new((from.rank() as usize + to.rank() as usize) / 2),
+                )
+            });
+
+        let halfmove_clock = if is_pawn || captured_piece.is_some() {
+            0
+        } else {
+            self.halfmove_clock().saturating_add(1)
+        };
+
+        let fullmove_counter = match color {
+            Color::White => self.fullmove_counter(),
+            Color::Black => self.fullmove_counter().saturating_add(1),
+        };
+
+        self.history.push(previous);
+
+        let mut state = State::new(
+            !color,
+            castling_rights,
+            en_passant_square,
+            halfmove_clock,
+            fullmove_counter,
+        );
+        state.set_irreversible(irreversible);
+        state.set_captured_piece(captured_piece);
+        // the keys of the pieces are already toggled by the moves above
+        state.set_hash(self.state.hash());
+        state.set_verification_key(self.state.verification_key());
+        self.state = state;
+
+        self.toggle_key(|zobrist| zobrist.color());
+
+        for color in Color::ALL {
+            let (before, after) = (
+                previous.castling_rights().0[color as usize],
+                castling_rights.0[color as usize],
+            );
+
+            if before != after {
+                self.toggle_key(|zobrist| {
+                    zobrist.castling_rights(color, before) ^ zobrist.castling_rights(color, after)
+                });
+            }
+        }
+
+        for square in [previous.en_passant_square(), en_passant_square]
+            .into_iter()
+            .flatten()
+        {
+            self.toggle_key(|zobrist| zobrist.en_passant(square));
+        }
+
+        debug_assert_eq!(self.state.hash(), self.hash_with(&ZOBRIST));
+        debug_assert_eq!(self.state.verification_key(), self.verification_key());
+    }
+
+    /// Takes back the last move played with [`Board::make_move`], and pops
+    /// its previous [`State`] from the [history](Board::history).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the history is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let mut board = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// let castling = Move::new(Square::E1, Square::G1, None);
+    /// board.make_move(castling);
+    /// assert_eq!(board.to_string(), "r3k2r/8/8/8/8/8/8/R4RK1 b kq - 1 1");
+    ///
+    /// board.unmake_move(castling);
+    /// assert_eq!(board.to_string(), "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+    /// assert!(board.history().is_empty());
+    /// ```
+    ///
+    /// Every kind of move is taken back, and the hash is restored:
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let moves = [
+    ///     // queen side castling
+    ///     ("r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 3 9", "e8c8", "2kr3r/8/8/8/8/8/8/R3K2R w KQ - 4 10"),
+    ///     // en passant
+    ///     ("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2", "e5d6", "4k3/8/3P4/8/8/8/8/4K3 b - - 0 2"),
+    ///     // promotion with a capture, losing a castling right
+    ///     ("r3k3/1P6/8/8/8/8/8/4K3 w q - 0 1", "b7a8q", "Q3k3/8/8/8/8/8/8/4K3 b - - 0 1"),
+    ///     // double push
+    ///     ("4k3/3p4/8/8/8/8/8/4K3 b - - 5 7", "d7d5", "4k3/8/8/3p4/8/8/8/4K3 w - d6 0 8"),
+    /// ];
+    ///
+    /// for (fen, mv, after) in moves {
+    ///     let mut board = fen.parse::<BoardBuilder>().unwrap().build();
+    ///     let (hash, key) = (board.hash(), board.verification_key());
+    ///     let mv: Move = mv.parse().unwrap();
+    ///
+    ///     board.make_move(mv);
+    ///     assert_eq!(board.to_string(), after);
+    ///     assert_eq!(board.hash(), after.parse::<BoardBuilder>().unwrap().build().hash());
+    ///
+    ///     board.unmake_move(mv);
+    ///     assert_eq!(board.to_string(), fen);
+    ///     assert_eq!((board.hash(), board.verification_key()), (hash, key));
+    /// }
+    /// ```
+    pub fn unmake_move(&mut self, mv: Move) {
+        self.unmake_move_with(mv, &mut ());
+    }
+
+    /// Takes back the last move like [`Board::unmake_move`], and notifies the
+    /// accumulator of every piece put on or taken off the board.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the history is empty.
+    pub fn unmake_move_with(&mut self, mv: Move, accumulator: &mut impl Accumulator) {
+        let (from, to) = (mv.from(), mv.to());
+        let captured_piece = self.state.captured_piece();
+        let previous = self.history.pop().expect("a move to unmake");
+        let color = previous.color();
+
+        let piece = match mv.promotion() {
+            Some(_) => Piece::new(PieceType::Pawn, color),
+            None => self
+                .get_piece(to)
+                .expect("a piece on the destination square of the move"),
+        };
+
+        self.remove_piece_with(to, accumulator);
+        self.put_piece_with(piece, from, accumulator);
+
+        let is_king = piece.piece_type() == PieceType::King;
+        if is_king && (from.file() as usize).abs_diff(to.file() as usize) == 2 {
+            let (rook_from, rook_to) = castling_rook_squares(to);
+            self.remove_piece_with(rook_to, accumulator);
+            self.put_piece_with(Piece::new(PieceType::Rook, color), rook_from, accumulator);
+        }
+
+        if let Some(captured_piece) = captured_piece {
+            let is_en_passant =
+                piece.piece_type() == PieceType::Pawn && Some(to) == previous.en_passant_square();
+            let square = if is_en_passant {
+                Square::with_file_rank(to.file(), from.rank())
+            } else {
+                to
+            };
+
+            self.put_piece_with(captured_piece, square, accumulator);
+        }
+
+        self.state = previous;
+    }
+}
+
+/// Returns the origin and destination squares of the rook when the king
+/// castles to `to`.
+fn castling_rook_squares(to: Square) -> (Square, Square) {
+    let (from, to_file) = match to.file() {
+        File::G => (File::H, File::F),
+        _ => (File::A, File::D),
+    };
+
+    (
+        Square::with_file_rank(from, to.rank()),
+        Square::with_file_rank(to_file, to.rank()),
+    )
+}
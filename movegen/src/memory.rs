@@ -0,0 +1,98 @@
+//! Memory budgets
+//!
+//! Embedders with strict memory limits, like WASM pages or mobile apps, can
+//! bound the memory of the engine with a [`MemoryBudget`]. The budget is
+//! split between the [`EvalCache`] and the [`Tablebase`] tables, and
+//! [`MemoryBudget::enforce`] downsizes them when they go over their share.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chess_engine_movegen::{eval::*, memory::*, tablebase::*};
+//! let budget = MemoryBudget::low_memory();
+//!
+//! let mut cache = EvalCache::with_memory(Material, budget.eval_cache());
+//! let mut tablebase = Tablebase::new();
+//! tablebase.generate(&"KRvK".parse().unwrap());
+//!
+//! budget.enforce(&mut cache, &mut tablebase);
+//! assert!(budget.used(&cache, &tablebase) <= budget.total());
+//! ```
+
+use crate::{eval::EvalCache, tablebase::Tablebase};
+
+/// A limit on the memory of the caches and tables of the engine, in bytes.
+///
+/// A quarter of the budget goes to the evaluation cache, the rest to the
+/// tablebase tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryBudget {
+    total: usize,
+}
+
+/// Methods for the `MemoryBudget` struct.
+impl MemoryBudget {
+    /// The budget of [`MemoryBudget::low_memory`], in bytes, enough for a
+    /// few tables of three pieces.
+    pub const LOW_MEMORY: usize = 4 << 20;
+
+    /// Creates a `MemoryBudget` of `bytes`.
+    pub fn new(bytes: usize) -> Self {
+        Self { total: bytes }
+    }
+
+    /// Creates a `MemoryBudget` small enough for WASM and mobile embedders.
+    pub fn low_memory() -> Self {
+        Self::new(Self::LOW_MEMORY)
+    }
+
+    /// Returns the whole budget, in bytes.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the share of the evaluation cache, in bytes.
+    pub fn eval_cache(&self) -> usize {
+        self.total / 4
+    }
+
+    /// Returns the share of the tablebase tables, in bytes.
+    pub fn tablebase(&self) -> usize {
+        self.total - self.eval_cache()
+    }
+
+    /// Returns the memory used by the cache and the tablebase, in bytes.
+    pub fn used<E>(&self, cache: &EvalCache<E>, tablebase: &Tablebase) -> usize {
+        cache.memory() + tablebase.memory()
+    }
+
+    /// Downsizes the cache and the tablebase that use more than their share:
+    /// the cache is resized, and cleared, and the largest tables are
+    /// dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::{eval::*, memory::*, tablebase::*};
+    /// let mut cache = EvalCache::with_memory(Material, 1 << 20);
+    /// let mut tablebase = Tablebase::new();
+    ///
+    /// let budget = MemoryBudget::new(1 << 16);
+    /// budget.enforce(&mut cache, &mut tablebase);
+    /// assert!(cache.memory() <= budget.eval_cache());
+    /// ```
+    pub fn enforce<E>(&self, cache: &mut EvalCache<E>, tablebase: &mut Tablebase) {
+        if cache.memory() > self.eval_cache() {
+            cache.set_memory(self.eval_cache());
+        }
+
+        tablebase.shrink_to(self.tablebase());
+    }
+}
+
+impl Default for MemoryBudget {
+    /// A budget of 64 MiB.
+    fn default() -> Self {
+        Self::new(64 << 20)
+    }
+}
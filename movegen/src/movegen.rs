@@ -1,10 +1,10 @@
 use chess_engine_core::{
-    BitBoard, CastleRightsType, Color, Move, Piece, PieceMoves, PieceType, Rank, Square,
+    BitBoard, CastleRightsType, Color, File, Move, Piece, PieceMoves, PieceType, Rank, Square,
 };
 
 use crate::{
     get_bishop_attacks, get_king_attacks, get_knight_attacks, get_pawn_attacks, get_pawn_moves,
-    get_queen_attacks, get_rook_attacks, Board,
+    get_queen_attacks, get_rook_attacks, Board, CastleRights,
 };
 
 impl Board {
@@ -66,7 +66,11 @@ impl Board {
         attacked
     }
 
-    fn generate_piece_moves(&self, piece_type: PieceType, square: Square) -> Option<PieceMoves> {
+    pub(crate) fn generate_piece_moves(
+        &self,
+        piece_type: PieceType,
+        square: Square,
+    ) -> Option<PieceMoves> {
         if !self
             .piece_bitboard(Piece::new(piece_type, self.color()))
             .is_get_square(square)
@@ -79,15 +83,16 @@ impl Board {
                 let color = self.color();
                 let mut blockers = self.both_bitboard();
 
-                // pawn moves
+                // pawn moves, a double push is blocked by a piece in front
 
                 match color {
-                    Color::White => {
+                    Color::White if square.rank() == Rank::Two => {
                         blockers |= BitBoard((Rank::Three.bitboard() & blockers).0 << 8);
                     }
-                    Color::Black => {
+                    Color::Black if square.rank() == Rank::Seven => {
                         blockers |= BitBoard((Rank::Six.bitboard() & blockers).0 >> 8);
                     }
+                    _ => {}
                 }
 
                 let pawn_moves = get_pawn_moves(color, square).unset_bit(blockers.0);
@@ -139,13 +144,16 @@ impl Board {
 
                 let castle_rights_type = self.castling_rights().0[color as usize];
 
+                let rook = Piece::new(PieceType::Rook, color);
+
                 if castle_rights_type as usize & CastleRightsType::KingSide as usize != 0 {
                     let squares = match color {
-                        Color::White => [Square::F1, Square::G1],
-                        Color::Black => [Square::F8, Square::G8],
+                        Color::White => [Square::F1, Square::G1, Square::H1],
+                        Color::Black => [Square::F8, Square::G8, Square::H8],
                     };
 
-                    if !self.both_bitboard().is_get_square(squares[0])
+                    if self.get_piece(squares[2]) == Some(rook)
+                        && !self.both_bitboard().is_get_square(squares[0])
                         && !self.both_bitboard().is_get_square(squares[1])
                     {
                         let squares = match color {
@@ -164,11 +172,12 @@ impl Board {
 
                 if castle_rights_type as usize & CastleRightsType::QueenSide as usize != 0 {
                     let squares = match color {
-                        Color::White => [Square::D1, Square::C1, Square::B1],
-                        Color::Black => [Square::D8, Square::C8, Square::B8],
+                        Color::White => [Square::D1, Square::C1, Square::B1, Square::A1],
+                        Color::Black => [Square::D8, Square::C8, Square::B8, Square::A8],
                     };
 
-                    if !self.both_bitboard().is_get_square(squares[0])
+                    if self.get_piece(squares[3]) == Some(rook)
+                        && !self.both_bitboard().is_get_square(squares[0])
                         && !self.both_bitboard().is_get_square(squares[1])
                         && !self.both_bitboard().is_get_square(squares[2])
                     {
@@ -198,6 +207,28 @@ impl Board {
     }
 
     /// Generates all possible moves for the current position.
+    ///
+    /// The moves are pseudo-legal: they may leave the king in check. Use
+    /// [`Board::generate_legal_moves`] for the legal moves only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/8/8/8/P6n/7P/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    /// let moves = board.generate_moves();
+    ///
+    /// // a pawn on its third rank pushes one square
+    /// assert!(moves.contains(&Move::new(Square::A3, Square::A4, None)));
+    /// assert!(!moves.contains(&Move::new(Square::A3, Square::A5, None)));
+    ///
+    /// // a pawn blocked on its third rank can't push at all
+    /// assert!(!moves.iter().any(|mv| mv.from() == Square::H2));
+    /// ```
     pub fn generate_moves(&self) -> Vec<Move> {
         let mut pieces = Vec::new();
 
@@ -211,4 +242,219 @@ impl Board {
 
         pieces
     }
+
+    /// Returns the destination squares of the legal moves of every square.
+    ///
+    /// Squares without a piece of the color to move have no destinations. The
+    /// destinations are the ones of [`Board::generate_legal_moves`], so pinned
+    /// pieces stay on their pin and the king avoids attacked squares.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = Board::default();
+    /// let targets = board.legal_targets();
+    ///
+    /// assert_eq!(targets[Square::G1 as usize], Square::F3.bitboard() | Square::H3.bitboard());
+    /// assert_eq!(targets[Square::E1 as usize], BitBoard::EMPTY);
+    /// assert_eq!(targets[Square::E7 as usize], BitBoard::EMPTY);
+    ///
+    /// // the pinned bishop only moves along its pin, and the king keeps off
+    /// // the square the knight attacks
+    /// let board = "4k3/8/8/8/8/5b2/6B1/5n1K w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    /// let targets = board.legal_targets();
+    ///
+    /// assert_eq!(targets[Square::G2 as usize], Square::F3.bitboard());
+    /// assert_eq!(targets[Square::H1 as usize], Square::G1.bitboard());
+    /// ```
+    pub fn legal_targets(&self) -> [BitBoard; Square::LEN] {
+        let mut targets = [BitBoard::EMPTY; Square::LEN];
+
+        for mv in self.generate_legal_moves() {
+            targets[mv.from() as usize] |= mv.to();
+        }
+
+        targets
+    }
+
+    /// Returns true if the move captures a piece, en passant included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(board.is_capture(Move::new(Square::E5, Square::D6, None)));
+    /// assert!(!board.is_capture(Move::new(Square::E5, Square::E6, None)));
+    /// ```
+    pub fn is_capture(&self, mv: Move) -> bool {
+        self.captured_piece(mv).is_some()
+    }
+
+    /// Returns the piece the move captures, en passant included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     board.captured_piece(Move::new(Square::E5, Square::D6, None)),
+    ///     Some(Piece::new(PieceType::Pawn, Color::Black))
+    /// );
+    /// assert_eq!(board.captured_piece(Move::new(Square::E1, Square::D2, None)), None);
+    /// ```
+    pub fn captured_piece(&self, mv: Move) -> Option<Piece> {
+        if self.is_en_passant(mv) {
+            return Some(Piece::new(PieceType::Pawn, !self.color()));
+        }
+
+        self.get_piece(mv.to())
+            .filter(|piece| piece.color() != self.color())
+    }
+
+    /// Returns true if the move is a pawn capturing en passant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(board.is_en_passant(Move::new(Square::E5, Square::D6, None)));
+    /// ```
+    pub fn is_en_passant(&self, mv: Move) -> bool {
+        self.is_piece_type(mv.from(), PieceType::Pawn)
+            && mv.from().file() != mv.to().file()
+            && Some(mv.to()) == self.en_passant_square()
+    }
+
+    /// Returns true if the move is a king moving two files, castling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/8/8/8/8/8/R3K2R w KQ - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(board.is_castling(Move::new(Square::E1, Square::C1, None)));
+    /// assert!(!board.is_castling(Move::new(Square::E1, Square::D1, None)));
+    /// ```
+    pub fn is_castling(&self, mv: Move) -> bool {
+        self.is_piece_type(mv.from(), PieceType::King)
+            && (mv.from().file() as isize - mv.to().file() as isize).abs() == 2
+    }
+
+    /// Returns true if the move is a pawn reaching the last rank, whether the
+    /// move names the piece to promote to or not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/1P6/8/8/8/8/8/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(board.is_promotion(Move::new(Square::B7, Square::B8, Some(PieceType::Queen))));
+    /// ```
+    pub fn is_promotion(&self, mv: Move) -> bool {
+        let last_rank = match self.color() {
+            Color::White => Rank::Eight,
+            Color::Black => Rank::One,
+        };
+
+        self.is_piece_type(mv.from(), PieceType::Pawn) && mv.to().rank() == last_rank
+    }
+
+    /// Returns the castling rights after the move: a king leaving its
+    /// square loses both rights of its color, and a rook leaving or captured
+    /// on its corner loses the right of its side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// let rights = board.castling_rights_after(Move::new(Square::A1, Square::A8, None));
+    /// assert_eq!(rights.to_string(), "Kk");
+    /// ```
+    pub fn castling_rights_after(&self, mv: Move) -> CastleRights {
+        let mut rights = self.castling_rights();
+
+        for (color, rank) in [(Color::White, Rank::One), (Color::Black, Rank::Eight)] {
+            let square = |file| Square::with_file_rank(file, rank);
+            let lost = [
+                (square(File::E), CastleRightsType::Both),
+                (square(File::H), CastleRightsType::KingSide),
+                (square(File::A), CastleRightsType::QueenSide),
+            ]
+            .into_iter()
+            .filter(|(square, _)| mv.from() == *square || mv.to() == *square)
+            .fold(0, |lost, (_, side)| lost | side as usize);
+
+            let right = &mut rights.0[color as usize];
+            *right = CastleRightsType::new(*right as usize & !lost);
+        }
+
+        rights
+    }
+
+    /// Returns true if the move can't be undone: a pawn move, a capture, or
+    /// a move that loses castling rights. No position before it can repeat.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/8/8/8/8/4P3/R3K1N1 w Q - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(board.is_irreversible(Move::new(Square::E2, Square::E4, None)));
+    /// assert!(board.is_irreversible(Move::new(Square::A1, Square::A2, None)));
+    /// assert!(!board.is_irreversible(Move::new(Square::G1, Square::F3, None)));
+    /// ```
+    pub fn is_irreversible(&self, mv: Move) -> bool {
+        self.is_piece_type(mv.from(), PieceType::Pawn)
+            || self.is_capture(mv)
+            || self.castling_rights_after(mv) != self.castling_rights()
+    }
+
+    fn is_piece_type(&self, square: Square, piece_type: PieceType) -> bool {
+        self.get_piece(square)
+            .is_some_and(|piece| piece.piece_type() == piece_type)
+    }
 }
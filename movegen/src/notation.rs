@@ -0,0 +1,556 @@
+//! Move notations and conversions between them
+//!
+//! A move can be written in several notations:
+//!
+//! - [UCI](https://www.chessprogramming.org/Algebraic_Chess_Notation#UCI):
+//!   `e2e4`, `e7e8q`
+//! - [SAN](https://www.chessprogramming.org/Algebraic_Chess_Notation#SAN):
+//!   `e4`, `Nbd2`, `exd5`, `e8=Q`, `O-O`
+//! - [LAN](https://www.chessprogramming.org/Algebraic_Chess_Notation#LAN):
+//!   `e2-e4`, `Nb1-d2`, `e4xd5`, `e7-e8=Q`, `O-O`
+//! - [ICCF numeric](https://en.wikipedia.org/wiki/ICCF_numeric_notation):
+//!   `5254`, `57581`
+//!
+//! Moves are parsed and formatted against a [`Board`], since SAN and LAN need
+//! the position to know which piece moves, whether it captures and how to
+//! disambiguate it. Moves are matched against
+//! [`Board::generate_legal_moves`], so a pinned piece is neither a legal move
+//! nor a reason to disambiguate. Whole games can be converted with
+//! [`Board::convert_game`].
+//!
+//! # Errors
+//!
+//! Returns a [`NotationError`] if the move can't be parsed or isn't one of
+//! the moves of the board.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chess_engine_movegen::{notation::*, *};
+//! let board = Board::default();
+//!
+//! assert_eq!(board.convert_move("g1f3", Notation::San).unwrap(), "Nf3");
+//! assert_eq!(board.convert_move("Nf3", Notation::Lan).unwrap(), "Ng1-f3");
+//! assert_eq!(board.convert_move("Ng1-f3", Notation::Iccf).unwrap(), "7163");
+//! assert_eq!(board.convert_move("7163", Notation::Uci).unwrap(), "g1f3");
+//! ```
+
+use chess_engine_core::{Color, File, Move, PieceType, Rank, Square};
+use thiserror::Error;
+
+use crate::Board;
+
+/// Errors that can occur when parsing a move
+#[derive(Error, Debug)]
+pub enum NotationError {
+    /// The move is not written in the notation
+    #[error("invalid move {0}")]
+    Invalid(String),
+
+    /// No move of the board matches
+    #[error("illegal move {0}")]
+    Illegal(String),
+
+    /// More than one move of the board matches
+    #[error("ambiguous move {0}")]
+    Ambiguous(String),
+
+    /// The move promotes to a king or a pawn
+    #[error("invalid promotion piece in move {0} (expected N, B, R or Q)")]
+    Promotion(String),
+}
+
+/// A notation for writing moves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Notation {
+    /// Universal Chess Interface notation, like `e2e4`.
+    Uci,
+    /// Standard Algebraic Notation, like `e4`.
+    San,
+    /// Long Algebraic Notation, like `e2-e4`.
+    Lan,
+    /// ICCF numeric notation, like `5254`.
+    Iccf,
+}
+
+impl Notation {
+    /// All the notations, in the order they are tried by
+    /// [`Notation::detect`].
+    pub const ALL: [Notation; 4] = [Notation::Uci, Notation::Iccf, Notation::Lan, Notation::San];
+
+    /// Returns the notation a move is written in, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::notation::*;
+    /// assert_eq!(Notation::detect("e2e4"), Some(Notation::Uci));
+    /// assert_eq!(Notation::detect("e2-e4"), Some(Notation::Lan));
+    /// assert_eq!(Notation::detect("e4"), Some(Notation::San));
+    /// assert_eq!(Notation::detect("5254"), Some(Notation::Iccf));
+    /// assert_eq!(Notation::detect("e9"), None);
+    /// ```
+    pub fn detect(mv: &str) -> Option<Notation> {
+        Notation::ALL
+            .into_iter()
+            .find(|notation| MoveQuery::parse(mv, *notation, Color::White).is_some())
+    }
+}
+
+/// A move as described by a notation, matched against the moves of a board.
+#[derive(Clone, Copy, Debug, Default)]
+struct MoveQuery {
+    piece_type: Option<PieceType>,
+    from_file: Option<File>,
+    from_rank: Option<Rank>,
+    to: Option<Square>,
+    promotion: Option<PieceType>,
+}
+
+impl MoveQuery {
+    /// Parses a move written in a notation, with `color` to move.
+    fn parse(mv: &str, notation: Notation, color: Color) -> Option<MoveQuery> {
+        if !mv.is_ascii() {
+            return None;
+        }
+
+        match notation {
+            Notation::Uci => {
+                let mv: Move = mv.parse().ok()?;
+
+                Some(MoveQuery {
+                    from_file: Some(mv.from().file()),
+                    from_rank: Some(mv.from().rank()),
+                    to: Some(mv.to()),
+                    promotion: mv.promotion(),
+                    ..Default::default()
+                })
+            }
+            Notation::Iccf => {
+                let digits: Vec<usize> = mv
+                    .chars()
+                    .map(|c| c.to_digit(10).map(|digit| digit as usize))
+                    .collect::<Option<_>>()?;
+
+                if (digits.len() != 4 && digits.len() != 5)
+                    || !digits[..4].iter().all(|digit| (1..=8).contains(digit))
+                {
+                    return None;
+                }
+
+                let promotion = match digits.get(4) {
+                    None => None,
+                    Some(1) => Some(PieceType::Queen),
+                    Some(2) => Some(PieceType::Rook),
+                    Some(3) => Some(PieceType::Bishop),
+                    Some(4) => Some(PieceType::Knight),
+                    Some(_) => return None,
+                };
+
+                Some(MoveQuery {
+                    from_file: Some(File::new(digits[0] - 1)),
+                    from_rank: Some(Rank::new(digits[1] - 1)),
+                    to: Some(Square::with_file_rank(
+                        File::new(digits[2] - 1),
+                        Rank::new(digits[3] - 1),
+                    )),
+                    promotion,
+                    ..Default::default()
+                })
+            }
+            Notation::Lan | Notation::San => {
+                let mv = mv.trim_end_matches(['+', '#', '!', '?']);
+
+                if let Some(query) = castling_query(mv, color) {
+                    return Some(query);
+                }
+
+                let (piece_type, mv) = match mv.chars().next()? {
+                    'N' | 'B' | 'R' | 'Q' | 'K' => {
+                        (mv[0..1].to_lowercase().parse().ok()?, &mv[1..])
+                    }
+                    _ => (PieceType::Pawn, mv),
+                };
+
+                let (mv, promotion) = match mv.split_once('=') {
+                    Some((mv, promotion)) => (mv, Some(promotion)),
+                    None if piece_type == PieceType::Pawn
+                        && mv.ends_with(['N', 'B', 'R', 'Q', 'n', 'b', 'r', 'q']) =>
+                    {
+                        (&mv[..mv.len() - 1], Some(&mv[mv.len() - 1..]))
+                    }
+                    None => (mv, None),
+                };
+
+                let promotion = match promotion {
+                    Some(promotion) => Some(promotion.to_lowercase().parse().ok()?),
+                    None => None,
+                };
+
+                if mv.len() < 2 {
+                    return None;
+                }
+
+                let to: Square = mv[mv.len() - 2..].parse().ok()?;
+                let from = mv[..mv.len() - 2].strip_suffix('x');
+                let from = from.unwrap_or(&mv[..mv.len() - 2]);
+
+                let (from_file, from_rank) = if notation == Notation::Lan {
+                    let from = match from.strip_suffix('-') {
+                        Some(from) => from,
+                        None if mv[..mv.len() - 2].ends_with('x') => from,
+                        None => return None,
+                    };
+
+                    let from: Square = from.parse().ok()?;
+
+                    (Some(from.file()), Some(from.rank()))
+                } else {
+                    let (file, rank) = match from.len() {
+                        0 => (None, None),
+                        1 => (from.parse().ok(), from.parse().ok()),
+                        2 => (
+                            Some(from[0..1].parse().ok()?),
+                            Some(from[1..2].parse().ok()?),
+                        ),
+                        _ => return None,
+                    };
+
+                    if from.len() == 1 && file.is_none() && rank.is_none() {
+                        return None;
+                    }
+
+                    (file, rank)
+                };
+
+                Some(MoveQuery {
+                    piece_type: Some(piece_type),
+                    from_file,
+                    from_rank,
+                    to: Some(to),
+                    promotion,
+                })
+            }
+        }
+    }
+
+    /// Returns `true` if the move, made on the board, matches the query.
+    fn matches(&self, board: &Board, mv: Move) -> bool {
+        let piece_type = board.get_piece(mv.from()).map(|piece| piece.piece_type());
+
+        self.piece_type
+            .is_none_or(|query| Some(query) == piece_type)
+            && self.from_file.is_none_or(|file| file == mv.from().file())
+            && self.from_rank.is_none_or(|rank| rank == mv.from().rank())
+            && self.to.is_none_or(|to| to == mv.to())
+            && self.promotion == mv.promotion()
+    }
+}
+
+/// Returns the query of a castling move, if the move is one.
+fn castling_query(mv: &str, color: Color) -> Option<MoveQuery> {
+    let file = match mv {
+        "O-O" | "0-0" => File::G,
+        "O-O-O" | "0-0-0" => File::C,
+        _ => return None,
+    };
+
+    let rank = match color {
+        Color::White => Rank::One,
+        Color::Black => Rank::Eight,
+    };
+
+    Some(MoveQuery {
+        piece_type: Some(PieceType::King),
+        from_file: Some(File::E),
+        from_rank: Some(rank),
+        to: Some(Square::with_file_rank(file, rank)),
+        promotion: None,
+    })
+}
+
+/// Move notations for the `Board` struct.
+impl Board {
+    /// Parses a move written in a notation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NotationError`] if the move isn't written in the notation,
+    /// or doesn't match exactly one move of the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::{notation::*, *};
+    /// # use chess_engine_core::*;
+    /// let board = Board::default();
+    ///
+    /// let mv = board.parse_move("Nc3", Notation::San).unwrap();
+    /// assert_eq!(mv, Move::new(Square::B1, Square::C3, None));
+    ///
+    /// assert!(board.parse_move("Nc3", Notation::Uci).is_err());
+    /// assert!(board.parse_move("Nd2", Notation::San).is_err());
+    /// assert!(matches!(
+    ///     board.parse_move("e8=K", Notation::San),
+    ///     Err(NotationError::Promotion(_))
+    /// ));
+    /// ```
+    pub fn parse_move(&self, mv: &str, notation: Notation) -> Result<Move, NotationError> {
+        let query = MoveQuery::parse(mv, notation, self.color())
+            .ok_or_else(|| NotationError::Invalid(mv.to_string()))?;
+
+        if let Some(PieceType::King | PieceType::Pawn) = query.promotion {
+            return Err(NotationError::Promotion(mv.to_string()));
+        }
+
+        let mut moves = self
+            .generate_legal_moves()
+            .into_iter()
+            .filter(|generated| query.matches(self, *generated));
+
+        match (moves.next(), moves.next()) {
+            (Some(generated), None) => Ok(generated),
+            (Some(_), Some(_)) => Err(NotationError::Ambiguous(mv.to_string())),
+            (None, _) => Err(NotationError::Illegal(mv.to_string())),
+        }
+    }
+
+    /// Formats a move of the board in a notation.
+    ///
+    /// SAN moves that give check end with `+`, and with `#` when they mate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::{notation::*, *};
+    /// # use chess_engine_core::*;
+    /// let board: Board = "4k3/1P6/8/8/8/8/8/R3K2R w KQ - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// let castle = Move::new(Square::E1, Square::C1, None);
+    /// assert_eq!(board.format_move(castle, Notation::San), "O-O-O");
+    ///
+    /// let promotion = Move::new(Square::B7, Square::B8, Some(PieceType::Queen));
+    /// assert_eq!(board.format_move(promotion, Notation::San), "b8=Q+");
+    /// assert_eq!(board.format_move(promotion, Notation::Lan), "b7-b8=Q");
+    /// assert_eq!(board.format_move(promotion, Notation::Iccf), "27281");
+    ///
+    /// let check = Move::new(Square::A1, Square::A8, None);
+    /// assert_eq!(board.format_move(check, Notation::San), "Ra8+");
+    /// assert_eq!(board.format_move(check, Notation::Lan), "Ra1-a8");
+    ///
+    /// let board: Board = "6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// let mate = Move::new(Square::A1, Square::A8, None);
+    /// assert_eq!(board.format_move(mate, Notation::San), "Ra8#");
+    /// ```
+    pub fn format_move(&self, mv: Move, notation: Notation) -> String {
+        let piece_type = self
+            .get_piece(mv.from())
+            .map_or(PieceType::Pawn, |piece| piece.piece_type());
+
+        let promotion = mv.promotion().map_or(String::new(), |promotion| {
+            format!("={}", promotion.to_string().to_uppercase())
+        });
+
+        let is_castling = self.is_castling(mv);
+
+        let castling = if mv.to().file() == File::G {
+            "O-O"
+        } else {
+            "O-O-O"
+        };
+
+        let is_capture = self.is_capture(mv);
+
+        let piece = match piece_type {
+            PieceType::Pawn => String::new(),
+            piece_type => piece_type.to_string().to_uppercase(),
+        };
+
+        match notation {
+            Notation::Uci => mv.to_string(),
+            Notation::Iccf => {
+                let promotion = match mv.promotion() {
+                    Some(PieceType::Queen) => "1",
+                    Some(PieceType::Rook) => "2",
+                    Some(PieceType::Bishop) => "3",
+                    Some(PieceType::Knight) => "4",
+                    _ => "",
+                };
+
+                format!(
+                    "{}{}{}{}{}",
+                    mv.from().file() as usize + 1,
+                    mv.from().rank() as usize + 1,
+                    mv.to().file() as usize + 1,
+                    mv.to().rank() as usize + 1,
+                    promotion
+                )
+            }
+            Notation::Lan if is_castling => castling.to_string(),
+            Notation::Lan => format!(
+                "{}{}{}{}{}",
+                piece,
+                mv.from(),
+                if is_capture { 'x' } else { '-' },
+                mv.to(),
+                promotion
+            ),
+            Notation::San => {
+                let after = self.after(mv);
+                let suffix = match (after.is_check(), after.generate_legal_moves().is_empty()) {
+                    (true, true) => "#",
+                    (true, false) => "+",
+                    (false, _) => "",
+                };
+
+                if is_castling {
+                    return format!("{castling}{suffix}");
+                }
+
+                let disambiguation = if piece_type == PieceType::Pawn {
+                    if is_capture {
+                        mv.from().file().to_string()
+                    } else {
+                        String::new()
+                    }
+                } else {
+                    let others: Vec<Square> = self
+                        .generate_legal_moves()
+                        .into_iter()
+                        .filter(|other| {
+                            other.to() == mv.to()
+                                && other.from() != mv.from()
+                                && self.get_piece(other.from()).map(|piece| piece.piece_type())
+                                    == Some(piece_type)
+                        })
+                        .map(|other| other.from())
+                        .collect();
+
+                    if others.is_empty() {
+                        String::new()
+                    } else if others.iter().all(|other| other.file() != mv.from().file()) {
+                        mv.from().file().to_string()
+                    } else if others.iter().all(|other| other.rank() != mv.from().rank()) {
+                        mv.from().rank().to_string()
+                    } else {
+                        mv.from().to_string()
+                    }
+                };
+
+                format!(
+                    "{}{}{}{}{}{}",
+                    piece,
+                    disambiguation,
+                    if is_capture { "x" } else { "" },
+                    mv.to(),
+                    promotion,
+                    suffix
+                )
+            }
+        }
+    }
+
+    /// Converts a move written in any notation to another notation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NotationError`] if the move isn't written in any notation,
+    /// or doesn't match exactly one move of the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::{notation::*, *};
+    /// let board: Board = "4k3/8/8/8/8/8/4K3/R6R w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(board.convert_move("a1d1", Notation::San).unwrap(), "Rad1");
+    /// assert!(board.convert_move("Rd1", Notation::Uci).is_err());
+    /// ```
+    pub fn convert_move(&self, mv: &str, notation: Notation) -> Result<String, NotationError> {
+        let detected =
+            Notation::detect(mv).ok_or_else(|| NotationError::Invalid(mv.to_string()))?;
+
+        Ok(self.format_move(self.parse_move(mv, detected)?, notation))
+    }
+
+    /// Converts the moves of a game played from the board, written in any
+    /// notation, to another notation, to import and export games, like
+    /// correspondence games in ICCF numeric notation.
+    ///
+    /// Moves are separated by whitespace. Move numbers, like `1.` or `5...`,
+    /// and the result of the game are skipped. The converted moves are
+    /// numbered from the fullmove counter of the board.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NotationError`] for the first move that isn't written in
+    /// any notation, or doesn't match exactly one move of its position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::{notation::*, *};
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(
+    ///     board.convert_game("1. 5254 5755 2. 7163 2836 1-0", Notation::San).unwrap(),
+    ///     "1. e4 e5 2. Nf3 Nc6"
+    /// );
+    /// assert_eq!(
+    ///     board.convert_game("e4 e5 Nf3", Notation::Iccf).unwrap(),
+    ///     "1. 5254 5755 2. 7163"
+    /// );
+    /// assert_eq!(
+    ///     board.convert_game("f2f3 e7e5 g2g4 d8h4", Notation::San).unwrap(),
+    ///     "1. f3 e5 2. g4 Qh4#"
+    /// );
+    /// assert!(board.convert_game("1. e4 e4", Notation::Iccf).is_err());
+    /// ```
+    pub fn convert_game(&self, game: &str, notation: Notation) -> Result<String, NotationError> {
+        let mut board = self.clone();
+        let mut converted = Vec::new();
+
+        for token in game.split_whitespace() {
+            if is_move_number(token) || RESULTS.contains(&token) {
+                continue;
+            }
+
+            let detected =
+                Notation::detect(token).ok_or_else(|| NotationError::Invalid(token.to_string()))?;
+            let mv = board.parse_move(token, detected)?;
+
+            match board.color() {
+                Color::White => converted.push(format!("{}.", board.fullmove_counter())),
+                Color::Black if converted.is_empty() => {
+                    converted.push(format!("{}...", board.fullmove_counter()))
+                }
+                Color::Black => {}
+            }
+
+            converted.push(board.format_move(mv, notation));
+            board.make_move(mv);
+        }
+
+        Ok(converted.join(" "))
+    }
+}
+
+/// The results ending a game.
+const RESULTS: [&str; 4] = ["1-0", "0-1", "1/2-1/2", "*"];
+
+/// Returns `true` if the token is a move number, like `1.` or `5...`.
+fn is_move_number(token: &str) -> bool {
+    let number = token.trim_end_matches('.');
+
+    number.len() < token.len() && !number.is_empty() && number.chars().all(|c| c.is_ascii_digit())
+}
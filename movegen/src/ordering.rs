@@ -0,0 +1,246 @@
+//! Move ordering hooks
+//!
+//! A search cuts off sooner when it tries the best moves first. A
+//! [`MoveOrderer`] sorts the moves of each node, and learns from the moves
+//! that caused cutoffs, so the ordering policy can be replaced, for example
+//! by the priors of a neural network with [`PolicyOrderer`], without
+//! changing the search.
+//!
+//! [`HeuristicOrderer`] is the default ordering: the hash move, then
+//! captures by MVV-LVA, then killer moves, then quiet moves by their history.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chess_engine_movegen::{ordering::*, *};
+//! # use chess_engine_core::*;
+//! let board = "4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1"
+//!     .parse::<BoardBuilder>()
+//!     .unwrap()
+//!     .build();
+//!
+//! let mut moves = board.generate_moves();
+//! HeuristicOrderer::new().order(&board, &mut moves, None, 0);
+//!
+//! // the only capture comes first
+//! assert_eq!(moves[0], Move::new(Square::E4, Square::D5, None));
+//! ```
+
+use std::cmp::Reverse;
+
+use chess_engine_core::{Color, Move, PieceType, Square};
+
+use crate::{
+    eval::{Material, Policy},
+    Board,
+};
+
+/// Sorts the moves of a search node, best first.
+pub trait MoveOrderer {
+    /// Sorts the moves of the board, `ply` plies from the root of the
+    /// search, so the most promising come first.
+    ///
+    /// The hash move is the best move of the transposition table for the
+    /// board, if any.
+    fn order(&self, board: &Board, moves: &mut [Move], hash_move: Option<Move>, ply: usize);
+
+    /// Called when a move of the board, `ply` plies from the root, caused a
+    /// cutoff in a search of `depth` plies. Does nothing by default.
+    fn cutoff(&mut self, _board: &Board, _mv: Move, _ply: usize, _depth: u32) {}
+}
+
+/// The default [`MoveOrderer`] of the search.
+///
+/// Moves are tried in this order:
+///
+/// 1. The hash move.
+/// 2. Captures and promotions, the most valuable victim first, then the
+///    least valuable attacker first (MVV-LVA).
+/// 3. The killer moves of the ply, quiet moves that caused a cutoff in a
+///    sibling node.
+/// 4. The other quiet moves, by how often they caused cutoffs anywhere in
+///    the search (the history heuristic).
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::{ordering::*, *};
+/// # use chess_engine_core::*;
+/// let board = Board::default();
+/// let mut orderer = HeuristicOrderer::new();
+///
+/// let killer = Move::new(Square::G1, Square::F3, None);
+/// orderer.cutoff(&board, killer, 2, 4);
+///
+/// let mut moves = board.generate_moves();
+/// orderer.order(&board, &mut moves, None, 2);
+/// assert_eq!(moves[0], killer);
+///
+/// let hash_move = Move::new(Square::E2, Square::E4, None);
+/// orderer.order(&board, &mut moves, Some(hash_move), 2);
+/// assert_eq!(&moves[..2], &[hash_move, killer]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct HeuristicOrderer {
+    killers: Vec<[Option<Move>; HeuristicOrderer::KILLERS]>,
+    history: Box<[[[i32; Square::LEN]; Square::LEN]; Color::LEN]>,
+}
+
+/// Methods for the `HeuristicOrderer` struct.
+impl HeuristicOrderer {
+    /// The number of killer moves kept per ply.
+    pub const KILLERS: usize = 2;
+
+    /// The history score past which every score is halved, so recent
+    /// cutoffs weigh more than old ones.
+    pub const MAX_HISTORY: i32 = 1 << 20;
+
+    const HASH_MOVE_SCORE: i32 = i32::MAX;
+    const CAPTURE_SCORE: i32 = 1 << 28;
+    const KILLER_SCORE: i32 = 1 << 24;
+
+    /// Creates a `HeuristicOrderer` with no killer moves and no history.
+    pub fn new() -> Self {
+        Self {
+            killers: Vec::new(),
+            history: Box::new([[[0; Square::LEN]; Square::LEN]; Color::LEN]),
+        }
+    }
+
+    /// Forgets the killer moves and the history, before a new game.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the killer moves of a ply, the most recent first.
+    pub fn killers(&self, ply: usize) -> [Option<Move>; HeuristicOrderer::KILLERS] {
+        self.killers.get(ply).copied().unwrap_or_default()
+    }
+
+    /// Returns the history score of a quiet move for a color.
+    pub fn history(&self, color: Color, mv: Move) -> i32 {
+        self.history[color as usize][mv.from() as usize][mv.to() as usize]
+    }
+
+    /// Returns the score of a move, higher first.
+    pub fn score(&self, board: &Board, mv: Move, hash_move: Option<Move>, ply: usize) -> i32 {
+        if Some(mv) == hash_move {
+            return Self::HASH_MOVE_SCORE;
+        }
+
+        if board.is_capture(mv) || board.is_promotion(mv) {
+            let victim = board
+                .get_piece(mv.to())
+                .map_or(PieceType::Pawn, |piece| piece.piece_type());
+            let victim = if board.is_capture(mv) {
+                Material::value(victim)
+            } else {
+                0
+            };
+            let attacker = board
+                .get_piece(mv.from())
+                .map_or(0, |piece| Material::value(piece.piece_type()));
+            let promotion = mv.promotion().map_or(0, Material::value);
+
+            return Self::CAPTURE_SCORE + (victim + promotion) * 16 - attacker / 100;
+        }
+
+        if let Some(slot) = self
+            .killers(ply)
+            .iter()
+            .position(|&killer| killer == Some(mv))
+        {
+            return Self::KILLER_SCORE - slot as i32;
+        }
+
+        self.history(board.color(), mv)
+    }
+}
+
+impl Default for HeuristicOrderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MoveOrderer for HeuristicOrderer {
+    fn order(&self, board: &Board, moves: &mut [Move], hash_move: Option<Move>, ply: usize) {
+        moves.sort_by_cached_key(|&mv| Reverse(self.score(board, mv, hash_move, ply)));
+    }
+
+    fn cutoff(&mut self, board: &Board, mv: Move, ply: usize, depth: u32) {
+        if board.is_capture(mv) || board.is_promotion(mv) {
+            return;
+        }
+
+        if self.killers.len() <= ply {
+            self.killers.resize(ply + 1, Default::default());
+        }
+
+        let killers = &mut self.killers[ply];
+        if killers[0] != Some(mv) {
+            killers.copy_within(..Self::KILLERS - 1, 1);
+            killers[0] = Some(mv);
+        }
+
+        let color = board.color() as usize;
+        let bonus = (depth * depth).min(Self::MAX_HISTORY as u32) as i32;
+        let history = &mut self.history[color][mv.from() as usize][mv.to() as usize];
+        *history += bonus;
+
+        if *history > Self::MAX_HISTORY {
+            for score in self.history.iter_mut().flatten().flatten() {
+                *score /= 2;
+            }
+        }
+    }
+}
+
+/// A [`MoveOrderer`] sorting moves by the priors of a [`Policy`], after the
+/// hash move.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::{eval::*, ordering::*, *};
+/// # use chess_engine_core::*;
+/// /// A policy preferring moves to the center.
+/// struct Center;
+///
+/// impl Policy for Center {
+///     fn priors(&self, _board: &Board, moves: &[Move]) -> Vec<f32> {
+///         let center = |mv: &Move| {
+///             let file = mv.to().file() as i32;
+///             let rank = mv.to().rank() as i32;
+///             (7 - (2 * file - 7).abs() - (2 * rank - 7).abs()) as f32
+///         };
+///         let total: f32 = moves.iter().map(|mv| center(mv).exp()).sum();
+///         moves.iter().map(|mv| center(mv).exp() / total).collect()
+///     }
+/// }
+///
+/// let board = Board::default();
+/// let mut moves = board.generate_moves();
+/// PolicyOrderer(Center).order(&board, &mut moves, None, 0);
+///
+/// assert!(moves[..2].contains(&Move::new(Square::E2, Square::E4, None)));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PolicyOrderer<P>(pub P);
+
+impl<P: Policy> MoveOrderer for PolicyOrderer<P> {
+    fn order(&self, board: &Board, moves: &mut [Move], hash_move: Option<Move>, _ply: usize) {
+        let priors = self.0.priors(board, moves);
+
+        let mut scored: Vec<(Move, f32)> = moves.iter().copied().zip(priors).collect();
+        scored.sort_by(|(a, a_prior), (b, b_prior)| {
+            (Some(*b) == hash_move)
+                .cmp(&(Some(*a) == hash_move))
+                .then(b_prior.total_cmp(a_prior))
+        });
+
+        for (mv, (scored, _)) in moves.iter_mut().zip(scored) {
+            *mv = scored;
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Compact binary codec for positions
+//!
+//! Training data for tuning holds millions of positions, each with a score
+//! and the result of its game. A [`PackedPosition`] always takes
+//! [`PackedPosition::SIZE`] bytes, so datasets stay small and any record can
+//! be found by its index.
+//!
+//! | Bytes  | Content                                                     |
+//! | ------ | ----------------------------------------------------------- |
+//! | 0..8   | occupied squares, as a little-endian bitboard               |
+//! | 8..24  | one nibble per occupied square, `color << 3 \| piece type`  |
+//! | 24     | color to move (bit 0), white and black castle rights (bits 1-4) |
+//! | 25     | en passant square, or 64 if there is none                   |
+//! | 26     | halfmove clock                                              |
+//! | 27..29 | fullmove counter, little-endian                             |
+//! | 29..31 | score, little-endian                                        |
+//! | 31     | game result                                                 |
+//!
+//! # Errors
+//!
+//! Returns a [`PackError`] if the position can't be packed or the bytes are
+//! invalid.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chess_engine_movegen::{packed::*, *};
+//! # use chess_engine_core::*;
+//! let position = PackedPosition {
+//!     board: Board::default(),
+//!     score: 35,
+//!     result: GameResult::Draw,
+//! };
+//!
+//! let bytes = position.encode().unwrap();
+//! let decoded = PackedPosition::decode(&bytes).unwrap();
+//!
+//! assert_eq!(decoded.board.to_string(), position.board.to_string());
+//! assert_eq!(decoded.score, 35);
+//! assert_eq!(decoded.result, GameResult::Draw);
+//! ```
+
+use chess_engine_core::{
+    BitBoard, CastleRightsType, Color, GameResult, Piece, PieceCodeError, Rank, Square,
+};
+use thiserror::Error;
+
+use crate::{Board, BoardBuilder, CastleRights, State};
+
+/// Errors that can occur when packing or unpacking a position
+#[derive(Error, Debug)]
+pub enum PackError {
+    /// Too many pieces to pack
+    #[error("too many pieces (expected 32 or fewer, got {0})")]
+    TooManyPieces(u32),
+
+    /// Invalid piece code
+    #[error("{0}")]
+    Piece(#[from] PieceCodeError),
+
+    /// Invalid en passant square
+    #[error("invalid en passant square {0}")]
+    EnPassantSquare(u8),
+
+    /// Invalid halfmove clock
+    #[error("invalid halfmove clock")]
+    HalfmoveClock,
+
+    /// Invalid fullmove counter
+    #[error("invalid fullmove counter")]
+    FullmoveCounter,
+
+    /// Invalid game result
+    #[error("invalid game result {0}")]
+    GameResult(u8),
+}
+
+/// A position with its score and the result of its game, packed into
+/// [`PackedPosition::SIZE`] bytes.
+#[derive(Clone, Debug)]
+pub struct PackedPosition {
+    /// The position.
+    pub board: Board,
+    /// Score of the position, usually in centipawns from White's point of
+    /// view.
+    pub score: i16,
+    /// Result of the game the position comes from.
+    pub result: GameResult,
+}
+
+impl PackedPosition {
+    /// Size in bytes of a packed position.
+    pub const SIZE: usize = 32;
+
+    /// Maximum number of pieces in a packed position.
+    pub const MAX_PIECES: u32 = 32;
+
+    /// No en passant square.
+    const NO_EN_PASSANT: u8 = Square::LEN as u8;
+
+    /// Packs the position into bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PackError::TooManyPieces`] if the board has more than
+    /// [`PackedPosition::MAX_PIECES`] pieces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::{packed::*, *};
+    /// # use chess_engine_core::*;
+    /// let position = PackedPosition {
+    ///     board: Board::default(),
+    ///     score: 0,
+    ///     result: GameResult::WhiteWins,
+    /// };
+    ///
+    /// let bytes = position.encode().unwrap();
+    /// assert_eq!(bytes[..8], 0xFFFF00000000FFFFu64.to_le_bytes());
+    /// ```
+    pub fn encode(&self) -> Result<[u8; Self::SIZE], PackError> {
+        let mut bytes = [0; Self::SIZE];
+
+        let occupied = self.board.both_bitboard();
+
+        if occupied.0.count_ones() > Self::MAX_PIECES {
+            return Err(PackError::TooManyPieces(occupied.0.count_ones()));
+        }
+
+        bytes[0..8].copy_from_slice(&occupied.0.to_le_bytes());
+
+        let mut pieces = 0u128;
+
+        for (index, square) in occupied.into_iter().enumerate() {
+            let code = u8::from(self.board.get_piece(square).unwrap()) as u128;
+
+            pieces |= code << (index * 4);
+        }
+
+        bytes[8..24].copy_from_slice(&pieces.to_le_bytes());
+
+        let castling_rights = self.board.castling_rights().0;
+
+        bytes[24] = self.board.color() as u8
+            | (castling_rights[Color::White as usize] as u8) << 1
+            | (castling_rights[Color::Black as usize] as u8) << 3;
+
+        bytes[25] = self
+            .board
+            .en_passant_square()
+            .map_or(Self::NO_EN_PASSANT, |square| square as u8);
+
+        bytes[26] = self.board.halfmove_clock();
+        bytes[27..29].copy_from_slice(&self.board.fullmove_counter().to_le_bytes());
+        bytes[29..31].copy_from_slice(&self.score.to_le_bytes());
+        bytes[31] = self.result as u8;
+
+        Ok(bytes)
+    }
+
+    /// Unpacks a position from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PackError`] if the bytes don't hold a valid position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::packed::*;
+    /// let mut bytes = [0; PackedPosition::SIZE];
+    /// bytes[25] = 64;
+    /// bytes[27] = 1;
+    /// assert!(PackedPosition::decode(&bytes).is_ok());
+    ///
+    /// bytes[31] = 3;
+    /// assert!(PackedPosition::decode(&bytes).is_err());
+    /// ```
+    pub fn decode(bytes: &[u8; Self::SIZE]) -> Result<Self, PackError> {
+        let mut board_builder = BoardBuilder::new();
+
+        let occupied = BitBoard(u64::from_le_bytes(bytes[0..8].try_into().unwrap()));
+
+        if occupied.0.count_ones() > Self::MAX_PIECES {
+            return Err(PackError::TooManyPieces(occupied.0.count_ones()));
+        }
+
+        let pieces = u128::from_le_bytes(bytes[8..24].try_into().unwrap());
+
+        for (index, square) in occupied.into_iter().enumerate() {
+            let code = (pieces >> (index * 4)) as u8 & 0b1111;
+
+            board_builder.put_piece(Piece::try_from(code)?, square);
+        }
+
+        board_builder.color(Color::new((bytes[24] & 0b1) as usize));
+
+        board_builder.castling_rights(CastleRights([
+            CastleRightsType::new((bytes[24] >> 1 & 0b11) as usize),
+            CastleRightsType::new((bytes[24] >> 3 & 0b11) as usize),
+        ]));
+
+        let en_passant_square = match bytes[25] {
+            Self::NO_EN_PASSANT => None,
+            index if index < Self::NO_EN_PASSANT => {
+                let square = Square::new(index as usize);
+
+                if square.rank() != Rank::Three && square.rank() != Rank::Six {
+                    return Err(PackError::EnPassantSquare(index));
+                }
+
+                Some(square)
+            }
+            index => return Err(PackError::EnPassantSquare(index)),
+        };
+
+        board_builder.en_passant_square(en_passant_square);
+
+        if bytes[26] > State::MAX_HALFMOVE_CLOCK {
+            return Err(PackError::HalfmoveClock);
+        }
+
+        board_builder.halfmove_clock(bytes[26]);
+
+        let fullmove_counter = u16::from_le_bytes([bytes[27], bytes[28]]);
+
+        if fullmove_counter == 0 {
+            return Err(PackError::FullmoveCounter);
+        }
+
+        board_builder.fullmove_counter(fullmove_counter);
+
+        if bytes[31] as usize >= GameResult::LEN {
+            return Err(PackError::GameResult(bytes[31]));
+        }
+
+        Ok(PackedPosition {
+            board: board_builder.build(),
+            score: i16::from_le_bytes([bytes[29], bytes[30]]),
+            result: GameResult::new(bytes[31] as usize),
+        })
+    }
+}
@@ -0,0 +1,81 @@
+use chess_engine_core::{BitBoard, Color, Piece, PieceType};
+
+use crate::{adjacent_files, pawn_attack_span, pawn_front_span, Board};
+
+/// Pawn structure for the `Board` struct.
+impl Board {
+    /// Returns the passed pawns of the color: the pawns no enemy pawn can
+    /// stop or capture on their way to promotion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/1p6/6P1/1PP5/8/8/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(board.passed_pawns(Color::White), Square::G5.bitboard());
+    /// assert_eq!(board.passed_pawns(Color::Black), BitBoard::EMPTY);
+    /// ```
+    pub fn passed_pawns(&self, color: Color) -> BitBoard {
+        let enemy_pawns = self.piece_bitboard(Piece::new(PieceType::Pawn, !color));
+        let mut passed = BitBoard::EMPTY;
+
+        for square in self.piece_bitboard(Piece::new(PieceType::Pawn, color)) {
+            let span = pawn_front_span(color, square) | pawn_attack_span(color, square);
+
+            if (span & enemy_pawns).is_empty() {
+                passed |= square;
+            }
+        }
+
+        passed
+    }
+
+    /// Returns the candidate passed pawns of the color: the pawns that are
+    /// not passed yet, but have no enemy pawn in front of them and at least
+    /// as many pawns to support them as enemy pawns guarding their path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/1p6/6P1/1PP5/8/8/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// // the c-pawn can trade itself through with the help of the b-pawn
+    /// assert_eq!(board.candidate_passers(Color::White), Square::C4.bitboard());
+    /// ```
+    pub fn candidate_passers(&self, color: Color) -> BitBoard {
+        let pawns = self.piece_bitboard(Piece::new(PieceType::Pawn, color));
+        let enemy_pawns = self.piece_bitboard(Piece::new(PieceType::Pawn, !color));
+        let mut candidates = BitBoard::EMPTY;
+
+        for square in pawns {
+            if !(pawn_front_span(color, square) & enemy_pawns).is_empty() {
+                continue;
+            }
+
+            let sentries = pawn_attack_span(color, square) & enemy_pawns;
+
+            if sentries.is_empty() {
+                continue;
+            }
+
+            let neighbors = adjacent_files(square.file()) & square.rank().bitboard();
+            let helpers = (pawn_attack_span(!color, square) | neighbors) & pawns;
+
+            if helpers.len() >= sentries.len() {
+                candidates |= square;
+            }
+        }
+
+        candidates
+    }
+}
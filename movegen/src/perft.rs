@@ -0,0 +1,203 @@
+//! Loader for perft test suites
+//!
+//! [Perft](https://www.chessprogramming.org/Perft) counts the leaf nodes of
+//! the move tree of a position up to a depth. The community `perftsuite.epd`
+//! format lists one position per line, followed by the expected node counts
+//! at several depths:
+//!
+//! ```text
+//! rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 ;D1 20 ;D2 400 ;D3 8902
+//! ```
+//!
+//! Empty lines and lines starting with `#` are skipped. The node counts are
+//! checked with [`perft`].
+//!
+//! # Errors
+//!
+//! Returns a [`PerftError`] if a line is invalid.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chess_engine_movegen::perft::*;
+//! let suite = "
+//!     ## starting position
+//!     rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 ;D1 20 ;D2 400
+//!     4k3/8/8/8/8/8/8/4K2R w K - ;D1 15
+//! ";
+//!
+//! let positions = load_suite(suite.as_bytes()).unwrap();
+//!
+//! assert_eq!(positions.len(), 2);
+//! assert_eq!(positions[0].depths, vec![(1, 20), (2, 400)]);
+//! ```
+
+use std::{io::BufRead, str::FromStr};
+
+use thiserror::Error;
+
+use crate::{fen::FenError, Board, BoardBuilder};
+
+/// Errors that can occur when loading a perft suite
+#[derive(Error, Debug)]
+pub enum PerftError {
+    /// Failed to read the suite
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+
+    /// Invalid FEN string
+    #[error("{0}")]
+    Fen(#[from] FenError),
+
+    /// Invalid depth entry
+    #[error("invalid depth entry (expected D<depth> <nodes>, got {0})")]
+    Depth(String),
+
+    /// Invalid line, with the line number
+    #[error("line {line}: {error}")]
+    Line {
+        /// Line number, starting at 1
+        line: usize,
+        /// Error in the line
+        error: Box<PerftError>,
+    },
+}
+
+/// A position of a perft suite, with the expected node counts.
+#[derive(Clone, Debug)]
+pub struct PerftPosition {
+    /// The position.
+    pub board: BoardBuilder,
+    /// Expected node counts, as `(depth, nodes)` pairs.
+    pub depths: Vec<(u8, u64)>,
+}
+
+/// Parses a [`PerftPosition`] from a line of a perft suite.
+///
+/// # Errors
+///
+/// Returns a [`PerftError`] if the FEN string or a depth entry is invalid.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::perft::*;
+/// let position: PerftPosition = "4k3/8/8/8/8/8/8/4K2R w K - ;D1 15"
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(position.board.to_string(), "4k3/8/8/8/8/8/8/4K2R w K - 0 1");
+/// assert_eq!(position.depths, vec![(1, 15)]);
+///
+/// assert!("4k3/8/8/8/8/8/8/4K2R w K - ;1 15".parse::<PerftPosition>().is_err());
+/// ```
+impl FromStr for PerftPosition {
+    type Err = PerftError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut sections = line.split(';');
+
+        let board = sections.next().unwrap_or_default().parse()?;
+
+        let depths = sections
+            .map(|entry| {
+                let error = || PerftError::Depth(entry.trim().to_string());
+
+                let (depth, nodes) = entry
+                    .trim()
+                    .strip_prefix('D')
+                    .and_then(|entry| entry.split_once(' '))
+                    .ok_or_else(error)?;
+
+                Ok((
+                    depth.parse().map_err(|_| error())?,
+                    nodes.trim().parse().map_err(|_| error())?,
+                ))
+            })
+            .collect::<Result<_, PerftError>>()?;
+
+        Ok(PerftPosition { board, depths })
+    }
+}
+
+/// Loads all the positions of a perft suite.
+///
+/// # Errors
+///
+/// Returns a [`PerftError::Line`] with the number of the first invalid line,
+/// or a [`PerftError::Io`] if the suite can't be read.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::perft::*;
+/// let error = load_suite("8/8/8 w - - ;D1 1".as_bytes()).unwrap_err();
+/// assert!(matches!(error, PerftError::Line { line: 1, .. }));
+/// ```
+pub fn load_suite(reader: impl BufRead) -> Result<Vec<PerftPosition>, PerftError> {
+    let mut positions = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let position = line.parse().map_err(|error| PerftError::Line {
+            line: index + 1,
+            error: Box::new(error),
+        })?;
+
+        positions.push(position);
+    }
+
+    Ok(positions)
+}
+
+/// Counts the leaf nodes of the legal move tree of the board up to a depth,
+/// playing the moves with [`Board::make_move`] and taking them back.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::perft::*;
+/// let suite = "
+///     rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 ;D1 20 ;D2 400 ;D3 8902
+///     r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1 ;D1 48 ;D2 2039
+///     8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1 ;D1 14 ;D2 191 ;D3 2812
+///     r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1 ;D1 6 ;D2 264
+///     rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8 ;D1 44 ;D2 1486
+///     r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10 ;D1 46 ;D2 2079
+/// ";
+///
+/// for position in load_suite(suite.as_bytes()).unwrap() {
+///     let mut board = position.board.build();
+///
+///     for (depth, nodes) in position.depths {
+///         assert_eq!(perft(&mut board, depth), nodes, "{board} at depth {depth}");
+///     }
+/// }
+/// ```
+pub fn perft(board: &mut Board, depth: u8) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let moves = board.generate_legal_moves();
+
+    if depth == 1 {
+        return moves.len() as u64;
+    }
+
+    moves
+        .into_iter()
+        .map(|mv| {
+            board.make_move(mv);
+            let nodes = perft(board, depth - 1);
+            board.unmake_move(mv);
+            nodes
+        })
+        .sum()
+}
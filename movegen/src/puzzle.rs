@@ -0,0 +1,242 @@
+//! Loader for the Lichess puzzle database
+//!
+//! The [Lichess puzzle database](https://database.lichess.org/#puzzles) is a
+//! CSV file with one puzzle per line:
+//!
+//! ```text
+//! PuzzleId,FEN,Moves,Rating,RatingDeviation,Popularity,NbPlays,Themes,GameUrl,OpeningTags
+//! 00sHx,q3k1nr/1pp1nQpp/3p4/1P2p3/4P3/B1PP1b2/B5PP/5K2 b k - 0 17,e8d7 a2e6 d7d8 f7f8,1760,80,83,72,mate mateIn2 middlegame short,https://lichess.org/yyznGmXs/black#34,
+//! ```
+//!
+//! The FEN is the position before the opponent's move, the first of the
+//! moves. The solution is the rest of the moves, in UCI notation. The header
+//! line is skipped.
+//!
+//! # Errors
+//!
+//! Returns a [`PuzzleError`] if a line is invalid.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chess_engine_movegen::puzzle::*;
+//! let csv = "\
+//! PuzzleId,FEN,Moves,Rating,RatingDeviation,Popularity,NbPlays,Themes,GameUrl,OpeningTags
+//! 00sHx,q3k1nr/1pp1nQpp/3p4/1P2p3/4P3/B1PP1b2/B5PP/5K2 b k - 0 17,e8d7 a2e6 d7d8 f7f8,1760,80,83,72,mate mateIn2 middlegame short,https://lichess.org/yyznGmXs/black#34,
+//! ";
+//!
+//! let puzzles = load_puzzles(csv.as_bytes()).unwrap();
+//!
+//! assert_eq!(puzzles.len(), 1);
+//! assert_eq!(puzzles[0].rating, 1760);
+//! assert_eq!(puzzles[0].solution().len(), 3);
+//! ```
+
+use std::{io::BufRead, str::FromStr};
+
+use chess_engine_core::{Move, MoveError};
+use thiserror::Error;
+
+use crate::{fen::FenError, Board, BoardBuilder};
+
+/// Errors that can occur when loading puzzles
+#[derive(Error, Debug)]
+pub enum PuzzleError {
+    /// Failed to read the puzzles
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+
+    /// Invalid number of columns
+    #[error("invalid number of columns (expected at least 8, got {0})")]
+    Columns(usize),
+
+    /// Invalid FEN string
+    #[error("{0}")]
+    Fen(#[from] FenError),
+
+    /// Invalid move
+    #[error("{0}")]
+    Move(#[from] MoveError),
+
+    /// The puzzle has no solution
+    #[error("puzzle has no solution")]
+    NoSolution,
+
+    /// A move of the line is not legal in its position
+    #[error("illegal move {0}")]
+    IllegalMove(Move),
+
+    /// Invalid rating
+    #[error("invalid rating (got {0})")]
+    Rating(String),
+
+    /// Invalid line, with the line number
+    #[error("line {line}: {error}")]
+    Line {
+        /// Line number, starting at 1
+        line: usize,
+        /// Error in the line
+        error: Box<PuzzleError>,
+    },
+}
+
+/// A puzzle from the Lichess puzzle database.
+#[derive(Clone, Debug)]
+pub struct Puzzle {
+    /// The puzzle id.
+    pub id: String,
+    /// The position before the opponent's move.
+    pub board: Board,
+    /// The opponent's move followed by the solution, in order.
+    pub moves: Vec<Move>,
+    /// The puzzle rating.
+    pub rating: u16,
+    /// The puzzle themes, like `mateIn2` or `fork`.
+    pub themes: Vec<String>,
+}
+
+impl Puzzle {
+    /// Returns the move played by the opponent before the puzzle starts.
+    pub fn opponent_move(&self) -> Move {
+        self.moves[0]
+    }
+
+    /// Returns the solution moves, alternating between the solver and the
+    /// opponent.
+    pub fn solution(&self) -> &[Move] {
+        &self.moves[1..]
+    }
+
+    /// Returns `true` if the puzzle has the theme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::puzzle::*;
+    /// let puzzle: Puzzle = "00008,r6k/pp2r2p/4Rp1Q/3p4/8/1N1P2R1/PqP2bPP/7K b - - 0 24,f2g3 e6e7 b2b1 b3c1 b1c1 h6c1,1913,75,94,6230,crushing hangingPiece long middlegame,https://lichess.org/787zsVup/black#48,"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// assert!(puzzle.has_theme("hangingPiece"));
+    /// assert!(!puzzle.has_theme("mate"));
+    /// ```
+    pub fn has_theme(&self, theme: &str) -> bool {
+        self.themes.iter().any(|other| other == theme)
+    }
+}
+
+/// Parses a [`Puzzle`] from a line of the Lichess puzzle database.
+///
+/// The whole line, the opponent's move and the solution, is replayed from
+/// the position, each move checked against the legal moves.
+///
+/// # Errors
+///
+/// Returns a [`PuzzleError`] if a column is invalid, or with the first move
+/// of the line that is not legal.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::puzzle::*;
+/// let line = "00sHx,q3k1nr/1pp1nQpp/3p4/1P2p3/4P3/B1PP1b2/B5PP/5K2 b k - 0 17,e8d7 a2e6 d7d8 f7f8,1760,80,83,72,mate mateIn2 middlegame short,https://lichess.org/yyznGmXs/black#34,";
+/// let puzzle: Puzzle = line.parse().unwrap();
+///
+/// assert_eq!(puzzle.id, "00sHx");
+/// assert_eq!(puzzle.opponent_move().to_string(), "e8d7");
+///
+/// let illegal = line.replace("e8d7", "e8e7");
+/// assert!(matches!(
+///     illegal.parse::<Puzzle>(),
+///     Err(PuzzleError::IllegalMove(_))
+/// ));
+///
+/// let illegal = line.replace("d7d8 f7f8", "d7c8 f7f8");
+/// assert!(matches!(
+///     illegal.parse::<Puzzle>(),
+///     Err(PuzzleError::IllegalMove(mv)) if mv.to_string() == "d7c8"
+/// ));
+/// ```
+impl FromStr for Puzzle {
+    type Err = PuzzleError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let columns: Vec<&str> = line.split(',').collect();
+
+        if columns.len() < 8 {
+            return Err(PuzzleError::Columns(columns.len()));
+        }
+
+        let board = columns[1].parse::<BoardBuilder>()?.build();
+
+        let moves = columns[2]
+            .split_whitespace()
+            .map(|mv| mv.parse())
+            .collect::<Result<Vec<Move>, _>>()?;
+
+        if moves.len() < 2 {
+            return Err(PuzzleError::NoSolution);
+        }
+
+        let mut replay = board.clone();
+
+        for &mv in &moves {
+            if !replay.generate_legal_moves().contains(&mv) {
+                return Err(PuzzleError::IllegalMove(mv));
+            }
+
+            replay.make_move(mv);
+        }
+
+        let rating = columns[3]
+            .parse()
+            .map_err(|_| PuzzleError::Rating(columns[3].to_string()))?;
+
+        let themes = columns[7].split_whitespace().map(str::to_string).collect();
+
+        Ok(Puzzle {
+            id: columns[0].to_string(),
+            board,
+            moves,
+            rating,
+            themes,
+        })
+    }
+}
+
+/// Loads all the puzzles of a Lichess puzzle database.
+///
+/// # Errors
+///
+/// Returns a [`PuzzleError::Line`] with the number of the first invalid
+/// line, or a [`PuzzleError::Io`] if the puzzles can't be read.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::puzzle::*;
+/// let error = load_puzzles("00000,8/8/8 w - -,e2e4 e7e5,1500,0,0,0,opening".as_bytes())
+///     .unwrap_err();
+/// assert!(matches!(error, PuzzleError::Line { line: 1, .. }));
+/// ```
+pub fn load_puzzles(reader: impl BufRead) -> Result<Vec<Puzzle>, PuzzleError> {
+    let mut puzzles = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with("PuzzleId,") {
+            continue;
+        }
+
+        let puzzle = line.parse().map_err(|error| PuzzleError::Line {
+            line: index + 1,
+            error: Box::new(error),
+        })?;
+
+        puzzles.push(puzzle);
+    }
+
+    Ok(puzzles)
+}
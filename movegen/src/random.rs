@@ -0,0 +1,184 @@
+use chess_engine_core::{Color, Move, Piece, PieceType, Square};
+use rand::prelude::*;
+
+use crate::{Board, BoardBuilder};
+
+/// Random move selection for the `Board` struct.
+impl Board {
+    /// Returns a move of the position chosen uniformly at random, or `None`
+    /// if there are no moves.
+    ///
+    /// The move is one of [`Board::generate_legal_moves`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// let board = Board::default();
+    /// let mut rng = StdRng::seed_from_u64(0);
+    ///
+    /// let mv = board.random_legal_move(&mut rng).unwrap();
+    /// assert!(board.generate_legal_moves().contains(&mv));
+    ///
+    /// // the pinned knight never moves
+    /// let board = "4k3/4r3/8/8/8/8/4N3/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// for _ in 0..20 {
+    ///     assert_eq!(board.random_legal_move(&mut rng).unwrap().from(), Square::E1);
+    /// }
+    /// ```
+    pub fn random_legal_move<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Move> {
+        self.generate_legal_moves().choose(rng).copied()
+    }
+
+    /// Returns a random move of the position, choosing a capture with a
+    /// probability of `capture_bias` when there is one.
+    ///
+    /// A bias of `0.0` picks among the quiet moves when there are any, and a
+    /// bias of `1.0` always captures when it can.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// let board = "4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    /// let mut rng = StdRng::seed_from_u64(0);
+    ///
+    /// let mv = board.random_legal_move_biased(&mut rng, 1.0).unwrap();
+    /// assert_eq!(mv.to_string(), "e4d5");
+    /// ```
+    pub fn random_legal_move_biased<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        capture_bias: f64,
+    ) -> Option<Move> {
+        let (captures, quiets): (Vec<Move>, Vec<Move>) = self
+            .generate_legal_moves()
+            .into_iter()
+            .partition(|mv| self.is_capture(*mv));
+
+        let capture = !captures.is_empty()
+            && (quiets.is_empty() || rng.gen_bool(capture_bias.clamp(0.0, 1.0)));
+
+        if capture {
+            captures.choose(rng).copied()
+        } else {
+            quiets.choose(rng).copied()
+        }
+    }
+
+    /// Plays random legal moves from the position, with
+    /// [`Board::random_legal_move_biased`], and returns them.
+    ///
+    /// The playout stops after `max_plies` moves, or earlier on checkmate,
+    /// stalemate or a draw by the fifty-move rule, repetition or
+    /// insufficient material. The board is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// let mut board = Board::default();
+    /// let mut rng = StdRng::seed_from_u64(0);
+    ///
+    /// let moves = board.random_playout(&mut rng, 40, 0.5);
+    /// assert!(moves.len() <= 40);
+    ///
+    /// for mv in moves {
+    ///     assert!(board.generate_legal_moves().contains(&mv));
+    ///     board.make_move(mv);
+    /// }
+    /// ```
+    pub fn random_playout<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        max_plies: usize,
+        capture_bias: f64,
+    ) -> Vec<Move> {
+        let mut board = self.clone();
+        let mut moves = Vec::new();
+
+        while moves.len() < max_plies
+            && !board.draw_by_fifty_moves()
+            && !board.draw_by_repetition()
+            && !board.draw_by_insufficient_material()
+        {
+            let Some(mv) = board.random_legal_move_biased(rng, capture_bias) else {
+                break;
+            };
+
+            board.make_move(mv);
+            moves.push(mv);
+        }
+
+        moves
+    }
+}
+
+/// Random positions for the `BoardBuilder` struct.
+impl BoardBuilder {
+    /// Returns a random position with both kings and up to 16 other pieces,
+    /// [reachable](Board::is_reachable) as far as the heuristics can tell.
+    ///
+    /// Either color can be to move, and there are no castling rights nor en
+    /// passant square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(0);
+    ///
+    /// for _ in 0..20 {
+    ///     let board = BoardBuilder::random(&mut rng).build();
+    ///     let pieces = Square::ALL
+    ///         .into_iter()
+    ///         .filter(|&square| board.get_piece(square).is_some())
+    ///         .count();
+    ///
+    ///     assert!(board.is_reachable());
+    ///     assert!((2..=18).contains(&pieces));
+    /// }
+    /// ```
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let others: Vec<Piece> = Piece::ALL
+            .into_iter()
+            .filter(|piece| piece.piece_type() != PieceType::King)
+            .collect();
+
+        loop {
+            let mut squares = Square::ALL;
+            squares.shuffle(rng);
+
+            let mut builder = BoardBuilder::new();
+            builder.put_piece(Piece::new(PieceType::King, Color::White), squares[0]);
+            builder.put_piece(Piece::new(PieceType::King, Color::Black), squares[1]);
+
+            for &square in &squares[2..rng.gen_range(2..=18)] {
+                builder.put_piece(*others.choose(rng).expect("there are pieces"), square);
+            }
+
+            builder.color(if rng.gen() {
+                Color::White
+            } else {
+                Color::Black
+            });
+
+            if builder.build().is_reachable() {
+                return builder;
+            }
+        }
+    }
+}
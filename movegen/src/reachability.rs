@@ -0,0 +1,193 @@
+use chess_engine_core::{BitBoard, CastleRightsType, Color, File, Piece, PieceType, Rank, Square};
+
+use crate::Board;
+
+/// Reachability for the `Board` struct.
+impl Board {
+    /// Returns `false` if the board can't arise from the starting position.
+    ///
+    /// The checks are heuristics: a position that passes them may still be
+    /// unreachable, but a position that fails them is never reachable.
+    ///
+    /// - every color has exactly one king, at most 16 pieces, and no pawns
+    ///   on the first or last rank.
+    /// - extra queens, rooks, knights and bishops, counted per square color
+    ///   for bishops, need as many missing pawns to promote from.
+    /// - pawns off their files need as many captured enemy pieces.
+    /// - the color not to move is not in check, and the color to move is in
+    ///   check at most twice, with a slider among two checkers.
+    /// - the kings and rooks with castling rights are on their squares, and
+    ///   a pawn has just moved past the en passant square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// assert!(Board::default().is_reachable());
+    ///
+    /// // white has two dark-squared bishops and all eight pawns
+    /// let bishops = "4k3/8/8/8/8/2B1B3/PPPPPPPP/4KB2 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(!bishops.is_reachable());
+    ///
+    /// // doubled pawns need a capture, but black has all its pieces
+    /// let pawns = "rnbqkbnr/pppppppp/8/8/8/4P3/PPPP1PPP/RNBQKBNR w KQkq - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(pawns.is_reachable());
+    ///
+    /// let doubled = "rnbqkbnr/pppppppp/8/8/8/4P3/PPPPP1PP/RNBQKBNR w KQkq - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(!doubled.is_reachable());
+    /// ```
+    pub fn is_reachable(&self) -> bool {
+        Color::ALL
+            .into_iter()
+            .all(|color| self.is_material_reachable(color) && self.is_castling_reachable(color))
+            && self.is_check_reachable()
+            && self.is_en_passant_reachable()
+    }
+
+    fn is_material_reachable(&self, color: Color) -> bool {
+        let count = |piece_type| self.piece_bitboard(Piece::new(piece_type, color)).len();
+        let pieces = self.color_bitboard(color);
+
+        let pawns = self.piece_bitboard(Piece::new(PieceType::Pawn, color));
+        let last_ranks = Rank::One.bitboard() | Rank::Eight.bitboard();
+
+        if count(PieceType::King) != 1 || pieces.len() > 16 || !(pawns & last_ranks).is_empty() {
+            return false;
+        }
+
+        let bishops = [Color::White, Color::Black]
+            .into_iter()
+            .map(|color_complex| {
+                (self.bishops_on(color_complex) & pieces)
+                    .len()
+                    .saturating_sub(1)
+            })
+            .sum::<usize>();
+
+        let promoted = count(PieceType::Queen).saturating_sub(1)
+            + count(PieceType::Rook).saturating_sub(2)
+            + count(PieceType::Knight).saturating_sub(2)
+            + bishops;
+
+        if pawns.len() + promoted > 8 {
+            return false;
+        }
+
+        let captured = 16 - self.color_bitboard(!color).len();
+
+        pawn_captures(pawns) <= captured
+    }
+
+    fn is_castling_reachable(&self, color: Color) -> bool {
+        let rights = self.castling_rights().0[color as usize] as usize;
+        let rank = match color {
+            Color::White => Rank::One,
+            Color::Black => Rank::Eight,
+        };
+
+        let on = |piece_type, file| {
+            self.get_piece(Square::with_file_rank(file, rank))
+                == Some(Piece::new(piece_type, color))
+        };
+
+        let sides = [
+            (CastleRightsType::KingSide as usize, File::H),
+            (CastleRightsType::QueenSide as usize, File::A),
+        ];
+
+        rights == 0
+            || on(PieceType::King, File::E)
+                && sides
+                    .into_iter()
+                    .all(|(side, file)| rights & side == 0 || on(PieceType::Rook, file))
+    }
+
+    fn is_check_reachable(&self) -> bool {
+        let attack_map = self.attack_map();
+        let king = |color| self.piece_bitboard(Piece::new(PieceType::King, color));
+
+        let checkers = |color: Color| {
+            king(color)
+                .into_iter()
+                .fold(BitBoard::EMPTY, |checkers, square| {
+                    checkers | attack_map.attackers(square, !color)
+                })
+        };
+
+        if !checkers(!self.color()).is_empty() {
+            return false;
+        }
+
+        let checkers = checkers(self.color());
+        let sliders = self.piece_types_bitboard(PieceType::Bishop)
+            | self.piece_types_bitboard(PieceType::Rook)
+            | self.piece_types_bitboard(PieceType::Queen);
+
+        match checkers.len() {
+            0 | 1 => true,
+            2 => !(checkers & sliders).is_empty(),
+            _ => false,
+        }
+    }
+
+    fn is_en_passant_reachable(&self) -> bool {
+        let Some(square) = self.en_passant_square() else {
+            return true;
+        };
+
+        let (rank, forward): (Rank, isize) = match self.color() {
+            Color::White => (Rank::Six, -8),
+            Color::Black => (Rank::Three, 8),
+        };
+
+        if square.rank() != rank {
+            return false;
+        }
+
+        let pawn = Square::new((square as isize + forward) as usize);
+        let origin = Square::new((square as isize - forward) as usize);
+
+        self.get_piece(pawn) == Some(Piece::new(PieceType::Pawn, !self.color()))
+            && self.get_piece(square).is_none()
+            && self.get_piece(origin).is_none()
+    }
+}
+
+/// Returns the fewest captures that bring pawns from their starting files to
+/// their files: every capture moves a pawn to the next file.
+fn pawn_captures(pawns: BitBoard) -> usize {
+    let mut files: Vec<usize> = pawns
+        .into_iter()
+        .map(|square| square.file() as usize)
+        .collect();
+    files.sort_unstable();
+
+    // captures[j] is the fewest captures for the pawns so far, from the
+    // first j starting files
+    let mut captures = vec![0usize; File::LEN + 1];
+
+    for (index, file) in files.into_iter().enumerate() {
+        let mut next = vec![usize::MAX; File::LEN + 1];
+
+        for start in index..File::LEN {
+            let captured = captures[start].saturating_add(file.abs_diff(start));
+            next[start + 1] = next[start].min(captured);
+        }
+
+        captures = next;
+    }
+
+    captures[File::LEN]
+}
@@ -1,6 +1,6 @@
-use chess_engine_core::{Color, Rank, Square};
+use chess_engine_core::{Color, Piece, Rank, Square};
 
-use crate::{CastleRights, ZOBRIST};
+use crate::{CastleRights, Zobrist, ZOBRIST};
 
 /// Represents the state of the chessboard.
 ///
@@ -20,6 +20,9 @@ pub struct State {
     halfmove_clock: u8,
     fullmove_counter: u16,
     hash: u64,
+    verification_key: u32,
+    irreversible: bool,
+    captured_piece: Option<Piece>,
 }
 
 /// Getters and setters for the `State` struct.
@@ -117,6 +120,58 @@ impl State {
         self.hash
     }
 
+    /// Returns the 32-bit verification key of the state, which tells apart
+    /// states whose hashes collide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let state = State::default();
+    /// assert_eq!(state.verification_key(), 0);
+    /// ```
+    pub fn verification_key(&self) -> u32 {
+        self.verification_key
+    }
+
+    /// Returns `true` if the state was reached by an irreversible move, so
+    /// no state before it can repeat: after a capture or a pawn move, which
+    /// reset the halfmove clock, or after a move that lost castling rights.
+    ///
+    /// Repetition detection stops looking back at the boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let mut state = State::default();
+    /// assert!(state.is_irreversible_boundary());
+    ///
+    /// state.set_halfmove_clock(3);
+    /// assert!(!state.is_irreversible_boundary());
+    ///
+    /// state.set_irreversible(true);
+    /// assert!(state.is_irreversible_boundary());
+    /// ```
+    pub fn is_irreversible_boundary(&self) -> bool {
+        self.halfmove_clock == 0 || self.irreversible
+    }
+
+    /// Returns the [`Piece`] captured by the move that reached the state, if
+    /// any, so [`Board::unmake_move`](crate::Board::unmake_move) can put it
+    /// back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let state = State::default();
+    /// assert_eq!(state.captured_piece(), None);
+    /// ```
+    pub fn captured_piece(&self) -> Option<Piece> {
+        self.captured_piece
+    }
+
     /// Sets the color to move.
     ///
     /// # Examples
@@ -216,6 +271,54 @@ impl State {
     pub fn set_hash(&mut self, hash: u64) {
         self.hash = hash;
     }
+
+    /// Sets the verification key of the state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let mut state = State::default();
+    /// state.set_verification_key(50);
+    /// assert_eq!(state.verification_key(), 50);
+    /// ```
+    pub fn set_verification_key(&mut self, verification_key: u32) {
+        self.verification_key = verification_key;
+    }
+
+    /// Sets whether the state was reached by an irreversible move that did
+    /// not reset the halfmove clock, like a move that lost castling rights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let mut state = State::default();
+    /// state.set_halfmove_clock(3);
+    /// state.set_irreversible(true);
+    /// assert!(state.is_irreversible_boundary());
+    /// ```
+    pub fn set_irreversible(&mut self, irreversible: bool) {
+        self.irreversible = irreversible;
+    }
+
+    /// Sets the piece captured by the move that reached the state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let mut state = State::default();
+    /// state.set_captured_piece(Some(Piece::new(PieceType::Knight, Color::Black)));
+    /// assert_eq!(
+    ///     state.captured_piece(),
+    ///     Some(Piece::new(PieceType::Knight, Color::Black))
+    /// );
+    /// ```
+    pub fn set_captured_piece(&mut self, captured_piece: Option<Piece>) {
+        self.captured_piece = captured_piece;
+    }
 }
 
 impl State {
@@ -233,7 +336,7 @@ impl State {
     ///     0,
     ///     1
     ///  );
-    pub fn new(
+    pub const fn new(
         color: Color,
         castling_rights: CastleRights,
         en_passant_square: Option<Square>,
@@ -247,6 +350,9 @@ impl State {
             halfmove_clock,
             fullmove_counter,
             hash: 0,
+            verification_key: 0,
+            irreversible: false,
+            captured_piece: None,
         }
     }
 
@@ -263,18 +369,23 @@ impl State {
     /// let hash = state.partial_hash();
     /// ```
     pub fn partial_hash(&self) -> u64 {
+        self.partial_hash_with(&ZOBRIST)
+    }
+
+    /// Returns the partial hash of the state with the keys of a [`Zobrist`].
+    pub(crate) fn partial_hash_with(&self, zobrist: &Zobrist) -> u64 {
         let mut hash: u64 = 0;
 
         if self.color == Color::White {
-            hash ^= ZOBRIST.color()
+            hash ^= zobrist.color()
         }
 
         for color in 0..Color::LEN {
-            hash ^= ZOBRIST.castling_rights(Color::new(color), self.castling_rights.0[color]);
+            hash ^= zobrist.castling_rights(Color::new(color), self.castling_rights.0[color]);
         }
 
         if let Some(en_passant_square) = self.en_passant_square {
-            hash ^= ZOBRIST.en_passant(en_passant_square);
+            hash ^= zobrist.en_passant(en_passant_square);
         }
 
         hash
@@ -301,6 +412,9 @@ impl Default for State {
             halfmove_clock: 0,
             fullmove_counter: 1,
             hash: 0,
+            verification_key: 0,
+            irreversible: false,
+            captured_piece: None,
         }
     }
 }
@@ -0,0 +1,838 @@
+//! Endgame tablebases for 3 and 4 pieces
+//!
+//! A [`Tablebase`] is generated by retrograde analysis: starting from the
+//! checkmates, it walks the moves backwards to find the distance to mate
+//! ([`Dtm`]) of every position of an [`Endgame`]. Captures and promotions
+//! lead to other endgames, which are generated first.
+//!
+//! Each [`Table`] stores one byte per position, with no symmetry reduction,
+//! and can be written to and read from disk. Castling and en passant are
+//! ignored.
+//!
+//! # Examples
+//!
+//! ```
+//! # use chess_engine_movegen::{tablebase::*, *};
+//! let mut tablebase = Tablebase::new();
+//! tablebase.generate(&"KQvK".parse().unwrap());
+//!
+//! let board = "k7/8/1K6/8/8/8/7Q/8 w - - 0 1"
+//!     .parse::<BoardBuilder>()
+//!     .unwrap()
+//!     .build();
+//!
+//! assert_eq!(tablebase.probe(&board), Some(Dtm::Win(1)));
+//!
+//! // the other side of the mate
+//! let board = "k6Q/8/1K6/8/8/8/8/8 b - - 0 1"
+//!     .parse::<BoardBuilder>()
+//!     .unwrap()
+//!     .build();
+//!
+//! assert_eq!(tablebase.probe(&board), Some(Dtm::Loss(0)));
+//! ```
+
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{self, Read, Write},
+    str::FromStr,
+};
+
+use chess_engine_core::{BitBoard, Color, Piece, PieceType, Rank, Square};
+use thiserror::Error;
+
+use crate::{get_attacks, get_pawn_attacks, Board};
+
+/// The maximum number of pieces of an [`Endgame`], kings included.
+pub const MAX_PIECES: usize = 4;
+
+/// The distance to mate of a position, in plies, for the color to move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dtm {
+    /// The color to move mates in the number of plies.
+    Win(u8),
+    /// The color to move is mated in the number of plies.
+    Loss(u8),
+    /// Neither color can force a mate.
+    Draw,
+}
+
+/// An error that can occur when parsing an [`Endgame`].
+#[derive(Error, Debug)]
+#[error("invalid endgame (expected up to 4 pieces like KQvK, got {0})")]
+pub struct EndgameError(pub String);
+
+/// Errors that can occur when reading a [`Table`]
+#[derive(Error, Debug)]
+pub enum TablebaseError {
+    /// Failed to read the table
+    #[error("{0}")]
+    Io(#[from] io::Error),
+
+    /// Invalid endgame of the table
+    #[error("{0}")]
+    Endgame(#[from] EndgameError),
+
+    /// The data is not a table
+    #[error("invalid table data")]
+    Format,
+}
+
+/// The pieces of an endgame, like `KQvK`.
+///
+/// The pieces are kept in a fixed order, White's first, from the king to
+/// the pawns.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_movegen::tablebase::*;
+/// let endgame: Endgame = "KvKRP".parse().unwrap();
+/// assert_eq!(endgame.to_string(), "KvKRP");
+/// assert_eq!(endgame.pieces().len(), 4);
+///
+/// assert!("KQQvKQ".parse::<Endgame>().is_err());
+/// assert!("KQvQ".parse::<Endgame>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Endgame {
+    pieces: Vec<Piece>,
+}
+
+/// Methods for the `Endgame` struct.
+impl Endgame {
+    /// Returns the pieces of the endgame, in order.
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    /// Returns the endgame of the pieces on the board, or `None` if there
+    /// are too many pieces, or a color doesn't have exactly one king.
+    pub fn from_board(board: &Board) -> Option<Self> {
+        position(board).map(|(endgame, _)| endgame)
+    }
+
+    /// Returns the endgames the moves of this one can lead to: one piece
+    /// less after a capture, or a pawn promoted.
+    fn dependencies(&self) -> Vec<Endgame> {
+        let mut dependencies = Vec::new();
+
+        for (index, piece) in self.pieces.iter().enumerate() {
+            if piece.piece_type() != PieceType::King {
+                let mut pieces = self.pieces.clone();
+                pieces.remove(index);
+                dependencies.push(Endgame::from_pieces(pieces));
+            }
+
+            if piece.piece_type() == PieceType::Pawn {
+                for promotion in PROMOTIONS {
+                    let mut pieces = self.pieces.clone();
+                    pieces[index] = Piece::new(promotion, piece.color());
+                    dependencies.push(Endgame::from_pieces(pieces));
+                }
+            }
+        }
+
+        dependencies
+    }
+
+    fn from_pieces(mut pieces: Vec<Piece>) -> Self {
+        pieces.sort_by_key(|piece| order(*piece));
+        Endgame { pieces }
+    }
+}
+
+/// Parses an `Endgame` from the pieces of each color, separated by `v`.
+///
+/// # Errors
+///
+/// Returns an [`EndgameError`] if a piece is invalid, a color doesn't have
+/// exactly one king, or there are more than [`MAX_PIECES`] pieces.
+impl FromStr for Endgame {
+    type Err = EndgameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || EndgameError(s.to_string());
+
+        let (white, black) = s.split_once('v').ok_or_else(error)?;
+        let mut pieces = Vec::new();
+
+        for (side, color) in [(white, Color::White), (black, Color::Black)] {
+            for c in side.chars() {
+                let piece_type: PieceType =
+                    c.to_lowercase().to_string().parse().map_err(|_| error())?;
+
+                pieces.push(Piece::new(piece_type, color));
+            }
+
+            let kings = side.chars().filter(|c| *c == 'K').count();
+
+            if kings != 1 || !side.chars().all(|c| c.is_ascii_uppercase()) {
+                return Err(error());
+            }
+        }
+
+        if pieces.len() > MAX_PIECES {
+            return Err(error());
+        }
+
+        Ok(Endgame::from_pieces(pieces))
+    }
+}
+
+/// Formats an `Endgame` as the pieces of each color, separated by `v`.
+impl fmt::Display for Endgame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for color in Color::ALL {
+            if color == Color::Black {
+                write!(f, "v")?;
+            }
+
+            for piece in self.pieces.iter().filter(|piece| piece.color() == color) {
+                write!(f, "{}", piece.piece_type().to_string().to_uppercase())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The distances to mate of every position of an [`Endgame`].
+#[derive(Clone, Debug)]
+pub struct Table {
+    endgame: Endgame,
+    dtm: Vec<u8>,
+}
+
+/// Methods for the `Table` struct.
+impl Table {
+    /// The bytes every table file starts with.
+    const MAGIC: &'static [u8; 4] = b"CETB";
+
+    /// Returns the [`Endgame`] of the table.
+    pub fn endgame(&self) -> &Endgame {
+        &self.endgame
+    }
+
+    /// Returns the distance to mate of the board, or `None` if the board is
+    /// not a legal position of the endgame of the table.
+    pub fn probe(&self, board: &Board) -> Option<Dtm> {
+        let (endgame, squares) = position(board)?;
+
+        if endgame != self.endgame || !is_valid(&endgame.pieces, &squares, board.color()) {
+            return None;
+        }
+
+        Some(self.dtm_at(index(&squares, board.color())))
+    }
+
+    /// Returns the memory used by the distances to mate of the table, in
+    /// bytes.
+    pub fn memory(&self) -> usize {
+        self.dtm.len()
+    }
+
+    /// Writes the table: a header with the endgame, then one byte per
+    /// position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::tablebase::*;
+    /// let mut tablebase = Tablebase::new();
+    /// let endgame = "KvK".parse().unwrap();
+    /// tablebase.generate(&endgame);
+    ///
+    /// let mut bytes = Vec::new();
+    /// tablebase.table(&endgame).unwrap().write(&mut bytes).unwrap();
+    ///
+    /// let table = Table::read(bytes.as_slice()).unwrap();
+    /// assert_eq!(table.endgame().to_string(), "KvK");
+    /// ```
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        let name = self.endgame.to_string();
+
+        writer.write_all(Self::MAGIC)?;
+        writer.write_all(&[name.len() as u8])?;
+        writer.write_all(name.as_bytes())?;
+        writer.write_all(&self.dtm)
+    }
+
+    /// Reads a table written with [`Table::write`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TablebaseError`] if the table can't be read or is
+    /// invalid.
+    pub fn read(mut reader: impl Read) -> Result<Self, TablebaseError> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+
+        if &magic != Self::MAGIC {
+            return Err(TablebaseError::Format);
+        }
+
+        let mut len = [0; 1];
+        reader.read_exact(&mut len)?;
+
+        let mut name = vec![0; len[0] as usize];
+        reader.read_exact(&mut name)?;
+
+        let endgame: Endgame = String::from_utf8(name)
+            .map_err(|_| TablebaseError::Format)?
+            .parse()?;
+
+        let mut dtm = Vec::new();
+        reader.read_to_end(&mut dtm)?;
+
+        if dtm.len() != table_len(endgame.pieces.len()) {
+            return Err(TablebaseError::Format);
+        }
+
+        Ok(Table { endgame, dtm })
+    }
+
+    fn dtm_at(&self, index: usize) -> Dtm {
+        match self.dtm[index] {
+            0 => Dtm::Draw,
+            value if (value - 1) % 2 == 0 => Dtm::Loss(value - 1),
+            value => Dtm::Win(value - 1),
+        }
+    }
+}
+
+/// A set of [`Table`]s, probed by the endgame of the board.
+#[derive(Clone, Debug, Default)]
+pub struct Tablebase {
+    tables: HashMap<String, Table>,
+}
+
+/// Methods for the `Tablebase` struct.
+impl Tablebase {
+    /// Creates an empty `Tablebase`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generates the table of the endgame, and of the endgames it leads to,
+    /// unless they are already in the tablebase.
+    ///
+    /// Tables of 4 pieces take up to a minute each, and hundreds of
+    /// megabytes of memory while they are generated.
+    pub fn generate(&mut self, endgame: &Endgame) {
+        if self.tables.contains_key(&endgame.to_string()) {
+            return;
+        }
+
+        for dependency in endgame.dependencies() {
+            self.generate(&dependency);
+        }
+
+        let table = generate(endgame, self);
+        self.insert(table);
+    }
+
+    /// Adds a table, replacing the one of the same endgame.
+    pub fn insert(&mut self, table: Table) {
+        self.tables.insert(table.endgame.to_string(), table);
+    }
+
+    /// Returns the table of the endgame, if it is in the tablebase.
+    pub fn table(&self, endgame: &Endgame) -> Option<&Table> {
+        self.tables.get(&endgame.to_string())
+    }
+
+    /// Returns the distance to mate of the board, or `None` if the table of
+    /// its endgame is not in the tablebase.
+    pub fn probe(&self, board: &Board) -> Option<Dtm> {
+        self.table(&Endgame::from_board(board)?)?.probe(board)
+    }
+
+    /// Returns the memory used by the tables of the tablebase, in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::tablebase::*;
+    /// let mut tablebase = Tablebase::new();
+    /// assert_eq!(tablebase.memory(), 0);
+    ///
+    /// tablebase.generate(&"KvK".parse().unwrap());
+    /// assert!(tablebase.memory() > 0);
+    /// ```
+    pub fn memory(&self) -> usize {
+        self.tables.values().map(Table::memory).sum()
+    }
+
+    /// Drops tables, the largest first, until the tablebase uses at most
+    /// `bytes` of memory. Dropped tables can be generated again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::tablebase::*;
+    /// let mut tablebase = Tablebase::new();
+    /// tablebase.generate(&"KQvK".parse().unwrap());
+    ///
+    /// tablebase.shrink_to(1 << 16);
+    /// assert!(tablebase.memory() <= 1 << 16);
+    /// assert!(tablebase.table(&"KvK".parse().unwrap()).is_some());
+    /// ```
+    pub fn shrink_to(&mut self, bytes: usize) {
+        let mut tables: Vec<(usize, String)> = self
+            .tables
+            .iter()
+            .map(|(name, table)| (table.memory(), name.clone()))
+            .collect();
+        tables.sort();
+
+        while self.memory() > bytes {
+            let Some((_, name)) = tables.pop() else {
+                break;
+            };
+
+            self.tables.remove(&name);
+        }
+    }
+}
+
+/// The pieces a pawn can promote to.
+const PROMOTIONS: [PieceType; 4] = [
+    PieceType::Queen,
+    PieceType::Rook,
+    PieceType::Bishop,
+    PieceType::Knight,
+];
+
+/// Distance of a position not known yet, while generating.
+const UNKNOWN: u8 = u8::MAX;
+
+/// Distance of a drawn or invalid position, while generating.
+const DRAW: u8 = u8::MAX - 1;
+
+/// A move of a position: inside the endgame, only counted, or to a
+/// position of another endgame.
+enum Successor {
+    Inside,
+    Outside(Endgame, usize),
+}
+
+/// A step of the retrograde analysis, happening at a number of plies.
+enum Event {
+    /// The position has a move to a position lost at the previous ply.
+    Win(usize),
+    /// A move of the position leads to a position won at this ply.
+    Refute(usize),
+    /// The position was solved at this ply.
+    Solved(usize),
+}
+
+/// Returns the position in the order of the pieces of an endgame.
+fn order(piece: Piece) -> usize {
+    piece.color() as usize * PieceType::LEN + PieceType::King as usize - piece.piece_type() as usize
+}
+
+/// Returns the endgame and squares of the pieces of the board.
+fn position(board: &Board) -> Option<(Endgame, Vec<Square>)> {
+    let mut pieces = Vec::new();
+
+    for piece in Piece::ALL {
+        for square in board.piece_bitboard(piece) {
+            pieces.push((piece, square));
+        }
+    }
+
+    if pieces.len() > MAX_PIECES {
+        return None;
+    }
+
+    for color in Color::ALL {
+        let king = Piece::new(PieceType::King, color);
+
+        if pieces.iter().filter(|(piece, _)| *piece == king).count() != 1 {
+            return None;
+        }
+    }
+
+    pieces.sort_by_key(|(piece, _)| order(*piece));
+    let (pieces, squares) = pieces.into_iter().unzip();
+
+    Some((Endgame { pieces }, squares))
+}
+
+/// Returns the number of positions of a table of `n` pieces.
+fn table_len(n: usize) -> usize {
+    2 << (6 * n)
+}
+
+fn index(squares: &[Square], color: Color) -> usize {
+    let squares = squares
+        .iter()
+        .rev()
+        .fold(0, |index, square| index << 6 | *square as usize);
+
+    squares << 1 | color as usize
+}
+
+fn decode(index: usize, n: usize) -> ([Square; MAX_PIECES], Color) {
+    let mut squares = [Square::A1; MAX_PIECES];
+    let mut rest = index >> 1;
+
+    for square in squares.iter_mut().take(n) {
+        *square = Square::new(rest & 63);
+        rest >>= 6;
+    }
+
+    (squares, Color::new(index & 1))
+}
+
+fn occupancy(squares: &[Square]) -> BitBoard {
+    squares.iter().fold(BitBoard::EMPTY, |occupied, square| {
+        occupied | square.bitboard()
+    })
+}
+
+fn king(pieces: &[Piece], squares: &[Square], color: Color) -> Square {
+    let king = Piece::new(PieceType::King, color);
+    let index = pieces.iter().position(|piece| *piece == king);
+
+    squares[index.expect("every endgame has both kings")]
+}
+
+fn is_attacked(pieces: &[Piece], squares: &[Square], target: Square, color: Color) -> bool {
+    let occupied = occupancy(squares);
+
+    pieces.iter().zip(squares).any(|(piece, square)| {
+        piece.color() == color
+            && *square != target
+            && get_attacks(*piece, *square, occupied).is_get_square(target)
+    })
+}
+
+/// Returns `true` if no two pieces share a square, no pawn is on the first
+/// or last rank, and the color that is not to move is not in check.
+fn is_valid(pieces: &[Piece], squares: &[Square], color: Color) -> bool {
+    for (index, (piece, square)) in pieces.iter().zip(squares).enumerate() {
+        if piece.piece_type() == PieceType::Pawn
+            && (square.rank() == Rank::One || square.rank() == Rank::Eight)
+        {
+            return false;
+        }
+
+        if squares[index + 1..].contains(square) {
+            return false;
+        }
+    }
+
+    !is_attacked(pieces, squares, king(pieces, squares, !color), color)
+}
+
+fn pawn_pushes(color: Color, from: Square, occupied: BitBoard) -> BitBoard {
+    let (forward, start): (isize, Rank) = match color {
+        Color::White => (8, Rank::Two),
+        Color::Black => (-8, Rank::Seven),
+    };
+
+    let single = Square::new((from as isize + forward) as usize);
+
+    if occupied.is_get_square(single) {
+        return BitBoard::EMPTY;
+    }
+
+    if from.rank() != start {
+        return single.bitboard();
+    }
+
+    let double = Square::new((single as isize + forward) as usize);
+
+    if occupied.is_get_square(double) {
+        single.bitboard()
+    } else {
+        single.bitboard() | double.bitboard()
+    }
+}
+
+/// Returns the squares a pawn of the color can have been pushed to the
+/// square from.
+fn pawn_unpushes(color: Color, to: Square, occupied: BitBoard) -> BitBoard {
+    let (backward, start, double) = match color {
+        Color::White => (-8, Rank::Two, Rank::Four),
+        Color::Black => (8, Rank::Seven, Rank::Five),
+    };
+
+    if to.rank() == start {
+        return BitBoard::EMPTY;
+    }
+
+    let single = Square::new((to as isize + backward) as usize);
+
+    if occupied.is_get_square(single) {
+        return BitBoard::EMPTY;
+    }
+
+    if to.rank() != double {
+        return single.bitboard();
+    }
+
+    let origin = Square::new((single as isize + backward) as usize);
+
+    if occupied.is_get_square(origin) {
+        single.bitboard()
+    } else {
+        single.bitboard() | origin.bitboard()
+    }
+}
+
+/// Calls `f` with the legal moves of the position.
+fn for_each_move(pieces: &[Piece], squares: &[Square], color: Color, mut f: impl FnMut(Successor)) {
+    let n = pieces.len();
+    let occupied = occupancy(squares);
+
+    for (index, piece) in pieces.iter().enumerate() {
+        if piece.color() != color {
+            continue;
+        }
+
+        let from = squares[index];
+        let is_pawn = piece.piece_type() == PieceType::Pawn;
+
+        let targets = if is_pawn {
+            pawn_pushes(color, from, occupied) | (get_pawn_attacks(color, from) & occupied)
+        } else {
+            get_attacks(*piece, from, occupied)
+        };
+
+        for to in targets {
+            let captured = squares.iter().position(|square| *square == to);
+
+            if captured.is_some_and(|captured| {
+                pieces[captured].color() == color
+                    || pieces[captured].piece_type() == PieceType::King
+            }) {
+                continue;
+            }
+
+            let last_rank = match color {
+                Color::White => Rank::Eight,
+                Color::Black => Rank::One,
+            };
+
+            if is_pawn && to.rank() == last_rank {
+                for promotion in PROMOTIONS {
+                    let piece = Piece::new(promotion, color);
+
+                    if let Some(successor) = outside(pieces, squares, color, index, piece, to) {
+                        f(successor);
+                    }
+                }
+            } else if captured.is_some() {
+                if let Some(successor) = outside(pieces, squares, color, index, *piece, to) {
+                    f(successor);
+                }
+            } else {
+                let mut next = [Square::A1; MAX_PIECES];
+                next[..n].copy_from_slice(squares);
+                next[index] = to;
+
+                if is_valid(pieces, &next[..n], !color) {
+                    f(Successor::Inside);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the move of the piece at `index` to `to`, becoming `piece`, if it
+/// is legal. The move captures or promotes, so it leads to another endgame.
+fn outside(
+    pieces: &[Piece],
+    squares: &[Square],
+    color: Color,
+    index: usize,
+    piece: Piece,
+    to: Square,
+) -> Option<Successor> {
+    let mut next: Vec<(Piece, Square)> = pieces
+        .iter()
+        .copied()
+        .zip(squares.iter().copied())
+        .enumerate()
+        .filter(|(_, (_, square))| *square != to)
+        .map(|(other, (other_piece, square))| {
+            if other == index {
+                (piece, to)
+            } else {
+                (other_piece, square)
+            }
+        })
+        .collect();
+
+    next.sort_by_key(|(piece, _)| order(*piece));
+    let (pieces, squares): (Vec<Piece>, Vec<Square>) = next.into_iter().unzip();
+
+    if !is_valid(&pieces, &squares, !color) {
+        return None;
+    }
+
+    let index = self::index(&squares, !color);
+    Some(Successor::Outside(Endgame { pieces }, index))
+}
+
+/// Calls `f` with the positions whose moves lead to the position without a
+/// capture or a promotion.
+fn for_each_unmove(pieces: &[Piece], squares: &[Square], color: Color, mut f: impl FnMut(usize)) {
+    let n = pieces.len();
+    let mover = !color;
+    let occupied = occupancy(squares);
+
+    for (index, piece) in pieces.iter().enumerate() {
+        if piece.color() != mover {
+            continue;
+        }
+
+        let to = squares[index];
+
+        let origins = if piece.piece_type() == PieceType::Pawn {
+            pawn_unpushes(mover, to, occupied)
+        } else {
+            get_attacks(*piece, to, occupied) & !occupied
+        };
+
+        for from in origins {
+            let mut previous = [Square::A1; MAX_PIECES];
+            previous[..n].copy_from_slice(squares);
+            previous[index] = from;
+
+            if is_valid(pieces, &previous[..n], mover) {
+                f(self::index(&previous[..n], mover));
+            }
+        }
+    }
+}
+
+fn push(stages: &mut Vec<Vec<Event>>, stage: usize, event: Event) {
+    if stages.len() <= stage {
+        stages.resize_with(stage + 1, Vec::new);
+    }
+
+    stages[stage].push(event);
+}
+
+/// Generates the table of the endgame by retrograde analysis, with the
+/// tables of its dependencies in the tablebase.
+///
+/// Positions are solved ply by ply. A position is won at ply `n + 1` if one
+/// of its moves leads to a position lost at ply `n`, and lost at ply `n + 1`
+/// once every one of its moves leads to a position won by ply `n`. Moves are
+/// counted per position, and the count goes down as its moves are refuted.
+fn generate(endgame: &Endgame, tablebase: &Tablebase) -> Table {
+    let pieces = &endgame.pieces;
+    let n = pieces.len();
+    let len = table_len(n);
+
+    let mut dtm = vec![UNKNOWN; len];
+    let mut moves = vec![0u8; len];
+    let mut stages = Vec::new();
+
+    for position in 0..len {
+        let (squares, color) = decode(position, n);
+        let squares = &squares[..n];
+
+        if !is_valid(pieces, squares, color) {
+            dtm[position] = DRAW;
+            continue;
+        }
+
+        let mut count = 0;
+
+        for_each_move(pieces, squares, color, |successor| {
+            count += 1;
+
+            if let Successor::Outside(endgame, index) = successor {
+                let table = tablebase
+                    .table(&endgame)
+                    .expect("dependencies are generated first");
+
+                match table.dtm_at(index) {
+                    Dtm::Loss(plies) => push(&mut stages, plies as usize + 1, Event::Win(position)),
+                    Dtm::Win(plies) => push(&mut stages, plies as usize, Event::Refute(position)),
+                    Dtm::Draw => {}
+                }
+            }
+        });
+
+        if count == 0 {
+            if is_attacked(pieces, squares, king(pieces, squares, color), !color) {
+                dtm[position] = 0;
+                push(&mut stages, 0, Event::Solved(position));
+            } else {
+                dtm[position] = DRAW;
+            }
+        }
+
+        moves[position] = count;
+    }
+
+    let mut stage = 0;
+
+    while stage < stages.len() {
+        let plies = u8::try_from(stage).expect("distances to mate fit in a byte");
+        let mut solved = Vec::new();
+
+        let mut refute = |position: usize, dtm: &mut [u8], stages: &mut Vec<Vec<Event>>| {
+            if dtm[position] == UNKNOWN {
+                moves[position] -= 1;
+
+                if moves[position] == 0 {
+                    dtm[position] = plies + 1;
+                    push(stages, stage + 1, Event::Solved(position));
+                }
+            }
+        };
+
+        for event in std::mem::take(&mut stages[stage]) {
+            match event {
+                Event::Win(position) => {
+                    if dtm[position] == UNKNOWN {
+                        dtm[position] = plies;
+                        solved.push(position);
+                    }
+                }
+                Event::Refute(position) => refute(position, &mut dtm, &mut stages),
+                Event::Solved(position) => solved.push(position),
+            }
+        }
+
+        for position in solved {
+            let (squares, color) = decode(position, n);
+
+            for_each_unmove(pieces, &squares[..n], color, |previous| {
+                if dtm[previous] != UNKNOWN {
+                    return;
+                }
+
+                if plies % 2 == 0 {
+                    push(&mut stages, stage + 1, Event::Win(previous));
+                } else {
+                    refute(previous, &mut dtm, &mut stages);
+                }
+            });
+        }
+
+        stage += 1;
+    }
+
+    let dtm = dtm
+        .into_iter()
+        .map(|plies| match plies {
+            UNKNOWN | DRAW => 0,
+            plies => plies + 1,
+        })
+        .collect();
+
+    Table {
+        endgame: endgame.clone(),
+        dtm,
+    }
+}
@@ -0,0 +1,232 @@
+use chess_engine_core::{BitBoard, Color, Move, Piece, PieceType, Square};
+
+use crate::{eval::Material, get_attacks, get_pawn_attacks, Board};
+
+/// Tactical shortcuts for the `Board` struct, answered with move generation
+/// alone, without a search.
+impl Board {
+    /// Returns `true` if the king of the color to move is attacked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// let board = "4k3/8/8/8/8/8/8/4K2r w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(board.is_check());
+    /// assert!(!Board::default().is_check());
+    /// ```
+    pub fn is_check(&self) -> bool {
+        self.piece_bitboard(Piece::new(PieceType::King, self.color()))
+            .into_iter()
+            .any(|king| self.is_square_attcked(king, !self.color()))
+    }
+
+    /// Returns `true` if the move attacks the king of the opponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/8/8/8/8/8/R3K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(board.gives_check(Move::new(Square::A1, Square::A8, None)));
+    /// assert!(!board.gives_check(Move::new(Square::A1, Square::A2, None)));
+    /// ```
+    pub fn gives_check(&self, mv: Move) -> bool {
+        self.after(mv).is_check()
+    }
+
+    /// Returns the material the color to move wins with a capture, in
+    /// centipawns, if both colors keep recapturing on the destination with
+    /// their least valuable piece, and stop when it loses material (static
+    /// exchange evaluation).
+    ///
+    /// Pins are not taken into account.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/2p5/3p4/8/8/3R4/3Q4/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// // the pawn on d6 is defended, the rook is lost for two pawns
+    /// assert_eq!(board.see(Move::new(Square::D3, Square::D6, None)), -300);
+    ///
+    /// let board = "4k3/8/3p4/8/8/3R4/3Q4/4K3 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(board.see(Move::new(Square::D3, Square::D6, None)), 100);
+    /// ```
+    pub fn see(&self, mv: Move) -> i32 {
+        let (from, to) = (mv.from(), mv.to());
+        let Some(piece) = self.get_piece(from) else {
+            return 0;
+        };
+
+        let mut occupancy = self.both_bitboard().unset_square(from);
+        if self.is_en_passant(mv) {
+            occupancy = occupancy.unset_square(Square::with_file_rank(to.file(), from.rank()));
+        }
+
+        let captured = self
+            .captured_piece(mv)
+            .map_or(0, |captured| Material::value(captured.piece_type()));
+        let (mut gains, mut attacker) = match mv.promotion() {
+            Some(promotion) => (
+                vec![captured + Material::value(promotion) - Material::value(PieceType::Pawn)],
+                Material::value(promotion),
+            ),
+            None => (vec![captured], Material::value(piece.piece_type())),
+        };
+
+        let mut color = !self.color();
+
+        while let Some((square, piece_type)) = self.least_valuable_attacker(to, color, occupancy) {
+            // the king can't recapture a defended piece
+            if piece_type == PieceType::King
+                && self
+                    .least_valuable_attacker(to, !color, occupancy.unset_square(square))
+                    .is_some()
+            {
+                break;
+            }
+
+            gains.push(attacker - gains[gains.len() - 1]);
+
+            occupancy = occupancy.unset_square(square);
+            attacker = Material::value(piece_type);
+            color = !color;
+        }
+
+        while gains.len() > 1 {
+            let gain = gains.pop().expect("more than one gain");
+            let last = gains.len() - 1;
+            gains[last] = -(-gains[last]).max(gain);
+        }
+
+        gains[0]
+    }
+
+    /// Returns the legal captures that win material by
+    /// [static exchange evaluation](Board::see), the most winning first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "4k3/8/2p5/3n4/8/3R4/8/4K2B w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// // after cxd5 Rxd5, Bxd5 wins a knight and a pawn for the bishop, and
+    /// // Rxd5 loses the exchange
+    /// assert_eq!(board.winning_captures(), vec![Move::new(Square::H1, Square::D5, None)]);
+    ///
+    /// let board = "4k3/8/8/3n4/8/3R4/8/4K2B w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(board.winning_captures().len(), 2);
+    /// ```
+    pub fn winning_captures(&self) -> Vec<Move> {
+        let mut captures: Vec<(Move, i32)> = self
+            .generate_legal_moves()
+            .into_iter()
+            .filter(|&mv| self.is_capture(mv))
+            .map(|mv| (mv, self.see(mv)))
+            .filter(|&(_, see)| see > 0)
+            .collect();
+
+        captures.sort_by_key(|&(_, see)| std::cmp::Reverse(see));
+        captures.into_iter().map(|(mv, _)| mv).collect()
+    }
+
+    /// Returns a move that checkmates the opponent, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// # use chess_engine_core::*;
+    /// let board = "6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(board.mate_in_one(), Some(Move::new(Square::A1, Square::A8, None)));
+    /// assert_eq!(Board::default().mate_in_one(), None);
+    /// ```
+    pub fn mate_in_one(&self) -> Option<Move> {
+        self.generate_legal_moves().into_iter().find(|&mv| {
+            let board = self.after(mv);
+            board.is_check() && board.generate_legal_moves().is_empty()
+        })
+    }
+
+    /// Returns `true` if the color to move can checkmate in one move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_movegen::*;
+    /// // the queen mates on a8
+    /// let board = "7k/8/6K1/8/8/8/8/Q7 w - - 0 1"
+    ///     .parse::<BoardBuilder>()
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert!(board.has_mate_in_one());
+    /// ```
+    pub fn has_mate_in_one(&self) -> bool {
+        self.mate_in_one().is_some()
+    }
+
+    /// Returns the least valuable piece of a color attacking a square, with
+    /// only the pieces of the occupancy on the board.
+    fn least_valuable_attacker(
+        &self,
+        square: Square,
+        color: Color,
+        occupancy: BitBoard,
+    ) -> Option<(Square, PieceType)> {
+        PieceType::ALL.into_iter().find_map(|piece_type| {
+            let piece = Piece::new(piece_type, color);
+            let attacks = match piece_type {
+                PieceType::Pawn => get_pawn_attacks(!color, square),
+                _ => get_attacks(piece, square, occupancy),
+            };
+
+            (attacks & self.piece_bitboard(piece) & occupancy)
+                .into_iter()
+                .next()
+                .map(|attacker| (attacker, piece_type))
+        })
+    }
+
+    /// Returns the board after a move, with the opponent to move.
+    pub(crate) fn after(&self, mv: Move) -> Board {
+        let mut board = self.clone();
+        if self.get_piece(mv.from()).is_some() {
+            board.make_move(mv);
+        }
+
+        board
+    }
+}
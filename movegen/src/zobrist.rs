@@ -2,8 +2,12 @@ use chess_engine_core::{CastleRightsType, Color, File, Piece, PieceType, Rank, S
 use once_cell::sync::Lazy;
 use rand::prelude::*;
 
-/// A lazy const [`Zobrist`] instance.
-pub const ZOBRIST: Lazy<Zobrist> = Lazy::new(|| Zobrist::new());
+/// A lazy static [`Zobrist`] instance.
+pub static ZOBRIST: Lazy<Zobrist> = Lazy::new(Zobrist::new);
+
+/// A second lazy static [`Zobrist`] instance, independent from [`ZOBRIST`],
+/// for the verification keys of boards.
+pub static VERIFICATION_ZOBRIST: Lazy<Zobrist> = Lazy::new(Zobrist::new);
 
 /// A Zobrist hash for chess.
 ///
@@ -32,9 +36,10 @@ impl Zobrist {
     /// ```
     pub fn new() -> Self {
         let mut rng = rand::thread_rng();
-        let mut zobrist = Zobrist::default();
-
-        zobrist.color = rng.gen();
+        let mut zobrist = Zobrist {
+            color: rng.gen(),
+            ..Default::default()
+        };
 
         for square in 0..Square::LEN {
             for color in 0..Color::LEN {
@@ -100,7 +105,7 @@ impl Zobrist {
         assert!(en_passant_square.rank() == Rank::Three || en_passant_square.rank() == Rank::Six);
 
         self.en_passant[(en_passant_square.rank() == Rank::Three) as usize]
-            [en_passant_square.rank() as usize]
+            [en_passant_square.file() as usize]
     }
 
     /// Returns the hash for castling rights for a [`Color`] and [`CastleRightsType`].
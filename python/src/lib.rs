@@ -0,0 +1,174 @@
+#![deny(missing_docs)]
+#![doc = include_str!("../README.md")]
+
+//! Python bindings for the chess engine.
+
+use chess_engine_core::Square;
+use chess_engine_movegen::{notation::Notation, BoardBuilder};
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+/// The FEN string of the starting position.
+const STARTING_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+/// A move from one square to another, with an optional promotion.
+#[pyclass(module = "chess_engine_py", frozen)]
+#[derive(Clone, Copy)]
+pub struct Move(chess_engine_core::Move);
+
+#[pymethods]
+impl Move {
+    /// Parses a move from UCI notation, like `Move.from_uci("e7e8q")`.
+    #[staticmethod]
+    fn from_uci(uci: &str) -> PyResult<Self> {
+        uci.parse()
+            .map(Move)
+            .map_err(|_| PyValueError::new_err(format!("invalid uci: {:?}", uci)))
+    }
+
+    /// Returns the move in UCI notation.
+    fn uci(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __str__(&self) -> String {
+        self.uci()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Move.from_uci('{}')", self.uci())
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.uci() == other.uci()
+    }
+
+    fn __hash__(&self) -> u64 {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        self.uci().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// A chessboard, created from a FEN string or the starting position.
+#[pyclass(module = "chess_engine_py")]
+pub struct Board(chess_engine_movegen::Board);
+
+#[pymethods]
+impl Board {
+    #[new]
+    #[pyo3(signature = (fen = STARTING_FEN))]
+    fn new(fen: &str) -> PyResult<Self> {
+        Ok(Board(parse_fen(fen)?))
+    }
+
+    /// Returns the FEN string of the position.
+    fn fen(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Sets the position from a FEN string.
+    fn set_fen(&mut self, fen: &str) -> PyResult<()> {
+        self.0 = parse_fen(fen)?;
+        Ok(())
+    }
+
+    /// The side to move, `True` for white and `False` for black.
+    #[getter]
+    fn turn(&self) -> bool {
+        self.0.color() == chess_engine_core::Color::White
+    }
+
+    /// The moves of the position, without checking if they leave the king in
+    /// check.
+    #[getter]
+    fn pseudo_legal_moves(&self) -> Vec<Move> {
+        self.0.generate_moves().into_iter().map(Move).collect()
+    }
+
+    /// The legal moves of the position.
+    #[getter]
+    fn legal_moves(&self) -> Vec<Move> {
+        self.0
+            .generate_legal_moves()
+            .into_iter()
+            .map(Move)
+            .collect()
+    }
+
+    /// Returns `True` if the side to move is in check.
+    fn is_check(&self) -> bool {
+        self.0.is_check()
+    }
+
+    /// Plays a legal move, raising `ValueError` if it is illegal.
+    fn push(&mut self, mv: Move) -> PyResult<()> {
+        if !self.0.generate_legal_moves().contains(&mv.0) {
+            return Err(PyValueError::new_err(format!("illegal move: {}", mv.0)));
+        }
+
+        self.0.make_move(mv.0);
+        Ok(())
+    }
+
+    /// Plays a legal move in UCI notation, and returns it.
+    fn push_uci(&mut self, uci: &str) -> PyResult<Move> {
+        let mv = Move::from_uci(uci)?;
+        self.push(mv)?;
+        Ok(mv)
+    }
+
+    /// Plays a legal move in SAN, like `Nf3`, and returns it.
+    fn push_san(&mut self, san: &str) -> PyResult<Move> {
+        let mv = self
+            .0
+            .parse_move(san, Notation::San)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        self.0.make_move(mv);
+        Ok(Move(mv))
+    }
+
+    /// Returns a legal move in SAN, with its check or mate suffix.
+    fn san(&self, mv: Move) -> PyResult<String> {
+        if !self.0.generate_legal_moves().contains(&mv.0) {
+            return Err(PyValueError::new_err(format!("illegal move: {}", mv.0)));
+        }
+
+        Ok(self.0.format_move(mv.0, Notation::San))
+    }
+
+    /// Returns the symbol of the piece on a square (0 = a1, 63 = h8), or
+    /// `None` if the square is empty.
+    fn piece_at(&self, square: usize) -> PyResult<Option<String>> {
+        let square =
+            Square::try_new(square).map_err(|error| PyValueError::new_err(error.to_string()))?;
+
+        Ok(self.0.get_piece(square).map(|piece| piece.to_string()))
+    }
+
+    fn __str__(&self) -> String {
+        self.fen()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Board('{}')", self.fen())
+    }
+}
+
+/// Parses a board from a FEN string, raising `ValueError` if it is invalid.
+fn parse_fen(fen: &str) -> PyResult<chess_engine_movegen::Board> {
+    fen.parse::<BoardBuilder>()
+        .map(BoardBuilder::build)
+        .map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+#[pymodule]
+fn chess_engine_py(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add("STARTING_FEN", STARTING_FEN)?;
+    module.add_class::<Board>()?;
+    module.add_class::<Move>()?;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+import pytest
+
+import chess_engine_py
+from chess_engine_py import Board, Move
+
+
+def test_starting_position():
+    board = Board()
+
+    assert board.fen() == chess_engine_py.STARTING_FEN
+    assert board.turn
+    assert len(board.legal_moves) == 20
+
+
+def test_move_from_uci():
+    assert Move.from_uci("e7e8q").uci() == "e7e8q"
+    assert Move.from_uci("e2e4") == Move.from_uci("e2e4")
+
+    for uci in ["e7e8k", "e7e8p", "e2e9", "e2"]:
+        with pytest.raises(ValueError):
+            Move.from_uci(uci)
+
+
+def test_push():
+    board = Board()
+    board.push(Move.from_uci("e2e4"))
+    board.push_san("e5")
+    board.push_uci("g1f3")
+
+    assert board.fen() == "rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2"
+    assert not board.turn
+
+    with pytest.raises(ValueError):
+        board.push_uci("e5e4")
+
+
+def test_pinned_piece_has_no_legal_moves():
+    board = Board("4k3/4r3/8/8/8/8/4N3/4K3 w - - 0 1")
+
+    assert all(move.uci()[:2] != "e2" for move in board.legal_moves)
+    assert any(move.uci()[:2] == "e2" for move in board.pseudo_legal_moves)
+
+
+def test_fools_mate():
+    board = Board()
+
+    for san in ["f3", "e5", "g4"]:
+        board.push_san(san)
+
+    assert board.san(Move.from_uci("d8h4")) == "Qh4#"
+    board.push_uci("d8h4")
+
+    assert board.is_check()
+    assert board.legal_moves == []
@@ -0,0 +1,52 @@
+#![deny(missing_docs)]
+#![doc = include_str!("../README.md")]
+
+//! Single entry point to the chess engine crates.
+//!
+//! The board types of `chess-engine-core` are always available. Move
+//! generation and the UCI client are behind the `movegen` and `uci`
+//! features.
+
+pub mod prelude;
+
+pub use chess_engine_core::*;
+
+/// Move generation, from `chess-engine-movegen`.
+#[cfg(feature = "movegen")]
+pub use chess_engine_movegen as movegen;
+
+/// UCI client for external engines, from `chess-engine-uci`.
+#[cfg(feature = "uci")]
+pub use chess_engine_uci as uci;
+
+/// Scores a board with the [`Material`] evaluator, as an [`Evaluation`]
+/// from White's point of view, the convention of PGN annotations.
+///
+/// The evaluation doesn't search, so it never finds a mate and is always
+/// [`Evaluation::Centipawns`].
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine::{movegen::*, *};
+/// let board = "4k3/8/8/8/8/8/8/3QK3 b - - 0 1"
+///     .parse::<BoardBuilder>()
+///     .unwrap()
+///     .build();
+///
+/// assert_eq!(chess_engine::evaluate(&board), Evaluation::Centipawns(900));
+/// assert_eq!(chess_engine::evaluate(&Board::default()), Evaluation::Centipawns(0));
+/// ```
+///
+/// [`Material`]: chess_engine_movegen::eval::Material
+#[cfg(feature = "movegen")]
+pub fn evaluate(board: &chess_engine_movegen::Board) -> Evaluation {
+    use chess_engine_movegen::eval::{Evaluator, Material};
+
+    let score = Material.evaluate(board);
+
+    Evaluation::Centipawns(match board.color() {
+        Color::White => score,
+        Color::Black => -score,
+    })
+}
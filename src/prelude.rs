@@ -0,0 +1,18 @@
+//! The types most users need, in one import.
+//!
+//! # Examples
+//!
+//! ```
+//! use chess_engine::prelude::*;
+//!
+//! let board = Board::default();
+//! let mv = Move::new(Square::E2, Square::E4, None);
+//!
+//! assert!(board.generate_moves().contains(&mv));
+//! assert_eq!(board.get_piece(Square::E2), Some(Piece::new(PieceType::Pawn, Color::White)));
+//! ```
+
+pub use chess_engine_core::{bitboard, BitBoard, Color, Move, Piece, PieceType, Square};
+
+#[cfg(feature = "movegen")]
+pub use chess_engine_movegen::{board, fen, Board, BoardBuilder};
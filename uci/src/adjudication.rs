@@ -0,0 +1,180 @@
+use chess_engine_core::{Color, GameResult, PieceType};
+use chess_engine_movegen::{kpk, Board};
+
+use crate::Score;
+
+/// Rules to end self-play games whose result is already decided.
+///
+/// Rules left as `None` or `false` are disabled, which is the default. Scores
+/// are in centipawns, and a number of moves counts the moves of each engine,
+/// so both engines have to agree on the score.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_uci::*;
+/// let rules = AdjudicationRules {
+///     resign_score: Some(1000),
+///     resign_moves: 3,
+///     draw_score: Some(10),
+///     draw_moves: 8,
+///     draw_move_number: 40,
+///     tablebase: true,
+/// };
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AdjudicationRules {
+    /// A side loses when both engines see it below minus this score.
+    pub resign_score: Option<i32>,
+    /// Number of consecutive moves the resign score has to hold.
+    pub resign_moves: u32,
+    /// The game is drawn when both engines see it within this score of 0.
+    pub draw_score: Option<i32>,
+    /// Number of consecutive moves the draw score has to hold.
+    pub draw_moves: u32,
+    /// First move number from which the game can be drawn by score.
+    pub draw_move_number: u16,
+    /// End the game with the result of the bitbase when there is one.
+    pub tablebase: bool,
+}
+
+/// Adjudicates a self-play game from the scores reported by the engines.
+///
+/// The `Adjudicator` is told about every move of the game with
+/// [`Adjudicator::update`], and answers with a [`GameResult`] once the game
+/// is decided by its [`AdjudicationRules`].
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_uci::*;
+/// # use chess_engine_movegen::*;
+/// # use chess_engine_core::*;
+/// let mut adjudicator = Adjudicator::new(AdjudicationRules {
+///     resign_score: Some(500),
+///     resign_moves: 1,
+///     ..Default::default()
+/// });
+///
+/// let white = "4k3/8/8/8/8/8/8/3QK3 w - - 0 1".parse::<BoardBuilder>().unwrap().build();
+/// let black = "4k3/8/8/8/8/8/8/3QK3 b - - 0 1".parse::<BoardBuilder>().unwrap().build();
+///
+/// assert_eq!(adjudicator.update(&white, Some(Score::Centipawns(900))), None);
+/// assert_eq!(
+///     adjudicator.update(&black, Some(Score::Mate(-7))),
+///     Some(GameResult::WhiteWins)
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Adjudicator {
+    rules: AdjudicationRules,
+    resign_plies: [u32; Color::LEN],
+    draw_plies: u32,
+}
+
+/// Methods for the `Adjudicator` struct.
+impl Adjudicator {
+    /// Creates a new `Adjudicator` for a game with the given rules.
+    pub fn new(rules: AdjudicationRules) -> Self {
+        Adjudicator {
+            rules,
+            resign_plies: [0; Color::LEN],
+            draw_plies: 0,
+        }
+    }
+
+    /// Returns the [`AdjudicationRules`] of the `Adjudicator`.
+    pub fn rules(&self) -> &AdjudicationRules {
+        &self.rules
+    }
+
+    /// Forgets the scores seen so far, to adjudicate a new game.
+    pub fn reset(&mut self) {
+        self.resign_plies = [0; Color::LEN];
+        self.draw_plies = 0;
+    }
+
+    /// Records the score reported by the engine to move on the board, and
+    /// returns the [`GameResult`] if the game is decided.
+    ///
+    /// The score is from the point of view of the side to move, as sent in
+    /// [`Info`](crate::Info) lines. A move without a score breaks the
+    /// streaks of the resign and draw rules.
+    pub fn update(&mut self, board: &Board, score: Option<Score>) -> Option<GameResult> {
+        if self.rules.tablebase {
+            if let Some(result) = tablebase_result(board) {
+                return Some(result);
+            }
+        }
+
+        let white_score = score.map(|score| {
+            let score = match score {
+                Score::Centipawns(centipawns) => centipawns,
+                Score::Mate(moves) if moves > 0 => i32::MAX,
+                Score::Mate(_) => -i32::MAX,
+            };
+
+            match board.color() {
+                Color::White => score,
+                Color::Black => -score,
+            }
+        });
+
+        if let Some(resign_score) = self.rules.resign_score {
+            let losing = match white_score {
+                Some(score) if score <= -resign_score => Some(Color::White),
+                Some(score) if score >= resign_score => Some(Color::Black),
+                _ => None,
+            };
+
+            for color in Color::ALL {
+                if Some(color) == losing {
+                    self.resign_plies[color as usize] += 1;
+                } else {
+                    self.resign_plies[color as usize] = 0;
+                }
+            }
+
+            if let Some(color) = losing {
+                if self.resign_plies[color as usize] >= 2 * self.rules.resign_moves.max(1) {
+                    return Some(match color {
+                        Color::White => GameResult::BlackWins,
+                        Color::Black => GameResult::WhiteWins,
+                    });
+                }
+            }
+        }
+
+        if let Some(draw_score) = self.rules.draw_score {
+            let drawn = board.fullmove_counter() >= self.rules.draw_move_number
+                && white_score.is_some_and(|score| score.abs() <= draw_score);
+
+            if drawn {
+                self.draw_plies += 1;
+            } else {
+                self.draw_plies = 0;
+            }
+
+            if self.draw_plies >= 2 * self.rules.draw_moves.max(1) {
+                return Some(GameResult::Draw);
+            }
+        }
+
+        None
+    }
+}
+
+fn tablebase_result(board: &Board) -> Option<GameResult> {
+    match kpk::probe_board(board)? {
+        kpk::KpkResult::Draw => Some(GameResult::Draw),
+        kpk::KpkResult::Win => {
+            let pawns = board.piece_types_bitboard(PieceType::Pawn);
+
+            if (pawns & board.color_bitboard(Color::White)).is_empty() {
+                Some(GameResult::BlackWins)
+            } else {
+                Some(GameResult::WhiteWins)
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::{path::Path, process::Command};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/build.rs");
+
+    // the commit changes with HEAD, the branch it points to and the packed
+    // refs, and outside of a git checkout there is nothing to watch
+    let watched = ["HEAD".to_string(), "packed-refs".to_string()]
+        .into_iter()
+        .chain(git(&["symbolic-ref", "-q", "HEAD"]));
+
+    for name in watched {
+        if let Some(path) = git(&["rev-parse", "--git-path", &name]) {
+            if Path::new(&path).exists() {
+                println!("cargo:rerun-if-changed={}", path);
+            }
+        }
+    }
+
+    if let Some(hash) = git(&["rev-parse", "--short", "HEAD"]) {
+        println!("cargo:rustc-env=CHESS_ENGINE_GIT_HASH={}", hash);
+    }
+}
+
+/// Runs a git command and returns its trimmed output, if it succeeds.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let output = String::from_utf8(output.stdout).ok()?;
+    Some(output.trim().to_string()).filter(|output| !output.is_empty())
+}
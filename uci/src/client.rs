@@ -0,0 +1,395 @@
+use std::{
+    ffi::OsStr,
+    io::{self, BufRead, BufReader, Write},
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+    str::FromStr,
+};
+
+use chess_engine_core::Move;
+use chess_engine_movegen::Board;
+use thiserror::Error;
+
+use crate::{Info, SearchLimits};
+
+/// Errors that can occur when talking to a UCI engine
+#[derive(Error, Debug)]
+pub enum UciError {
+    /// Failed to spawn, write to or read from the engine
+    #[error("{0}")]
+    Io(#[from] io::Error),
+
+    /// The engine closed its output before answering
+    #[error("engine closed its output")]
+    Closed,
+
+    /// Invalid bestmove line
+    #[error("invalid bestmove line (got {0})")]
+    BestMove(String),
+}
+
+/// The moves of a `bestmove` line sent by an engine at the end of a search.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_uci::*;
+/// let bestmove: BestMove = "bestmove e2e4 ponder e7e5".parse().unwrap();
+/// assert_eq!(bestmove.best_move, Some("e2e4".parse().unwrap()));
+/// assert_eq!(bestmove.ponder, Some("e7e5".parse().unwrap()));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BestMove {
+    /// The best move, or `None` if the engine has no move to play.
+    pub best_move: Option<Move>,
+    /// The move the engine expects as an answer, if any.
+    pub ponder: Option<Move>,
+}
+
+/// Parses a `BestMove` from a `bestmove` line.
+///
+/// Engines without a move to play, when checkmated or stalemated, send
+/// `(none)` or the null move `0000`.
+///
+/// # Errors
+///
+/// Returns a [`UciError::BestMove`] if the line doesn't start with
+/// `bestmove`, or a move is invalid.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_uci::*;
+/// let bestmove: BestMove = "bestmove g1f3".parse().unwrap();
+/// assert_eq!(bestmove.best_move, Some("g1f3".parse().unwrap()));
+/// assert_eq!(bestmove.ponder, None);
+///
+/// for line in ["bestmove (none)", "bestmove 0000"] {
+///     let bestmove: BestMove = line.parse().unwrap();
+///     assert_eq!(bestmove.best_move, None);
+/// }
+///
+/// assert!("bestmove".parse::<BestMove>().is_err());
+/// assert!("bestmove e2e9".parse::<BestMove>().is_err());
+/// assert!("bestmove e2e4 ponder".parse::<BestMove>().is_err());
+/// assert!("bestmove e2e4 e7e5".parse::<BestMove>().is_err());
+/// assert!("info depth 1".parse::<BestMove>().is_err());
+/// ```
+impl FromStr for BestMove {
+    type Err = UciError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let error = || UciError::BestMove(line.to_string());
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+
+        let best_move = match tokens.get(..2) {
+            Some(["bestmove", "(none)" | "0000"]) => None,
+            Some(["bestmove", mv]) => Some(mv.parse().map_err(|_| error())?),
+            _ => return Err(error()),
+        };
+
+        let ponder = match &tokens[2..] {
+            ["ponder", mv] => Some(mv.parse().map_err(|_| error())?),
+            [] => None,
+            _ => return Err(error()),
+        };
+
+        Ok(BestMove { best_move, ponder })
+    }
+}
+
+/// Result of a search by a UCI engine.
+#[derive(Clone, Debug)]
+pub struct SearchResult {
+    /// The best move, or `None` if the engine has no move to play.
+    pub best_move: Option<Move>,
+    /// The move the engine expects as an answer, if any.
+    pub ponder: Option<Move>,
+    /// Every `info` line sent during the search, in order. Lines that can't
+    /// be parsed are skipped.
+    pub info: Vec<Info>,
+}
+
+/// A client controlling an external UCI engine process.
+///
+/// All methods block until the engine answers. The engine is told to quit
+/// when the client is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use chess_engine_uci::*;
+/// # use chess_engine_movegen::*;
+/// let mut stockfish = UciClient::spawn("stockfish").unwrap();
+/// stockfish.set_option("Threads", "2").unwrap();
+/// stockfish.new_game().unwrap();
+///
+/// stockfish.position(&Board::default(), &[]).unwrap();
+/// let result = stockfish
+///     .go(&SearchLimits {
+///         depth: Some(12),
+///         ..Default::default()
+///     })
+///     .unwrap();
+///
+/// println!("{:?}", result.best_move);
+/// ```
+pub struct UciClient {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    name: Option<String>,
+    author: Option<String>,
+    options: Vec<String>,
+}
+
+impl UciClient {
+    /// Spawns an engine and waits for it to finish the `uci` handshake.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UciError`] if the engine can't be spawned or closes before
+    /// sending `uciok`.
+    pub fn spawn(program: impl AsRef<OsStr>) -> Result<Self, UciError> {
+        Self::from_command(Command::new(program))
+    }
+
+    /// Spawns an engine from a [`Command`], for engines that need arguments
+    /// or environment variables, and waits for it to finish the `uci`
+    /// handshake.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UciError`] if the engine can't be spawned or closes before
+    /// sending `uciok`.
+    ///
+    /// # Examples
+    ///
+    /// A scripted engine, answering every command with canned lines:
+    ///
+    /// ```
+    /// # use chess_engine_uci::*;
+    /// # use chess_engine_movegen::*;
+    /// # use std::process::Command;
+    /// let script = r#"
+    ///     while read -r command; do
+    ///         case "$command" in
+    ///             uci)
+    ///                 echo "id name Scripted"
+    ///                 echo "id author Nobody"
+    ///                 echo "option name Hash type spin default 16 min 1 max 1024"
+    ///                 echo "uciok" ;;
+    ///             isready) echo "readyok" ;;
+    ///             go*)
+    ///                 echo "info depth 1 score cp 20 pv e2e4"
+    ///                 echo "info depth deep"
+    ///                 echo "info depth 2 score cp 15 pv e2e4 e7e5"
+    ///                 echo "bestmove e2e4 ponder e7e5" ;;
+    ///             quit) exit 0 ;;
+    ///         esac
+    ///     done
+    /// "#;
+    ///
+    /// let mut command = Command::new("sh");
+    /// command.args(["-c", script]);
+    ///
+    /// let mut engine = UciClient::from_command(command).unwrap();
+    /// assert_eq!(engine.name(), Some("Scripted"));
+    /// assert_eq!(engine.author(), Some("Nobody"));
+    /// assert_eq!(engine.options(), ["Hash"]);
+    ///
+    /// engine.set_option("Hash", "32").unwrap();
+    /// engine.new_game().unwrap();
+    /// engine.position(&Board::default(), &[]).unwrap();
+    ///
+    /// let result = engine.go(&SearchLimits::default()).unwrap();
+    /// assert_eq!(result.best_move, Some("e2e4".parse().unwrap()));
+    /// assert_eq!(result.ponder, Some("e7e5".parse().unwrap()));
+    /// assert_eq!(result.info.len(), 2);
+    /// assert_eq!(result.info[1].score, Some(Score::Centipawns(15)));
+    /// ```
+    ///
+    /// An engine that exits before the handshake is an error:
+    ///
+    /// ```
+    /// # use chess_engine_uci::*;
+    /// # use std::process::Command;
+    /// let mut command = Command::new("sh");
+    /// command.args(["-c", "read -r command"]);
+    ///
+    /// assert!(matches!(
+    ///     UciClient::from_command(command),
+    ///     Err(UciError::Closed)
+    /// ));
+    /// ```
+    pub fn from_command(mut command: Command) -> Result<Self, UciError> {
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        let stdin = child.stdin.take().ok_or(UciError::Closed)?;
+        let stdout = BufReader::new(child.stdout.take().ok_or(UciError::Closed)?);
+
+        let mut client = Self {
+            child,
+            stdin,
+            stdout,
+            name: None,
+            author: None,
+            options: Vec::new(),
+        };
+
+        client.send("uci")?;
+
+        loop {
+            let line = client.read_line()?;
+
+            if let Some(name) = line.strip_prefix("id name ") {
+                client.name = Some(name.to_string());
+            } else if let Some(author) = line.strip_prefix("id author ") {
+                client.author = Some(author.to_string());
+            } else if let Some(option) = line.strip_prefix("option name ") {
+                let name = option.split(" type ").next().unwrap_or(option);
+                client.options.push(name.to_string());
+            } else if line == "uciok" {
+                return Ok(client);
+            }
+        }
+    }
+
+    /// Returns the name the engine identified itself with.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the author the engine identified itself with.
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    /// Returns the names of the options the engine supports.
+    pub fn options(&self) -> &[String] {
+        &self.options
+    }
+
+    /// Sets an engine option and waits until the engine is ready.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UciError`] if the engine can't be reached.
+    pub fn set_option(&mut self, name: &str, value: &str) -> Result<(), UciError> {
+        self.send(&format!("setoption name {} value {}", name, value))?;
+        self.is_ready()
+    }
+
+    /// Tells the engine that the next position is from a new game and waits
+    /// until the engine is ready.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UciError`] if the engine can't be reached.
+    pub fn new_game(&mut self) -> Result<(), UciError> {
+        self.send("ucinewgame")?;
+        self.is_ready()
+    }
+
+    /// Waits until the engine answers `readyok`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UciError`] if the engine can't be reached.
+    pub fn is_ready(&mut self) -> Result<(), UciError> {
+        self.send("isready")?;
+
+        while self.read_line()? != "readyok" {}
+
+        Ok(())
+    }
+
+    /// Sets up a [`Board`] followed by some moves.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UciError`] if the engine can't be reached.
+    pub fn position(&mut self, board: &Board, moves: &[Move]) -> Result<(), UciError> {
+        let mut command = format!("position fen {}", board);
+
+        if !moves.is_empty() {
+            command.push_str(" moves");
+
+            for mv in moves {
+                command.push_str(&format!(" {}", mv));
+            }
+        }
+
+        self.send(&command)
+    }
+
+    /// Searches the current position and waits for the best move.
+    ///
+    /// `info` lines that can't be parsed, like those with tokens of newer
+    /// engines, are skipped instead of failing the search.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UciError`] if the engine can't be reached or sends an
+    /// invalid `bestmove` line.
+    pub fn go(&mut self, limits: &SearchLimits) -> Result<SearchResult, UciError> {
+        self.send(&limits.to_string())?;
+
+        let mut info = Vec::new();
+
+        loop {
+            let line = self.read_line()?;
+
+            if line.starts_with("info") {
+                if let Ok(parsed) = line.parse() {
+                    info.push(parsed);
+                }
+            } else if line.starts_with("bestmove") {
+                let BestMove { best_move, ponder } = line.parse()?;
+
+                return Ok(SearchResult {
+                    best_move,
+                    ponder,
+                    info,
+                });
+            }
+        }
+    }
+
+    /// Sends a raw command to the engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UciError`] if the engine can't be reached.
+    pub fn send(&mut self, command: &str) -> Result<(), UciError> {
+        writeln!(self.stdin, "{}", command)?;
+        self.stdin.flush()?;
+
+        Ok(())
+    }
+
+    /// Reads the next line sent by the engine, without the line ending.
+    fn read_line(&mut self) -> Result<String, UciError> {
+        let mut line = String::new();
+
+        if self.stdout.read_line(&mut line)? == 0 {
+            return Err(UciError::Closed);
+        }
+
+        Ok(line.trim_end().to_string())
+    }
+}
+
+/// Tells the engine to quit and waits for the process to exit.
+impl Drop for UciClient {
+    fn drop(&mut self) {
+        if self.send("quit").is_ok() {
+            let _ = self.child.wait();
+        } else {
+            let _ = self.child.kill();
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt;
+
+use chess_engine_movegen::build_info::{self, BuildInfo};
+
+/// The [`BuildInfo`] of this build, with the commit read by the build script
+/// of this crate, or `None` when building outside of a git checkout.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_uci::*;
+/// assert!(BUILD_INFO.to_string().starts_with("Chess Engine 0."));
+/// ```
+pub const BUILD_INFO: BuildInfo =
+    build_info::BUILD_INFO.with_git_hash(option_env!("CHESS_ENGINE_GIT_HASH"));
+
+/// The identification an engine answers the `uci` command with.
+///
+/// The default identification is this engine, named after its
+/// [`BUILD_INFO`] so the commit and features of the binary show up in the
+/// logs of the GUI or tournament manager running it.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_uci::*;
+/// let id = EngineId::new("Stockfish 16", "the Stockfish developers");
+/// assert_eq!(
+///     id.to_string(),
+///     "id name Stockfish 16\nid author the Stockfish developers"
+/// );
+///
+/// assert!(EngineId::default().to_string().starts_with("id name Chess Engine"));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct EngineId {
+    /// The name of the engine, with its version.
+    pub name: String,
+    /// The author of the engine.
+    pub author: String,
+}
+
+/// Methods for the `EngineId` struct.
+impl EngineId {
+    /// The author of this engine.
+    pub const AUTHOR: &'static str = "sanpezlo";
+
+    /// Creates an `EngineId`.
+    pub fn new(name: impl Into<String>, author: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            author: author.into(),
+        }
+    }
+
+    /// Creates the `EngineId` of a build of this engine.
+    pub fn from_build_info(build_info: &BuildInfo) -> Self {
+        Self::new(build_info.to_string(), Self::AUTHOR)
+    }
+}
+
+impl Default for EngineId {
+    fn default() -> Self {
+        Self::from_build_info(&BUILD_INFO)
+    }
+}
+
+/// Formats the `id name` and `id author` lines, without a trailing newline.
+impl fmt::Display for EngineId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "id name {}\nid author {}", self.name, self.author)
+    }
+}
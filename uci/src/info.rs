@@ -0,0 +1,261 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+use chess_engine_core::Move;
+use thiserror::Error;
+
+/// The score of a position, as reported by an engine.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_uci::*;
+/// let info: Info = "info depth 12 score mate -3".parse().unwrap();
+/// assert_eq!(info.score, Some(Score::Mate(-3)));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Score {
+    /// Score in centipawns, from the point of view of the side to move.
+    Centipawns(i32),
+    /// Mate in the given number of moves, negative if the side to move is
+    /// getting mated.
+    Mate(i32),
+}
+
+/// An error that can occur when parsing an [`Info`] line.
+#[derive(Error, Debug)]
+#[error("invalid info line (got {0})")]
+pub struct InfoError(pub String);
+
+/// Search information sent by an engine in an `info` line.
+///
+/// Fields the engine didn't send are `None`, or empty for the principal
+/// variation.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_uci::*;
+/// # use std::time::Duration;
+/// let info: Info = "info depth 10 seldepth 14 score cp 25 nodes 4096 time 250 pv e2e4 e7e5"
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(info.depth, Some(10));
+/// assert_eq!(info.score, Some(Score::Centipawns(25)));
+/// assert_eq!(info.time, Some(Duration::from_millis(250)));
+/// assert_eq!(info.pv, vec!["e2e4".parse().unwrap(), "e7e5".parse().unwrap()]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Info {
+    /// Search depth in plies.
+    pub depth: Option<u32>,
+    /// Selective search depth in plies.
+    pub seldepth: Option<u32>,
+    /// Index of the line when searching multiple principal variations.
+    pub multipv: Option<u32>,
+    /// Score of the line.
+    pub score: Option<Score>,
+    /// Number of nodes searched.
+    pub nodes: Option<u64>,
+    /// Nodes searched per second.
+    pub nps: Option<u64>,
+    /// Time spent searching.
+    pub time: Option<Duration>,
+    /// Root move being searched.
+    pub currmove: Option<Move>,
+    /// Number of the root move being searched, starting at 1.
+    pub currmovenumber: Option<u32>,
+    /// Principal variation.
+    pub pv: Vec<Move>,
+    /// Free-form text sent by the engine.
+    pub string: Option<String>,
+}
+
+/// Methods for the `Info` struct.
+impl Info {
+    /// Creates the `Info` an engine sends periodically to report its
+    /// progress: the nodes searched, the time spent and the resulting nodes
+    /// per second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_uci::*;
+    /// # use std::time::Duration;
+    /// let info = Info::progress(150_000, Duration::from_millis(500));
+    /// assert_eq!(info.to_string(), "info nodes 150000 nps 300000 time 500");
+    /// ```
+    pub fn progress(nodes: u64, time: Duration) -> Self {
+        let millis = time.as_millis().max(1) as u64;
+
+        Self {
+            nodes: Some(nodes),
+            nps: Some(nodes.saturating_mul(1000) / millis),
+            time: Some(time),
+            ..Default::default()
+        }
+    }
+
+    /// Creates the `Info` an engine sends when it starts searching a root
+    /// move, numbered from 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_uci::*;
+    /// let info = Info::current_move("e2e4".parse().unwrap(), 1);
+    /// assert_eq!(info.to_string(), "info currmove e2e4 currmovenumber 1");
+    /// ```
+    pub fn current_move(mv: Move, number: u32) -> Self {
+        Self {
+            currmove: Some(mv),
+            currmovenumber: Some(number),
+            ..Default::default()
+        }
+    }
+}
+
+/// Formats the `info` line an engine sends, with the fields that are set.
+///
+/// The principal variation and the free-form text come last, since they run
+/// to the end of the line.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_uci::*;
+/// let line = "info depth 10 score cp 25 nodes 4096 time 250 pv e2e4 e7e5";
+/// assert_eq!(line.parse::<Info>().unwrap().to_string(), line);
+/// ```
+impl fmt::Display for Info {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = "info".to_string();
+
+        let counts = [
+            ("depth", self.depth.map(u64::from)),
+            ("seldepth", self.seldepth.map(u64::from)),
+            ("multipv", self.multipv.map(u64::from)),
+        ];
+
+        for (name, count) in counts {
+            if let Some(count) = count {
+                s.push_str(&format!(" {} {}", name, count));
+            }
+        }
+
+        match self.score {
+            Some(Score::Centipawns(cp)) => s.push_str(&format!(" score cp {}", cp)),
+            Some(Score::Mate(mate)) => s.push_str(&format!(" score mate {}", mate)),
+            None => {}
+        }
+
+        let counts = [
+            ("nodes", self.nodes),
+            ("nps", self.nps),
+            ("time", self.time.map(|time| time.as_millis() as u64)),
+        ];
+
+        for (name, count) in counts {
+            if let Some(count) = count {
+                s.push_str(&format!(" {} {}", name, count));
+            }
+        }
+
+        if let Some(mv) = self.currmove {
+            s.push_str(&format!(" currmove {}", mv));
+        }
+
+        if let Some(number) = self.currmovenumber {
+            s.push_str(&format!(" currmovenumber {}", number));
+        }
+
+        if !self.pv.is_empty() {
+            s.push_str(" pv");
+            for mv in &self.pv {
+                s.push_str(&format!(" {}", mv));
+            }
+        }
+
+        if let Some(string) = &self.string {
+            s.push_str(&format!(" string {}", string));
+        }
+
+        write!(f, "{}", s)
+    }
+}
+
+/// Parses an [`Info`] from an `info` line.
+///
+/// Unknown fields are skipped, so extensions of the protocol don't fail the
+/// whole line.
+///
+/// # Errors
+///
+/// Returns an [`InfoError`] if the line doesn't start with `info`, or a known
+/// field has an invalid value.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_uci::*;
+/// let info: Info = "info hashfull 12 string searching".parse().unwrap();
+/// assert_eq!(info.string.as_deref(), Some("searching"));
+///
+/// assert!("info depth deep".parse::<Info>().is_err());
+/// assert!("info pv e7e8k".parse::<Info>().is_err());
+/// assert!("bestmove e2e4".parse::<Info>().is_err());
+/// ```
+impl FromStr for Info {
+    type Err = InfoError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let error = || InfoError(line.to_string());
+
+        let mut tokens = line.split_whitespace();
+
+        if tokens.next() != Some("info") {
+            return Err(error());
+        }
+
+        let mut info = Info::default();
+
+        while let Some(token) = tokens.next() {
+            match token {
+                "depth" => info.depth = Some(parse_next(&mut tokens).ok_or_else(error)?),
+                "seldepth" => info.seldepth = Some(parse_next(&mut tokens).ok_or_else(error)?),
+                "multipv" => info.multipv = Some(parse_next(&mut tokens).ok_or_else(error)?),
+                "nodes" => info.nodes = Some(parse_next(&mut tokens).ok_or_else(error)?),
+                "nps" => info.nps = Some(parse_next(&mut tokens).ok_or_else(error)?),
+                "time" => {
+                    let time = parse_next(&mut tokens).ok_or_else(error)?;
+                    info.time = Some(Duration::from_millis(time));
+                }
+                "currmove" => info.currmove = Some(parse_next(&mut tokens).ok_or_else(error)?),
+                "currmovenumber" => {
+                    info.currmovenumber = Some(parse_next(&mut tokens).ok_or_else(error)?)
+                }
+                "score" => {
+                    info.score = Some(match tokens.next() {
+                        Some("cp") => Score::Centipawns(parse_next(&mut tokens).ok_or_else(error)?),
+                        Some("mate") => Score::Mate(parse_next(&mut tokens).ok_or_else(error)?),
+                        _ => return Err(error()),
+                    });
+                }
+                "pv" => {
+                    for token in tokens.by_ref() {
+                        info.pv.push(token.parse().map_err(|_| error())?);
+                    }
+                }
+                "string" => info.string = Some(tokens.by_ref().collect::<Vec<_>>().join(" ")),
+                _ => {}
+            }
+        }
+
+        Ok(info)
+    }
+}
+
+/// Parses the next token, returning `None` if there is none or it is invalid.
+fn parse_next<'a, T: FromStr>(tokens: &mut impl Iterator<Item = &'a str>) -> Option<T> {
+    tokens.next()?.parse().ok()
+}
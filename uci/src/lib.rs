@@ -0,0 +1,18 @@
+#![deny(missing_docs)]
+#![doc = include_str!("../README.md")]
+
+//! Universal Chess Interface tools for the chess engine.
+
+mod adjudication;
+mod client;
+mod id;
+mod info;
+mod limits;
+mod openings;
+
+pub use adjudication::*;
+pub use client::*;
+pub use id::*;
+pub use info::*;
+pub use limits::*;
+pub use openings::*;
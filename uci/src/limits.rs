@@ -0,0 +1,77 @@
+use std::{fmt, time::Duration};
+
+/// Limits of a search, sent to an engine with the `go` command.
+///
+/// Limits left as `None` are not sent. Without any limit, most engines search
+/// until they are told to stop, so at least one should be set.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_uci::*;
+/// # use std::time::Duration;
+/// let limits = SearchLimits {
+///     depth: Some(12),
+///     movetime: Some(Duration::from_secs(1)),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(limits.to_string(), "go depth 12 movetime 1000");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct SearchLimits {
+    /// Maximum depth in plies.
+    pub depth: Option<u32>,
+    /// Maximum number of nodes.
+    pub nodes: Option<u64>,
+    /// Search for a mate in the given number of moves.
+    pub mate: Option<u32>,
+    /// Exact time to search.
+    pub movetime: Option<Duration>,
+    /// Time left on White's clock.
+    pub wtime: Option<Duration>,
+    /// Time left on Black's clock.
+    pub btime: Option<Duration>,
+    /// White's increment per move.
+    pub winc: Option<Duration>,
+    /// Black's increment per move.
+    pub binc: Option<Duration>,
+    /// Moves left until the next time control.
+    pub movestogo: Option<u32>,
+}
+
+/// Formats the `SearchLimits` as a `go` command.
+impl fmt::Display for SearchLimits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = String::from("go");
+
+        let counts = [
+            ("depth", self.depth.map(u64::from)),
+            ("nodes", self.nodes),
+            ("mate", self.mate.map(u64::from)),
+            ("movestogo", self.movestogo.map(u64::from)),
+        ];
+
+        let times = [
+            ("movetime", self.movetime),
+            ("wtime", self.wtime),
+            ("btime", self.btime),
+            ("winc", self.winc),
+            ("binc", self.binc),
+        ];
+
+        for (name, count) in counts {
+            if let Some(count) = count {
+                s.push_str(&format!(" {} {}", name, count));
+            }
+        }
+
+        for (name, time) in times {
+            if let Some(time) = time {
+                s.push_str(&format!(" {} {}", name, time.as_millis()));
+            }
+        }
+
+        write!(f, "{}", s)
+    }
+}
@@ -0,0 +1,130 @@
+use std::io::BufRead;
+
+use chess_engine_core::Color;
+use chess_engine_movegen::{
+    fen,
+    fen::{FenError, FenReader},
+    BoardBuilder,
+};
+
+/// The initial position, for matches without openings.
+const INITIAL_POSITION: BoardBuilder =
+    fen!("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+
+/// A game of a match between two engines, from an opening of an
+/// [`OpeningSet`].
+#[derive(Clone, Copy, Debug)]
+pub struct Pairing {
+    /// The position the game starts from.
+    pub opening: BoardBuilder,
+    /// The index of the opening in the set.
+    pub opening_index: usize,
+    /// The color the first engine plays. The second engine plays the other.
+    pub first_engine: Color,
+}
+
+/// A set of starting positions for engine matches.
+///
+/// Games from the initial position quickly repeat the same few lines, so
+/// matches start from a variety of openings instead. Each opening is played
+/// twice, once with each engine as White, so an unbalanced opening favors
+/// neither engine.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_uci::*;
+/// # use chess_engine_core::*;
+/// let epd = "
+///     rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - bm c5;
+///     rnbqkbnr/pppppppp/8/8/3P4/8/PPP1PPPP/RNBQKBNR b KQkq - bm d5;
+/// ";
+///
+/// let openings = OpeningSet::from_epd(epd.as_bytes()).unwrap();
+/// let pairings = openings.pairings(6);
+///
+/// let colors: Vec<(usize, Color)> = pairings
+///     .iter()
+///     .map(|pairing| (pairing.opening_index, pairing.first_engine))
+///     .collect();
+///
+/// assert_eq!(
+///     colors,
+///     vec![
+///         (0, Color::White),
+///         (0, Color::Black),
+///         (1, Color::White),
+///         (1, Color::Black),
+///         (0, Color::White),
+///         (0, Color::Black),
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct OpeningSet {
+    openings: Vec<BoardBuilder>,
+}
+
+/// Methods for the `OpeningSet` struct.
+impl OpeningSet {
+    /// Creates an `OpeningSet` from its positions.
+    pub fn new(openings: Vec<BoardBuilder>) -> Self {
+        OpeningSet { openings }
+    }
+
+    /// Reads an `OpeningSet` from a file with one FEN or EPD per line, like
+    /// the `openings.epd` files of tournament managers.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FenError`] if the file can't be read or a position is
+    /// invalid.
+    pub fn from_epd(reader: impl BufRead) -> Result<Self, FenError> {
+        Ok(Self::new(FenReader::new(reader).collect::<Result<_, _>>()?))
+    }
+
+    /// Returns the positions of the set.
+    pub fn openings(&self) -> &[BoardBuilder] {
+        &self.openings
+    }
+
+    /// Returns the number of positions of the set.
+    pub fn len(&self) -> usize {
+        self.openings.len()
+    }
+
+    /// Returns `true` if the set has no positions.
+    pub fn is_empty(&self) -> bool {
+        self.openings.is_empty()
+    }
+
+    /// Returns the pairings of a match of `games` games.
+    ///
+    /// The openings are played in order, each twice in a row with the
+    /// colors reversed, and start over when the set runs out. An empty set
+    /// plays every game from the initial position.
+    pub fn pairings(&self, games: usize) -> Vec<Pairing> {
+        (0..games)
+            .map(|game| {
+                let opening_index = match self.openings.len() {
+                    0 => 0,
+                    len => game / 2 % len,
+                };
+
+                Pairing {
+                    opening: self
+                        .openings
+                        .get(opening_index)
+                        .copied()
+                        .unwrap_or(INITIAL_POSITION),
+                    opening_index,
+                    first_engine: if game % 2 == 0 {
+                        Color::White
+                    } else {
+                        Color::Black
+                    },
+                }
+            })
+            .collect()
+    }
+}
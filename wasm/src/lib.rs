@@ -0,0 +1,175 @@
+#![deny(missing_docs)]
+#![doc = include_str!("../README.md")]
+
+//! WebAssembly bindings for the chess engine.
+
+use chess_engine_movegen::{
+    notation::{Notation, NotationError},
+    BoardBuilder,
+};
+use wasm_bindgen::prelude::*;
+
+/// A chessboard exposed to JavaScript.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_wasm::*;
+/// let board = Board::new();
+/// assert_eq!(
+///     board.fen(),
+///     "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+/// );
+/// ```
+#[wasm_bindgen]
+pub struct Board(chess_engine_movegen::Board);
+
+#[wasm_bindgen]
+impl Board {
+    /// Creates a new `Board` with the starting position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_wasm::*;
+    /// let board = Board::new();
+    /// assert_eq!(board.turn(), "w");
+    /// ```
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Board {
+        Board::default()
+    }
+
+    /// Creates a new `Board` from a FEN string.
+    ///
+    /// # Errors
+    ///
+    /// Throws a JavaScript error if the FEN string is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_wasm::*;
+    /// let fen = "4k3/8/8/8/8/8/4P3/4K3 b - - 0 1";
+    /// let board = Board::from_fen(fen).unwrap();
+    /// assert_eq!(board.fen(), fen);
+    /// ```
+    #[wasm_bindgen(js_name = fromFen)]
+    pub fn from_fen(fen: &str) -> Result<Board, JsError> {
+        let board_builder: BoardBuilder = fen.parse()?;
+
+        Ok(Board(board_builder.build()))
+    }
+
+    /// Returns the FEN string of the `Board`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_wasm::*;
+    /// let board = Board::new();
+    /// assert_eq!(
+    ///     board.fen(),
+    ///     "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+    /// );
+    /// ```
+    pub fn fen(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the color to move, `"w"` or `"b"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_wasm::*;
+    /// let board = Board::from_fen("4k3/8/8/8/8/8/4P3/4K3 b - - 0 1").unwrap();
+    /// assert_eq!(board.turn(), "b");
+    /// ```
+    pub fn turn(&self) -> String {
+        self.0.color().to_string()
+    }
+
+    /// Returns the legal moves for the current position in UCI notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_wasm::*;
+    /// let board = Board::new();
+    /// let moves = board.moves();
+    /// assert_eq!(moves.len(), 20);
+    /// assert!(moves.contains(&"e2e4".to_string()));
+    ///
+    /// let pinned = Board::from_fen("4k3/4r3/8/8/8/8/4N3/4K3 w - - 0 1").unwrap();
+    /// assert!(pinned.moves().iter().all(|mv| !mv.starts_with("e2")));
+    /// ```
+    pub fn moves(&self) -> Vec<String> {
+        self.0
+            .generate_legal_moves()
+            .iter()
+            .map(|m| m.to_string())
+            .collect()
+    }
+
+    /// Plays a legal move, written in UCI, SAN, LAN or ICCF numeric
+    /// notation.
+    ///
+    /// # Errors
+    ///
+    /// Throws a JavaScript error if the move can't be parsed or isn't legal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_wasm::*;
+    /// let mut board = Board::new();
+    /// board.make_move("e2e4").unwrap();
+    /// board.make_move("e5").unwrap();
+    /// assert_eq!(
+    ///     board.fen(),
+    ///     "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2"
+    /// );
+    /// ```
+    #[wasm_bindgen(js_name = makeMove)]
+    pub fn make_move(&mut self, mv: &str) -> Result<(), JsError> {
+        let notation =
+            Notation::detect(mv).ok_or_else(|| NotationError::Invalid(mv.to_string()))?;
+        let mv = self.0.parse_move(mv, notation)?;
+        self.0.make_move(mv);
+
+        Ok(())
+    }
+
+    /// Returns a legal move, written in any notation, in SAN.
+    ///
+    /// # Errors
+    ///
+    /// Throws a JavaScript error if the move can't be parsed or isn't legal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chess_engine_wasm::*;
+    /// let board = Board::new();
+    /// assert_eq!(board.san("g1f3").unwrap(), "Nf3");
+    /// ```
+    pub fn san(&self, mv: &str) -> Result<String, JsError> {
+        Ok(self.0.convert_move(mv, Notation::San)?)
+    }
+}
+
+/// Default implementation for `Board`, the starting position.
+///
+/// # Examples
+///
+/// ```
+/// # use chess_engine_wasm::*;
+/// let board = Board::default();
+/// assert_eq!(board.fen(), Board::new().fen());
+/// ```
+impl Default for Board {
+    fn default() -> Self {
+        Board(chess_engine_movegen::Board::default())
+    }
+}